@@ -0,0 +1,25 @@
+use redux_rs::EnablingCondition;
+use serde::{Deserialize, Serialize};
+
+use crate::automaton::State;
+
+/// The first `count` alerts of the outbox were handed to the alert service
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlertsDeliveredAction {
+    pub count: usize,
+}
+
+impl EnablingCondition<State> for AlertsDeliveredAction {
+    fn is_enabled(&self, state: &State) -> bool {
+        !state.alerts.outbox.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlertDismissAction {}
+
+impl EnablingCondition<State> for AlertDismissAction {
+    fn is_enabled(&self, state: &State) -> bool {
+        state.alerts.banner.is_some()
+    }
+}
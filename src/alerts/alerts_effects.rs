@@ -0,0 +1,28 @@
+use slog::warn;
+
+use crate::{
+    automaton::{ActionWithMeta, Store},
+    services::{alert_service::AlertService, Service},
+};
+
+use super::AlertsDeliveredAction;
+
+pub fn alerts_effects<S>(store: &mut Store<S>, _action: &ActionWithMeta)
+where
+    S: Service,
+{
+    // the alerts can fire on any action, so the outbox is checked after each of them
+    if store.state().alerts.outbox.is_empty() {
+        return;
+    }
+
+    let alerts = store.state().alerts.outbox.clone();
+    for alert in &alerts {
+        warn!(store.state().log, "Alert fired: {}", alert.message);
+        store.service().alert().deliver(alert);
+    }
+
+    store.dispatch(AlertsDeliveredAction {
+        count: alerts.len(),
+    });
+}
@@ -0,0 +1,78 @@
+use std::collections::BTreeMap;
+
+use crate::automaton::{Action, ActionWithMeta, State};
+
+use super::{Alert, ALERT_LOG_CAPACITY};
+
+/// Runs last in the reducer chain, so the rules see the state after the action
+pub fn alerts_reducer(state: &mut State, action: &ActionWithMeta) {
+    let now = action.time_as_nanos();
+
+    match &action.action {
+        Action::CurrentHeadHeaderReceived(_) => {
+            state.alerts.last_head_received = Some(now);
+        }
+        Action::AlertsDelivered(action) => {
+            let count = action.count.min(state.alerts.outbox.len());
+            state.alerts.outbox.drain(..count);
+        }
+        Action::AlertDismiss(_) => {
+            state.alerts.banner = None;
+        }
+        _ => {}
+    }
+
+    // the connection is considered lost only after some time without any response
+    if state.alerts.last_head_received.is_none() {
+        state.alerts.last_head_received = Some(now);
+    }
+
+    let conditions: BTreeMap<String, (&'static str, String)> = state
+        .config
+        .alerts
+        .rules
+        .iter()
+        .filter_map(|rule| {
+            rule.evaluate(state, now)
+                .map(|condition| (condition.key, (rule.name(), condition.message)))
+        })
+        .collect();
+
+    let alerts = &mut state.alerts;
+
+    let resolved: Vec<String> = alerts
+        .active
+        .keys()
+        .filter(|key| !conditions.contains_key(*key))
+        .cloned()
+        .collect();
+    for key in resolved {
+        alerts.active.remove(&key);
+        if let Some(alert) = alerts.log.iter_mut().rev().find(|alert| alert.key == key) {
+            alert.resolved = Some(now);
+        }
+    }
+
+    for (key, (rule, message)) in conditions {
+        if alerts.active.contains_key(&key) {
+            continue;
+        }
+        let alert = Alert {
+            key: key.clone(),
+            rule: rule.to_string(),
+            message,
+            level: state.current_head_header.level,
+            fired: now,
+            resolved: None,
+        };
+        alerts.active.insert(key, alert.clone());
+        alerts.log.push(alert.clone());
+        alerts.outbox.push(alert.clone());
+        alerts.banner = Some(alert);
+    }
+
+    if alerts.log.len() > ALERT_LOG_CAPACITY {
+        let overflow = alerts.log.len() - ALERT_LOG_CAPACITY;
+        alerts.log.drain(..overflow);
+    }
+}
@@ -0,0 +1,219 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use time::{Duration, OffsetDateTime};
+
+use crate::{
+    automaton::State,
+    baking::BlockApplicationSummary,
+    rights::{estimated_time, CalendarEntry, RightKind},
+};
+
+/// Number of alerts kept in the alert log
+pub const ALERT_LOG_CAPACITY: usize = 200;
+
+/// Condition of the node or the baker the TUI alerts about
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "rule", rename_all = "snake_case")]
+pub enum AlertRule {
+    /// The local head is more than `blocks` behind the best remote level
+    BehindRemote { blocks: i32 },
+    /// The next baking right of the baker is less than `minutes` away
+    BakingSoon { minutes: i64 },
+    /// The endorsement of the baker was not injected by the next head
+    EndorsementNotInjected,
+    /// A block application phase of the current head took longer than `millis`
+    SlowApplication {
+        phase: ApplicationPhase,
+        millis: u64,
+    },
+    /// No response to the current head request for `seconds`
+    ConnectionLost { seconds: u64 },
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ApplicationPhase {
+    Download,
+    LoadData,
+    Precheck,
+    ProtocolApplyBlock,
+    StoreData,
+}
+
+/// An alert condition that held in a reducer pass
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlertCondition {
+    /// Identifies the condition, the alert fires again only after the condition cleared
+    pub key: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Alert {
+    pub key: String,
+    /// Name of the rule, see [AlertRule::name]
+    pub rule: String,
+    pub message: String,
+    /// Level of the current head when the alert fired
+    pub level: i32,
+    /// Nanoseconds since the unix epoch
+    pub fired: u64,
+    /// Nanoseconds since the unix epoch, `None` while the condition holds
+    pub resolved: Option<u64>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AlertsState {
+    /// Alerts whose condition still holds keyed by the condition key
+    pub active: BTreeMap<String, Alert>,
+    /// Fired alerts, the oldest first
+    pub log: Vec<Alert>,
+    /// The last fired alert stays on the screen until dismissed
+    pub banner: Option<Alert>,
+    /// Fired alerts not yet handed to the alert service
+    pub outbox: Vec<Alert>,
+    /// Time of the last current head response, nanoseconds since the unix epoch
+    pub last_head_received: Option<u64>,
+}
+
+impl AlertRule {
+    pub fn defaults() -> Vec<AlertRule> {
+        vec![
+            AlertRule::BehindRemote { blocks: 2 },
+            AlertRule::BakingSoon { minutes: 5 },
+            AlertRule::EndorsementNotInjected,
+            AlertRule::SlowApplication {
+                phase: ApplicationPhase::ProtocolApplyBlock,
+                millis: 2000,
+            },
+            AlertRule::ConnectionLost { seconds: 120 },
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            AlertRule::BehindRemote { .. } => "behind_remote",
+            AlertRule::BakingSoon { .. } => "baking_soon",
+            AlertRule::EndorsementNotInjected => "endorsement_not_injected",
+            AlertRule::SlowApplication { .. } => "slow_application",
+            AlertRule::ConnectionLost { .. } => "connection_lost",
+        }
+    }
+
+    /// The condition of the rule if it holds in `state` at `now` (nanoseconds since the unix epoch)
+    pub fn evaluate(&self, state: &State, now: u64) -> Option<AlertCondition> {
+        let head = &state.current_head_header;
+        // nothing to compare with before the first head arrives
+        if head.level == 0 && !matches!(self, AlertRule::ConnectionLost { .. }) {
+            return None;
+        }
+
+        match self {
+            AlertRule::BehindRemote { blocks } => {
+                let behind = state.best_remote_level? - head.level;
+                (behind > *blocks).then(|| AlertCondition {
+                    key: self.name().to_string(),
+                    message: format!("Local head is {} blocks behind the network", behind),
+                })
+            }
+            AlertRule::BakingSoon { minutes } => {
+                state.baker_address.as_ref()?;
+                let (level, priority, rights) = state
+                    .rights
+                    .calendar
+                    .range(head.level + 1..)
+                    .find_map(|(level, rights)| {
+                        rights
+                            .baking_priority
+                            .map(|priority| (*level, priority, rights))
+                    })?;
+                let entry = CalendarEntry {
+                    level,
+                    kind: RightKind::Baking {
+                        priority,
+                        round: rights.baking_round,
+                    },
+                    estimated_time: rights.estimated_time,
+                };
+                let baking_time = estimated_time(
+                    &entry,
+                    head.level,
+                    head.timestamp,
+                    state.network_constants.minimal_block_delay,
+                )?;
+                let now = OffsetDateTime::from_unix_timestamp_nanos(now as i128).ok()?;
+                let until = baking_time - now;
+                (until < Duration::minutes(*minutes)).then(|| AlertCondition {
+                    key: format!("{}:{}", self.name(), level),
+                    message: format!(
+                        "Baking at level {} with priority {} in {} minutes",
+                        level,
+                        priority,
+                        until.whole_minutes().max(0)
+                    ),
+                })
+            }
+            AlertRule::EndorsementNotInjected => {
+                state.baker_address.as_ref()?;
+                // the rights of the previous head are pruned only on the next head
+                let endorsing_level = head.level - 1;
+                // the injection could have happened before the TUI started
+                if state.previous_head_header.level != endorsing_level {
+                    return None;
+                }
+                let endorsements = &state.endorsmenents;
+                let missed = endorsements
+                    .endorsement_rights_with_time
+                    .rights
+                    .contains_key(&endorsing_level)
+                    && !endorsements
+                        .injected_endorsement_stats
+                        .contains_key(&endorsing_level);
+                missed.then(|| AlertCondition {
+                    key: format!("{}:{}", self.name(), endorsing_level),
+                    message: format!("Endorsement for level {} was not injected", endorsing_level),
+                })
+            }
+            AlertRule::SlowApplication { phase, millis } => {
+                let statistics = state.baking.application_statistics.get(&head.hash)?;
+                let summary = BlockApplicationSummary::from(statistics.clone());
+                let took = phase.duration(&summary)? / 1_000_000;
+                (took > *millis).then(|| AlertCondition {
+                    key: format!("{}:{}:{}", self.name(), phase.name(), head.hash),
+                    message: format!("{} of block {} took {} ms", phase.name(), head.level, took),
+                })
+            }
+            AlertRule::ConnectionLost { seconds } => {
+                let silent = now.saturating_sub(state.alerts.last_head_received?) / 1_000_000_000;
+                (silent > *seconds).then(|| AlertCondition {
+                    key: self.name().to_string(),
+                    message: format!("No response from the node for {} seconds", silent),
+                })
+            }
+        }
+    }
+}
+
+impl ApplicationPhase {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ApplicationPhase::Download => "Download",
+            ApplicationPhase::LoadData => "Load data",
+            ApplicationPhase::Precheck => "Precheck",
+            ApplicationPhase::ProtocolApplyBlock => "Protocol apply block",
+            ApplicationPhase::StoreData => "Store data",
+        }
+    }
+
+    /// Duration of the phase in nanoseconds
+    pub fn duration(&self, summary: &BlockApplicationSummary) -> Option<u64> {
+        match self {
+            ApplicationPhase::Download => summary.download,
+            ApplicationPhase::LoadData => summary.load_data,
+            ApplicationPhase::Precheck => summary.precheck,
+            ApplicationPhase::ProtocolApplyBlock => summary.protocol_apply_block,
+            ApplicationPhase::StoreData => summary.store_data,
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use time::{format_description, OffsetDateTime};
+use tui::{
+    backend::Backend,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table},
+    Frame,
+};
+
+use crate::automaton::State;
+
+/// Draw the last fired alert as a bar at the top of the screen, below the protocol activation banner
+pub fn draw_alert_banner<B: Backend>(state: &State, f: &mut Frame<B>) {
+    let alert = match &state.alerts.banner {
+        Some(alert) => alert,
+        None => return,
+    };
+
+    let size = f.size();
+    let y = if state.governance.activation_banner.is_some() {
+        1
+    } else {
+        0
+    };
+    if y >= size.height {
+        return;
+    }
+    let area = Rect::new(0, y, size.width, 1);
+
+    let resolved = !state.alerts.active.contains_key(&alert.key);
+    let banner_style = if resolved {
+        Style::default().fg(Color::Black).bg(Color::Gray)
+    } else {
+        Style::default().fg(Color::White).bg(Color::Red)
+    };
+    let mut spans = vec![
+        Span::styled(" ALERT ", banner_style.add_modifier(Modifier::BOLD)),
+        Span::styled(
+            "(ESC to dismiss, a for all) ",
+            banner_style.add_modifier(Modifier::DIM),
+        ),
+        Span::styled(alert.message.clone(), banner_style),
+    ];
+    if resolved {
+        spans.push(Span::styled(" - resolved", banner_style));
+    }
+    let banner = Paragraph::new(Spans::from(spans)).style(banner_style);
+
+    f.render_widget(Clear, area);
+    f.render_widget(banner, area);
+}
+
+/// Draw the fired alerts, the newest first, as a popup over the screen
+pub fn draw_alert_log<B: Backend>(state: &State, f: &mut Frame<B>) {
+    if !state.ui.alert_log_open {
+        return;
+    }
+
+    let dim_style = Style::default().fg(Color::Gray).add_modifier(Modifier::DIM);
+    let value_style = Style::default().fg(Color::White);
+    let active_style = Style::default().fg(Color::Red);
+    let resolved_style = Style::default().fg(Color::Green);
+
+    let format_desc =
+        format_description::parse("[month]-[day] [hour]:[minute]:[second]").unwrap_or_default();
+    let format_time = |nanos: u64| {
+        OffsetDateTime::from_unix_timestamp_nanos(nanos as i128)
+            .ok()
+            .and_then(|time| time.format(&format_desc).ok())
+            .unwrap_or_else(|| String::from("-"))
+    };
+
+    let rows: Vec<Row> = if state.alerts.log.is_empty() {
+        vec![Row::new(vec![
+            Cell::from("No alerts fired").style(dim_style)
+        ])]
+    } else {
+        state
+            .alerts
+            .log
+            .iter()
+            .rev()
+            .map(|alert| {
+                let status = match alert.resolved {
+                    Some(resolved) => Cell::from(format!("resolved {}", format_time(resolved)))
+                        .style(resolved_style),
+                    None => Cell::from("active").style(active_style),
+                };
+                Row::new(vec![
+                    Cell::from(format_time(alert.fired)).style(dim_style),
+                    Cell::from(alert.level.to_string()).style(dim_style),
+                    status,
+                    Cell::from(alert.message.clone()).style(value_style),
+                ])
+            })
+            .collect()
+    };
+
+    let size = f.size();
+    let width = 110.min(size.width);
+    // borders, header and the help line
+    let height = (rows.len() as u16 + 5).min(size.height);
+    let area = Rect::new(
+        (size.width - width) / 2,
+        (size.height - height) / 2,
+        width,
+        height,
+    );
+
+    let header = Row::new(vec!["Fired", "Level", "Status", "Alert"])
+        .style(value_style)
+        .bottom_margin(1);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("Alerts")
+        .style(Style::default().bg(Color::Rgb(31, 30, 30)));
+
+    // borders, the fixed columns and the column spacing
+    let widths = [
+        Constraint::Length(14),
+        Constraint::Length(8),
+        Constraint::Length(23),
+        Constraint::Length(width.saturating_sub(50)),
+    ];
+    let log_table = Table::new(rows).header(header).block(block).widths(&widths);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(area);
+
+    f.render_widget(Clear, area);
+    f.render_widget(log_table, chunks[0]);
+
+    let help = Paragraph::new(Spans::from(vec![Span::styled("a close", dim_style)]))
+        .alignment(Alignment::Center)
+        .style(Style::default().bg(Color::Rgb(31, 30, 30)));
+    f.render_widget(help, chunks[1]);
+}
@@ -0,0 +1,14 @@
+pub mod alerts_actions;
+pub use alerts_actions::*;
+
+pub mod alerts_effects;
+pub use alerts_effects::*;
+
+pub mod alerts_reducer;
+pub use alerts_reducer::*;
+
+pub mod alerts_state;
+pub use alerts_state::*;
+
+pub mod alerts_view;
+pub use alerts_view::*;
@@ -4,29 +4,54 @@ pub use redux_rs::{ActionId, EnablingCondition};
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    alerts::{AlertDismissAction, AlertsDeliveredAction},
     baking::{
         ApplicationStatisticsGetAction, ApplicationStatisticsReceivedAction, BakingRightsGetAction,
         BakingRightsReceivedAction, PerPeerBlockStatisticsGetAction,
         PerPeerBlockStatisticsReceivedAction,
     },
+    blocks::BlockDetailsToggleAction,
     endorsements::{
         EndorsementsRightsGetAction, EndorsementsRightsReceivedAction,
         EndorsementsRightsWithTimeGetAction, EndorsementsRightsWithTimeReceivedAction,
         EndorsementsStatusesGetAction, EndorsementsStatusesReceivedAction,
         MempoolEndorsementStatsGetAction, MempoolEndorsementStatsReceivedAction,
     },
+    export::{
+        ExportFailureAction, ExportMenuToggleAction, ExportRawToggleAction, ExportScreenAction,
+        ExportSuccessAction, ExportTableAction,
+    },
+    governance::{
+        ProposalsGetAction, ProposalsReceivedAction, ProtocolActivatedAction,
+        ProtocolActivationDismissAction,
+    },
+    history::{
+        HistoryApplicationStatisticsReceivedAction, HistoryCloseAction, HistoryLevelGetAction,
+        HistoryLevelSubmitAction, HistoryPerPeerBlockStatisticsReceivedAction,
+        HistoryPromptEditAction, HistoryPromptToggleAction,
+    },
+    inspector::{InspectorRpcReportsGetAction, InspectorRpcReportsReceivedAction},
+    latency::LatencyWindowToggleAction,
+    logs::{
+        LogsGetAction, LogsLevelFilterAction, LogsModuleFilterAction, LogsReceivedAction,
+        LogsSearchEditAction, LogsSearchSubmitAction, LogsSearchToggleAction,
+    },
     operations::{OperationsStatisticsGetAction, OperationsStatisticsReceivedAction},
-    rpc::{RpcRequestAction, RpcResponseAction, RpcResponseReadAction},
+    rights::RightsCycleGetAction,
+    rpc::{RpcRequestAction, RpcRequestNotSentAction, RpcResponseAction, RpcResponseReadAction},
     terminal_ui::{
         BestRemoteLevelChangedAction, BestRemoteLevelGetAction, BestRemoteLevelReceivedAction,
         ChangeScreenAction, CurrentHeadHeaderChangedAction, CurrentHeadHeaderGetAction,
         CurrentHeadHeaderRecievedAction, CurrentHeadMetadataChangedAction,
         CurrentHeadMetadataGetAction, CurrentHeadMetadataReceivedAction, CycleChangedAction,
         DrawScreenAction, DrawScreenFailiureAction, DrawScreenSuccessAction,
-        NetworkConstantsGetAction, NetworkConstantsReceivedAction, TuiDeltaToggleKeyPushedAction,
-        TuiDownKeyPushedAction, TuiLeftKeyPushedAction, TuiRightKeyPushedAction,
+        NetworkConstantsGetAction, NetworkConstantsReceivedAction, TuiAlertLogKeyPushedAction,
+        TuiChainInfoKeyPushedAction, TuiColumnChooserKeyPushedAction, TuiColumnEditKeyPushedAction,
+        TuiDeltaToggleKeyPushedAction, TuiDownKeyPushedAction, TuiFollowKeyPushedAction,
+        TuiLeftKeyPushedAction, TuiMemoryPanelKeyPushedAction, TuiRightKeyPushedAction,
         TuiSortKeyPushedAction, TuiUpKeyPushedAction, TuiWidgetSelectionKeyPushedAction,
     },
+    trends::{MempoolSizeGetAction, MempoolSizeReceivedAction},
     websocket::{WebsocketMessageReceivedAction, WebsocketReadAction},
 };
 
@@ -70,6 +95,7 @@ pub enum Action {
     Shutdown(ShutdownAction),
 
     RpcRequest(RpcRequestAction),
+    RpcRequestNotSent(RpcRequestNotSentAction),
     RpcResponse(RpcResponseAction),
     RpcResponseRead(RpcResponseReadAction),
 
@@ -84,6 +110,8 @@ pub enum Action {
     EndorsementsRightsWithTimeReceived(EndorsementsRightsWithTimeReceivedAction),
     MempoolEndorsementStatsGet(MempoolEndorsementStatsGetAction),
     MempoolEndorsementStatsReceived(MempoolEndorsementStatsReceivedAction),
+    MempoolSizeGet(MempoolSizeGetAction),
+    MempoolSizeReceived(MempoolSizeReceivedAction),
 
     CurrentHeadHeaderGet(CurrentHeadHeaderGetAction),
     CurrentHeadHeaderReceived(CurrentHeadHeaderRecievedAction),
@@ -108,6 +136,8 @@ pub enum Action {
     BakingRightsReceived(BakingRightsReceivedAction),
     BakingRightsGet(BakingRightsGetAction),
 
+    RightsCycleGet(RightsCycleGetAction),
+
     ChangeScreen(ChangeScreenAction),
     DrawScreen(DrawScreenAction),
     DrawScreenSuccess(DrawScreenSuccessAction),
@@ -118,7 +148,145 @@ pub enum Action {
     TuiDownKeyPushedAction(TuiDownKeyPushedAction),
     TuiSortKeyPushed(TuiSortKeyPushedAction),
     TuiDeltaToggleKeyPushed(TuiDeltaToggleKeyPushedAction),
+    TuiFollowKeyPushed(TuiFollowKeyPushedAction),
+    TuiColumnChooserKeyPushed(TuiColumnChooserKeyPushedAction),
+    TuiColumnEditKeyPushed(TuiColumnEditKeyPushedAction),
+    TuiChainInfoKeyPushed(TuiChainInfoKeyPushedAction),
+    TuiAlertLogKeyPushed(TuiAlertLogKeyPushedAction),
+    TuiMemoryPanelKeyPushed(TuiMemoryPanelKeyPushedAction),
     TuiWidgetSelectionKeyPushed(TuiWidgetSelectionKeyPushedAction),
+
+    ExportMenuToggle(ExportMenuToggleAction),
+    ExportRawToggle(ExportRawToggleAction),
+    ExportTable(ExportTableAction),
+    ExportScreen(ExportScreenAction),
+    ExportSuccess(ExportSuccessAction),
+    ExportFailure(ExportFailureAction),
+
+    ProtocolActivated(ProtocolActivatedAction),
+    ProtocolActivationDismiss(ProtocolActivationDismissAction),
+    ProposalsGet(ProposalsGetAction),
+    ProposalsReceived(ProposalsReceivedAction),
+
+    BlockDetailsToggle(BlockDetailsToggleAction),
+
+    HistoryPromptToggle(HistoryPromptToggleAction),
+    HistoryPromptEdit(HistoryPromptEditAction),
+    HistoryLevelSubmit(HistoryLevelSubmitAction),
+    HistoryLevelGet(HistoryLevelGetAction),
+    HistoryApplicationStatisticsReceived(HistoryApplicationStatisticsReceivedAction),
+    HistoryPerPeerBlockStatisticsReceived(HistoryPerPeerBlockStatisticsReceivedAction),
+    HistoryClose(HistoryCloseAction),
+
+    LatencyWindowToggle(LatencyWindowToggleAction),
+
+    LogsGet(LogsGetAction),
+    LogsReceived(LogsReceivedAction),
+    LogsLevelFilter(LogsLevelFilterAction),
+    LogsModuleFilter(LogsModuleFilterAction),
+    LogsSearchToggle(LogsSearchToggleAction),
+    LogsSearchEdit(LogsSearchEditAction),
+    LogsSearchSubmit(LogsSearchSubmitAction),
+
+    InspectorRpcReportsGet(InspectorRpcReportsGetAction),
+    InspectorRpcReportsReceived(InspectorRpcReportsReceivedAction),
+
+    AlertsDelivered(AlertsDeliveredAction),
+    AlertDismiss(AlertDismissAction),
+}
+
+/// Lets the recorded actions be dispatched as they are, each variant keeps its own enabling condition
+impl EnablingCondition<State> for Action {
+    fn is_enabled(&self, state: &State) -> bool {
+        match self {
+            Action::Init(action) => action.is_enabled(state),
+            Action::Shutdown(action) => action.is_enabled(state),
+            Action::RpcRequest(action) => action.is_enabled(state),
+            Action::RpcRequestNotSent(action) => action.is_enabled(state),
+            Action::RpcResponse(action) => action.is_enabled(state),
+            Action::RpcResponseRead(action) => action.is_enabled(state),
+            Action::WebsocketRead(action) => action.is_enabled(state),
+            Action::WebsocketMessageReceived(action) => action.is_enabled(state),
+            Action::EndorsementsRightsGet(action) => action.is_enabled(state),
+            Action::EndorsementsRightsReceived(action) => action.is_enabled(state),
+            Action::EndorsementsStatusesGet(action) => action.is_enabled(state),
+            Action::EndorsementsStatusesReceived(action) => action.is_enabled(state),
+            Action::EndorsementsRightsWithTimeGet(action) => action.is_enabled(state),
+            Action::EndorsementsRightsWithTimeReceived(action) => action.is_enabled(state),
+            Action::MempoolEndorsementStatsGet(action) => action.is_enabled(state),
+            Action::MempoolEndorsementStatsReceived(action) => action.is_enabled(state),
+            Action::MempoolSizeGet(action) => action.is_enabled(state),
+            Action::MempoolSizeReceived(action) => action.is_enabled(state),
+            Action::CurrentHeadHeaderGet(action) => action.is_enabled(state),
+            Action::CurrentHeadHeaderReceived(action) => action.is_enabled(state),
+            Action::CurrentHeadHeaderChanged(action) => action.is_enabled(state),
+            Action::CycleChanged(action) => action.is_enabled(state),
+            Action::NetworkConstantsGet(action) => action.is_enabled(state),
+            Action::NetworkConstantsReceived(action) => action.is_enabled(state),
+            Action::CurrentHeadMetadataGet(action) => action.is_enabled(state),
+            Action::CurrentHeadMetadataReceived(action) => action.is_enabled(state),
+            Action::CurrentHeadMetadataChanged(action) => action.is_enabled(state),
+            Action::BestRemoteLevelGet(action) => action.is_enabled(state),
+            Action::BestRemoteLevelReceived(action) => action.is_enabled(state),
+            Action::BestRemoteLevelChanged(action) => action.is_enabled(state),
+            Action::OperationsStatisticsGet(action) => action.is_enabled(state),
+            Action::OperationsStatisticsReceived(action) => action.is_enabled(state),
+            Action::ApplicationStatisticsGet(action) => action.is_enabled(state),
+            Action::ApplicationStatisticsReceived(action) => action.is_enabled(state),
+            Action::PerPeerBlockStatisticsGet(action) => action.is_enabled(state),
+            Action::PerPeerBlockStatisticsReceived(action) => action.is_enabled(state),
+            Action::BakingRightsReceived(action) => action.is_enabled(state),
+            Action::BakingRightsGet(action) => action.is_enabled(state),
+            Action::RightsCycleGet(action) => action.is_enabled(state),
+            Action::ChangeScreen(action) => action.is_enabled(state),
+            Action::DrawScreen(action) => action.is_enabled(state),
+            Action::DrawScreenSuccess(action) => action.is_enabled(state),
+            Action::DrawScreenFailiure(action) => action.is_enabled(state),
+            Action::TuiRightKeyPushed(action) => action.is_enabled(state),
+            Action::TuiLeftKeyPushed(action) => action.is_enabled(state),
+            Action::TuiUpKeyPushedAction(action) => action.is_enabled(state),
+            Action::TuiDownKeyPushedAction(action) => action.is_enabled(state),
+            Action::TuiSortKeyPushed(action) => action.is_enabled(state),
+            Action::TuiDeltaToggleKeyPushed(action) => action.is_enabled(state),
+            Action::TuiFollowKeyPushed(action) => action.is_enabled(state),
+            Action::TuiColumnChooserKeyPushed(action) => action.is_enabled(state),
+            Action::TuiColumnEditKeyPushed(action) => action.is_enabled(state),
+            Action::TuiChainInfoKeyPushed(action) => action.is_enabled(state),
+            Action::TuiAlertLogKeyPushed(action) => action.is_enabled(state),
+            Action::TuiMemoryPanelKeyPushed(action) => action.is_enabled(state),
+            Action::TuiWidgetSelectionKeyPushed(action) => action.is_enabled(state),
+            Action::ExportMenuToggle(action) => action.is_enabled(state),
+            Action::ExportRawToggle(action) => action.is_enabled(state),
+            Action::ExportTable(action) => action.is_enabled(state),
+            Action::ExportScreen(action) => action.is_enabled(state),
+            Action::ExportSuccess(action) => action.is_enabled(state),
+            Action::ExportFailure(action) => action.is_enabled(state),
+            Action::ProtocolActivated(action) => action.is_enabled(state),
+            Action::ProtocolActivationDismiss(action) => action.is_enabled(state),
+            Action::ProposalsGet(action) => action.is_enabled(state),
+            Action::ProposalsReceived(action) => action.is_enabled(state),
+            Action::BlockDetailsToggle(action) => action.is_enabled(state),
+            Action::HistoryPromptToggle(action) => action.is_enabled(state),
+            Action::HistoryPromptEdit(action) => action.is_enabled(state),
+            Action::HistoryLevelSubmit(action) => action.is_enabled(state),
+            Action::HistoryLevelGet(action) => action.is_enabled(state),
+            Action::HistoryApplicationStatisticsReceived(action) => action.is_enabled(state),
+            Action::HistoryPerPeerBlockStatisticsReceived(action) => action.is_enabled(state),
+            Action::HistoryClose(action) => action.is_enabled(state),
+            Action::LatencyWindowToggle(action) => action.is_enabled(state),
+            Action::LogsGet(action) => action.is_enabled(state),
+            Action::LogsReceived(action) => action.is_enabled(state),
+            Action::LogsLevelFilter(action) => action.is_enabled(state),
+            Action::LogsModuleFilter(action) => action.is_enabled(state),
+            Action::LogsSearchToggle(action) => action.is_enabled(state),
+            Action::LogsSearchEdit(action) => action.is_enabled(state),
+            Action::LogsSearchSubmit(action) => action.is_enabled(state),
+            Action::InspectorRpcReportsGet(action) => action.is_enabled(state),
+            Action::InspectorRpcReportsReceived(action) => action.is_enabled(state),
+            Action::AlertsDelivered(action) => action.is_enabled(state),
+            Action::AlertDismiss(action) => action.is_enabled(state),
+        }
+    }
 }
 
 impl Action {
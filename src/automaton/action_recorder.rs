@@ -0,0 +1,337 @@
+use std::{
+    ffi::OsString,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use serde::{Deserialize, Serialize};
+use slog::{info, warn, Logger};
+use thiserror::Error;
+
+use crate::automaton::{ActionWithMeta, Service, State, Store};
+
+/// Actions recorded between two checkpoints of the state
+pub const ACTION_CHECKPOINT_INTERVAL: usize = 10_000;
+
+/// Size of the recording file after which the recording continues in a new file
+pub const ACTION_RECORDING_ROTATE_BYTES: u64 = 64 * 1024 * 1024;
+
+/// Number of the rotated files kept next to the current one, `<path>.1` is the newest
+pub const ACTION_RECORDING_ROTATED_FILES: usize = 3;
+
+#[derive(Error, Debug)]
+pub enum ActionRecordingError {
+    #[error("Action recording io error: {0}")]
+    Io(#[from] io::Error),
+    #[error("Action record is not valid json: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("Invalid action record on line {line}: {source}")]
+    Record {
+        line: usize,
+        source: serde_json::Error,
+    },
+    #[error("The action recording does not start with a state checkpoint")]
+    NoCheckpoint,
+    #[error("The action recording has no checkpoint {0}")]
+    MissingCheckpoint(usize),
+}
+
+/// A line of the action recording
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(clippy::large_enum_variant)]
+pub enum ActionRecord {
+    /// State before the next action, only taken before the top level actions
+    Checkpoint(State),
+    Action(ActionWithMeta),
+}
+
+/// Serialized like `ActionRecord`, without cloning the state
+#[derive(Serialize)]
+#[serde(rename = "ActionRecord")]
+enum ActionRecordRef<'a> {
+    Checkpoint(&'a State),
+    Action(&'a ActionWithMeta),
+}
+
+/// Streams the dispatched actions to a rotating file, a json record per line, with periodic checkpoints of the state.
+/// Every file starts with a checkpoint, so each of them can be replayed on its own. Held by the services and written
+/// by the effects, the owner records the initial state with [ActionRecorder::checkpoint]
+#[derive(Debug, Clone)]
+pub struct ActionRecorder {
+    file: Arc<Mutex<RecordingFile>>,
+    log: Logger,
+}
+
+#[derive(Debug)]
+struct RecordingFile {
+    path: PathBuf,
+    writer: BufWriter<File>,
+    written: u64,
+    since_checkpoint: usize,
+}
+
+impl ActionRecorder {
+    pub fn create(path: &Path, log: &Logger) -> Result<Self, ActionRecordingError> {
+        let writer = BufWriter::new(File::create(path)?);
+        info!(log, "Recording the actions to {}", path.display());
+
+        Ok(Self {
+            file: Arc::new(Mutex::new(RecordingFile {
+                path: path.to_path_buf(),
+                writer,
+                written: 0,
+                since_checkpoint: 0,
+            })),
+            log: log.clone(),
+        })
+    }
+
+    /// Record the action, the actions are recorded in the order they are reduced
+    pub fn record(&self, action: &ActionWithMeta) {
+        // the lock is only poisoned by a panic while writing, the recording is lost anyway
+        let mut file = self.file.lock().unwrap();
+        if let Err(e) = file.write(&ActionRecordRef::Action(action)) {
+            warn!(self.log, "Failed to record the action: {}", e);
+        }
+        file.since_checkpoint += 1;
+    }
+
+    /// Called once the nested actions of a top level action are reduced, with the state before the next top level action.
+    /// Takes the periodic checkpoint, rotates the file and flushes the recording
+    pub fn top_level_done(&self, state: &State) {
+        let mut file = self.file.lock().unwrap();
+        if let Err(e) = file.top_level_done(state) {
+            warn!(self.log, "Failed to record the checkpoint: {}", e);
+        }
+    }
+
+    /// Record the state before the next top level action, the recording starts with one
+    pub fn checkpoint(&self, state: &State) {
+        let mut file = self.file.lock().unwrap();
+        if let Err(e) = file.checkpoint(state) {
+            warn!(self.log, "Failed to record the checkpoint: {}", e);
+        }
+    }
+
+    /// Record the state the recording ends in
+    pub fn finish(&self, state: &State) {
+        let mut file = self.file.lock().unwrap();
+        if let Err(e) = file.checkpoint(state) {
+            warn!(self.log, "Failed to record the end state: {}", e);
+        }
+    }
+}
+
+impl RecordingFile {
+    fn top_level_done(&mut self, state: &State) -> Result<(), ActionRecordingError> {
+        // the nested actions are reduced on top of the state of their top level action, a checkpoint
+        // taken between them could not be replayed by dispatching the top level actions
+        if self.written >= ACTION_RECORDING_ROTATE_BYTES {
+            self.rotate()?;
+        }
+        if self.since_checkpoint >= ACTION_CHECKPOINT_INTERVAL {
+            self.checkpoint(state)
+        } else {
+            // flushed after every top level action, so the recording survives a crash of the tui
+            Ok(self.writer.flush()?)
+        }
+    }
+
+    fn checkpoint(&mut self, state: &State) -> Result<(), ActionRecordingError> {
+        self.write(&ActionRecordRef::Checkpoint(state))?;
+        self.writer.flush()?;
+        self.since_checkpoint = 0;
+        Ok(())
+    }
+
+    fn write(&mut self, record: &ActionRecordRef) -> Result<(), ActionRecordingError> {
+        let line = serde_json::to_vec(record)?;
+        self.writer.write_all(&line)?;
+        self.writer.write_all(b"\n")?;
+        self.written += line.len() as u64 + 1;
+        Ok(())
+    }
+
+    /// Move the current file to `<path>.1`, the older files one further and continue in a new file
+    fn rotate(&mut self) -> Result<(), ActionRecordingError> {
+        self.writer.flush()?;
+        for index in (1..ACTION_RECORDING_ROTATED_FILES).rev() {
+            let rotated = rotated_path(&self.path, index);
+            if rotated.exists() {
+                fs::rename(rotated, rotated_path(&self.path, index + 1))?;
+            }
+        }
+        fs::rename(&self.path, rotated_path(&self.path, 1))?;
+
+        self.writer = BufWriter::new(File::create(&self.path)?);
+        self.written = 0;
+        self.since_checkpoint = ACTION_CHECKPOINT_INTERVAL;
+        Ok(())
+    }
+}
+
+/// Path of the `index`th newest rotated file of the recording
+pub fn rotated_path(path: &Path, index: usize) -> PathBuf {
+    let mut rotated = OsString::from(path.as_os_str());
+    rotated.push(format!(".{}", index));
+    PathBuf::from(rotated)
+}
+
+/// Checkpoint of the recorded state
+#[derive(Debug, Clone)]
+pub struct RecordedCheckpoint {
+    /// Index of the action the checkpoint was taken before, the end state is after the last action
+    pub position: usize,
+    pub state: State,
+}
+
+/// Beginning of the dumps of the older versions, the fields are written in the order of the struct
+const LEGACY_DUMP_START: &str = "{\"init_state\":";
+
+/// Parts of the state the older versions compared after replaying their dump, the parts added since
+/// are missing in the recorded end state
+pub const LEGACY_DUMP_COMPARED: [&str; 17] = [
+    "network_constants",
+    "last_applied_level",
+    "current_head_header",
+    "current_head_metadata",
+    "previous_head_header",
+    "best_remote_level",
+    "baker_address",
+    "baking.last_baked_block_hash",
+    "baking.last_baked_block_level",
+    "baking.last_baking_summary",
+    "baking.baking_rights",
+    "baking.application_statistics",
+    "baking.per_peer_block_statistics",
+    "baking.baking_table",
+    "endorsmenents",
+    "operations_statistics",
+    "delta_toggle",
+];
+
+/// Dump of a whole run written by the older versions, `automaton_dump.json`
+#[derive(Deserialize)]
+struct AutomatonDump {
+    init_state: State,
+    end_state: State,
+    actions: Vec<ActionWithMeta>,
+}
+
+impl From<AutomatonDump> for ActionRecording {
+    fn from(dump: AutomatonDump) -> Self {
+        Self {
+            checkpoints: vec![
+                RecordedCheckpoint {
+                    position: 0,
+                    state: dump.init_state,
+                },
+                RecordedCheckpoint {
+                    position: dump.actions.len(),
+                    state: dump.end_state,
+                },
+            ],
+            actions: dump.actions,
+            compared: Some(&LEGACY_DUMP_COMPARED),
+        }
+    }
+}
+
+/// An action recording read back from a file
+#[derive(Debug, Clone)]
+pub struct ActionRecording {
+    /// The checkpoints in the order they were taken, the first one is the initial state
+    pub checkpoints: Vec<RecordedCheckpoint>,
+    pub actions: Vec<ActionWithMeta>,
+    /// Paths of the state compared to the replayed one, the whole state if `None`
+    pub compared: Option<&'static [&'static str]>,
+}
+
+impl ActionRecording {
+    /// Load a recording, or a dump of the older versions
+    pub fn load(path: &Path) -> Result<Self, ActionRecordingError> {
+        let reader = BufReader::new(File::open(path)?);
+        let mut checkpoints = Vec::new();
+        let mut actions = Vec::new();
+
+        let mut lines = reader.lines().enumerate().peekable();
+        while let Some((index, line)) = lines.next() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            // the older versions dumped the whole run as a single json object
+            if checkpoints.is_empty() && line.starts_with(LEGACY_DUMP_START) {
+                return serde_json::from_str::<AutomatonDump>(&line)
+                    .map(ActionRecording::from)
+                    .map_err(|source| ActionRecordingError::Record {
+                        line: index + 1,
+                        source,
+                    });
+            }
+            let record = match serde_json::from_str(&line) {
+                Ok(record) => record,
+                // a crash of the tui can cut the last record short
+                Err(_) if lines.peek().is_none() => break,
+                Err(source) => {
+                    return Err(ActionRecordingError::Record {
+                        line: index + 1,
+                        source,
+                    })
+                }
+            };
+            match record {
+                ActionRecord::Checkpoint(state) => checkpoints.push(RecordedCheckpoint {
+                    position: actions.len(),
+                    state,
+                }),
+                ActionRecord::Action(_) if checkpoints.is_empty() => {
+                    return Err(ActionRecordingError::NoCheckpoint)
+                }
+                ActionRecord::Action(action) => actions.push(action),
+            }
+        }
+
+        if checkpoints.is_empty() {
+            return Err(ActionRecordingError::NoCheckpoint);
+        }
+        Ok(Self {
+            checkpoints,
+            actions,
+            compared: None,
+        })
+    }
+
+    /// The state of the checkpoint with the actions recorded from it to the last checkpoint,
+    /// `None` if there is no such checkpoint
+    pub fn resume(&self, checkpoint: usize) -> Option<(&State, &[ActionWithMeta])> {
+        let end = self.checkpoints.last()?.position;
+        self.checkpoints
+            .get(checkpoint)
+            .map(|checkpoint| (&checkpoint.state, &self.actions[checkpoint.position..end]))
+    }
+
+    /// State of the last checkpoint, the end state of a recording the tui finished
+    pub fn end_state(&self) -> Option<&State> {
+        self.checkpoints.last().map(|checkpoint| &checkpoint.state)
+    }
+}
+
+/// First in the effects, before the nested actions are dispatched
+pub fn action_recorder_effects<S: Service>(store: &mut Store<S>, action: &ActionWithMeta) {
+    if let Some(recorder) = store.service().action_recorder() {
+        recorder.record(action);
+    }
+}
+
+/// Last in the effects, the nested actions of a top level action are reduced by then
+pub fn action_checkpoint_effects<S: Service>(store: &mut Store<S>, action: &ActionWithMeta) {
+    if action.depth > 0 {
+        return;
+    }
+    if let Some(recorder) = store.service().action_recorder().cloned() {
+        recorder.top_level_done(store.state());
+    }
+}
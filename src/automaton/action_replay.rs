@@ -0,0 +1,205 @@
+use std::fmt;
+
+use crate::{
+    debugger::{diff_states, StateChange},
+    services::Service,
+};
+
+use super::{reducer, ActionRecording, ActionRecordingError, ActionWithMeta, Automaton, State};
+
+/// The first top level action after which the replayed state differs from the recorded one
+#[derive(Debug, Clone)]
+pub struct ReplayDivergence {
+    /// Index of the action in the recording
+    pub position: usize,
+    pub action: ActionWithMeta,
+    /// Changes from the recorded state to the replayed one
+    pub changes: Vec<StateChange>,
+}
+
+/// Outcome of replaying an action recording
+#[derive(Debug, Clone)]
+pub struct ReplayReport {
+    /// Number of the top level actions dispatched again, the nested ones are dispatched by the effects
+    pub replayed: usize,
+    /// Changes from the recorded end state to the replayed one
+    pub changes: Vec<StateChange>,
+    /// Only reported when the end state does not match
+    pub divergence: Option<ReplayDivergence>,
+}
+
+impl ReplayReport {
+    pub fn matches(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl fmt::Display for ReplayReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.matches() {
+            return write!(
+                f,
+                "Replayed {} actions, the end state matches the recording",
+                self.replayed
+            );
+        }
+
+        let changes = match &self.divergence {
+            Some(divergence) => {
+                writeln!(
+                    f,
+                    "Replayed {} actions, the state differs from the recording after action {} ({})",
+                    self.replayed,
+                    divergence.position,
+                    divergence.action.action.kind()
+                )?;
+                &divergence.changes
+            }
+            None => {
+                writeln!(
+                    f,
+                    "Replayed {} actions, the end state differs from the recording",
+                    self.replayed
+                )?;
+                &self.changes
+            }
+        };
+        for change in changes {
+            writeln!(
+                f,
+                "    {}: {} -> {}",
+                change.path,
+                display_value(&change.before),
+                display_value(&change.after)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn display_value(value: &Option<serde_json::Value>) -> String {
+    value
+        .as_ref()
+        .map(|value| value.to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+impl<Serv: Service> Automaton<Serv> {
+    /// Replay the actions recorded from the checkpoint and compare the states with the recording.
+    /// Only the top level actions are dispatched, the effects dispatch the nested ones with the `service`.
+    /// The changes of the `ignored` paths of the serialized state, and of the values inside of them,
+    /// are not reported, e.g. the parts of the state depending on the time of the actions. Only the
+    /// paths compared by the recording are reported, see [ActionRecording::compared]
+    pub fn replay_actions(
+        recording: &ActionRecording,
+        checkpoint: usize,
+        service: Serv,
+        ignored: &[&str],
+    ) -> Result<(Self, ReplayReport), ActionRecordingError> {
+        let (init_state, actions) = recording
+            .resume(checkpoint)
+            .ok_or(ActionRecordingError::MissingCheckpoint(checkpoint))?;
+        let offset = recording.checkpoints[checkpoint].position;
+
+        let mut automaton = Self::new(init_state.clone(), service);
+        // the recorded state, reproduced by reducing the recorded actions of every depth
+        let mut recorded = init_state.clone();
+        let mut divergence = None;
+        let mut replayed = 0;
+        let mut last_top_level = None;
+        for (index, action) in actions.iter().enumerate() {
+            if action.depth == 0 {
+                // the previous top level action is compared once its nested actions are reduced
+                if let Some(position) = last_top_level.take() {
+                    check_divergence(
+                        &recorded,
+                        &automaton,
+                        position,
+                        recording,
+                        ignored,
+                        &mut divergence,
+                    );
+                }
+                automaton.dispatch_action(action.action.clone());
+                replayed += 1;
+                last_top_level = Some(offset + index);
+            }
+            reducer(&mut recorded, action);
+        }
+        if let Some(position) = last_top_level {
+            check_divergence(
+                &recorded,
+                &automaton,
+                position,
+                recording,
+                ignored,
+                &mut divergence,
+            );
+        }
+
+        // a recording that could be resumed has an end state
+        let changes = recording
+            .end_state()
+            .map(|end_state| relevant_changes(end_state, automaton.state(), recording, ignored))
+            .unwrap_or_default();
+        if changes.is_empty() {
+            divergence = None;
+        }
+
+        let report = ReplayReport {
+            replayed,
+            changes,
+            divergence,
+        };
+        Ok((automaton, report))
+    }
+}
+
+fn check_divergence<Serv: Service>(
+    recorded: &State,
+    automaton: &Automaton<Serv>,
+    position: usize,
+    recording: &ActionRecording,
+    ignored: &[&str],
+    divergence: &mut Option<ReplayDivergence>,
+) {
+    if divergence.is_some() {
+        return;
+    }
+    let changes = relevant_changes(recorded, automaton.state(), recording, ignored);
+    if !changes.is_empty() {
+        *divergence = Some(ReplayDivergence {
+            position,
+            action: recording.actions[position].clone(),
+            changes,
+        });
+    }
+}
+
+fn relevant_changes(
+    recorded: &State,
+    replayed: &State,
+    recording: &ActionRecording,
+    ignored: &[&str],
+) -> Vec<StateChange> {
+    diff_states(recorded, replayed)
+        .into_iter()
+        .filter(|change| {
+            let compared = match recording.compared {
+                Some(compared) => is_within(&change.path, compared),
+                None => true,
+            };
+            compared && !is_within(&change.path, ignored)
+        })
+        .collect()
+}
+
+/// Whether the path is one of the paths or leads inside of one
+fn is_within(path: &str, paths: &[&str]) -> bool {
+    paths.iter().any(|within| {
+        matches!(
+            path.strip_prefix(within),
+            Some(rest) if rest.is_empty() || rest.starts_with('.') || rest.starts_with('[')
+        )
+    })
+}
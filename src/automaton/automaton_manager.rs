@@ -1,44 +1,71 @@
-use crossterm::event::KeyCode;
+use crossterm::event::{KeyCode, KeyModifiers};
 use std::{
-    fs::File,
+    path::PathBuf,
     time::{Duration, SystemTime},
 };
 use tokio::sync::mpsc;
 use url::Url;
 
-use slog::{info, Logger};
+use slog::info;
 
 pub use crate::services::{Service, ServiceDefault};
 use crate::{
+    alerts::AlertDismissAction,
     baking::{
         ApplicationStatisticsGetAction, ApplicationStatisticsReceivedAction,
         BakingRightsReceivedAction, PerPeerBlockStatisticsGetAction,
         PerPeerBlockStatisticsReceivedAction,
     },
+    blocks::BlockDetailsToggleAction,
     endorsements::{
         EndorsementsRightsReceivedAction, EndorsementsRightsWithTimeReceivedAction,
         EndorsementsStatusesGetAction, EndorsementsStatusesReceivedAction,
         MempoolEndorsementStatsGetAction, MempoolEndorsementStatsReceivedAction,
     },
-    extensions::AutomatonDump,
+    export::{
+        ExportMenuToggleAction, ExportRawToggleAction, ExportScreenAction, ExportTableAction,
+    },
+    extensions::{ColumnEdit, ExportFormat},
+    governance::{ProposalsReceivedAction, ProtocolActivationDismissAction},
+    history::{
+        HistoryApplicationStatisticsReceivedAction, HistoryCloseAction, HistoryLevelSubmitAction,
+        HistoryPerPeerBlockStatisticsReceivedAction, HistoryPromptEditAction,
+        HistoryPromptToggleAction, PromptEdit,
+    },
+    inspector::InspectorRpcReportsGetAction,
+    latency::LatencyWindowToggleAction,
+    logs::{
+        LogsGetAction, LogsLevelFilterAction, LogsModuleFilterAction, LogsSearchEditAction,
+        LogsSearchSubmitAction, LogsSearchToggleAction,
+    },
     operations::OperationsStatisticsReceivedAction,
     services::{
-        rpc_service_async::{RpcResponse, RpcService, RpcServiceDefault},
-        tui_service::{TuiService, TuiServiceDefault},
-        ws_service::WebsocketServiceDefault,
+        alert_service::AlertServiceDefault,
+        config_service::ConfigServiceDefault,
+        export_service::ExportServiceDefault,
+        log_service::LogServiceDefault,
+        rpc_service_async::{RpcResponse, RpcService, RpcServiceDefault, RpcServiceReplay},
+        traffic_service::{
+            start_replay, ReplayControl, ReplayPace, TrafficRecord, TrafficRecorder,
+        },
+        tui_service::{TuiError, TuiService, TuiServiceDefault},
+        ws_service::{WebsocketService, WebsocketServiceDefault, WebsocketServiceReplay},
     },
     terminal_ui::{
         ActivePage, BestRemoteLevelGetAction, BestRemoteLevelReceivedAction, ChangeScreenAction,
         CurrentHeadHeaderGetAction, CurrentHeadHeaderRecievedAction, CurrentHeadMetadataGetAction,
         CurrentHeadMetadataReceivedAction, DrawScreenAction, NetworkConstantsGetAction,
-        NetworkConstantsReceivedAction, TuiDeltaToggleKeyPushedAction, TuiDownKeyPushedAction,
-        TuiEvent, TuiLeftKeyPushedAction, TuiRightKeyPushedAction, TuiSortKeyPushedAction,
-        TuiUpKeyPushedAction, TuiWidgetSelectionKeyPushedAction,
+        NetworkConstantsReceivedAction, TuiAlertLogKeyPushedAction, TuiChainInfoKeyPushedAction,
+        TuiColumnChooserKeyPushedAction, TuiColumnEditKeyPushedAction,
+        TuiDeltaToggleKeyPushedAction, TuiDownKeyPushedAction, TuiEvent, TuiFollowKeyPushedAction,
+        TuiLeftKeyPushedAction, TuiMemoryPanelKeyPushedAction, TuiRightKeyPushedAction,
+        TuiSortKeyPushedAction, TuiUpKeyPushedAction, TuiWidgetSelectionKeyPushedAction,
     },
+    trends::{MempoolSizeGetAction, MempoolSizeReceivedAction},
     websocket::WebsocketReadAction,
 };
 
-use super::{effects, reducer, Action, ShutdownAction, State};
+use super::{effects, reducer, Action, ActionRecorder, ShutdownAction, State};
 
 pub type Store<Service> = redux_rs::Store<State, Service, Action>;
 
@@ -47,13 +74,33 @@ pub struct Automaton<Serv> {
 }
 
 impl<Serv: Service> Automaton<Serv> {
-    pub fn new(initial_state: State, service: Serv) -> Self {
+    pub fn new(initial_state: State, mut service: Serv) -> Self {
+        if let Some(recorder) = service.action_recorder() {
+            recorder.checkpoint(&initial_state);
+        }
         let store = Store::new(reducer, effects, service, SystemTime::now(), initial_state);
 
         Self { store }
     }
 
-    pub async fn make_progress(&mut self, events: &mut mpsc::Receiver<TuiEvent>) {
+    /// Dispatch any action, e.g. a recorded one, through its own enabling condition
+    pub fn dispatch_action(&mut self, action: Action) -> bool {
+        self.store.dispatch(action)
+    }
+
+    pub fn state(&self) -> &State {
+        self.store.state()
+    }
+
+    pub fn service(&mut self) -> &mut Serv {
+        self.store.service()
+    }
+
+    pub async fn make_progress(
+        &mut self,
+        events: &mut mpsc::Receiver<TuiEvent>,
+        replay: Option<&ReplayControl>,
+    ) {
         self.store.dispatch(NetworkConstantsGetAction {});
         loop {
             // TODO: clean this up (create handler functions)
@@ -75,6 +122,90 @@ impl<Serv: Service> Automaton<Serv> {
                                 level: self.store.state().current_head_header.level,
                             });
                             self.store.dispatch(MempoolEndorsementStatsGetAction {});
+                            self.store.dispatch(MempoolSizeGetAction {});
+                            self.store.dispatch(LogsGetAction {});
+                            self.store.dispatch(InspectorRpcReportsGetAction {});
+                        }
+                        // the export menu takes over the keys while it is open
+                        Some(TuiEvent::Input(key, _))
+                            if self.store.state().export.menu_open && key != KeyCode::F(10) =>
+                        {
+                            match key {
+                                KeyCode::Char('c') => {
+                                    self.store.dispatch(ExportTableAction {
+                                        format: ExportFormat::Csv,
+                                    });
+                                }
+                                KeyCode::Char('j') => {
+                                    self.store.dispatch(ExportTableAction {
+                                        format: ExportFormat::Json,
+                                    });
+                                }
+                                KeyCode::Char('m') => {
+                                    self.store.dispatch(ExportTableAction {
+                                        format: ExportFormat::Markdown,
+                                    });
+                                }
+                                KeyCode::Char('r') => {
+                                    self.store.dispatch(ExportRawToggleAction {});
+                                }
+                                KeyCode::Char('t') => {
+                                    self.store.dispatch(ExportScreenAction {});
+                                }
+                                KeyCode::Char('e') | KeyCode::Esc => {
+                                    self.store.dispatch(ExportMenuToggleAction {});
+                                }
+                                _ => {}
+                            }
+                        }
+                        // the level prompt takes over the keys while it is open
+                        Some(TuiEvent::Input(key, _))
+                            if self.store.state().history.prompt.is_some()
+                                && key != KeyCode::F(10) =>
+                        {
+                            match key {
+                                KeyCode::Char(digit) => {
+                                    self.store.dispatch(HistoryPromptEditAction {
+                                        edit: PromptEdit::Push(digit),
+                                    });
+                                }
+                                KeyCode::Backspace => {
+                                    self.store.dispatch(HistoryPromptEditAction {
+                                        edit: PromptEdit::Pop,
+                                    });
+                                }
+                                KeyCode::Enter => {
+                                    self.store.dispatch(HistoryLevelSubmitAction {});
+                                }
+                                KeyCode::Esc => {
+                                    self.store.dispatch(HistoryPromptToggleAction {});
+                                }
+                                _ => {}
+                            }
+                        }
+                        // the log search prompt takes over the keys while it is open
+                        Some(TuiEvent::Input(key, _))
+                            if self.store.state().logs.prompt.is_some() && key != KeyCode::F(10) =>
+                        {
+                            match key {
+                                KeyCode::Char(character) => {
+                                    self.store.dispatch(LogsSearchEditAction {
+                                        edit: PromptEdit::Push(character),
+                                    });
+                                }
+                                KeyCode::Backspace => {
+                                    self.store.dispatch(LogsSearchEditAction {
+                                        edit: PromptEdit::Pop,
+                                    });
+                                }
+                                KeyCode::Enter => {
+                                    self.store.dispatch(LogsSearchSubmitAction {});
+                                }
+                                KeyCode::Esc => {
+                                    self.store.dispatch(LogsSearchToggleAction {});
+                                }
+                                _ => {}
+                            }
                         }
                         Some(TuiEvent::Input(key, modifier)) => match key {
                             KeyCode::F(10) => {
@@ -84,9 +215,106 @@ impl<Serv: Service> Automaton<Serv> {
                             KeyCode::Char('s') => {
                                 self.store.dispatch(TuiSortKeyPushedAction { modifier });
                             }
+                            KeyCode::Char('S') => {
+                                self.store.dispatch(TuiSortKeyPushedAction {
+                                    modifier: modifier | KeyModifiers::SHIFT,
+                                });
+                            }
                             KeyCode::Char('d') => {
                                 self.store.dispatch(TuiDeltaToggleKeyPushedAction {});
                             }
+                            KeyCode::Char('f') => {
+                                self.store.dispatch(TuiFollowKeyPushedAction {});
+                            }
+                            KeyCode::Char('c') => {
+                                self.store.dispatch(TuiColumnChooserKeyPushedAction {});
+                            }
+                            KeyCode::Char('e') => {
+                                self.store.dispatch(ExportMenuToggleAction {});
+                            }
+                            KeyCode::Char('i') => {
+                                self.store.dispatch(TuiChainInfoKeyPushedAction {});
+                            }
+                            KeyCode::Esc if self.store.state().ui.column_chooser.is_some() => {
+                                self.store.dispatch(TuiColumnChooserKeyPushedAction {});
+                            }
+                            KeyCode::Char('a') => {
+                                self.store.dispatch(TuiAlertLogKeyPushedAction {});
+                            }
+                            KeyCode::Esc if self.store.state().ui.chain_info_open => {
+                                self.store.dispatch(TuiChainInfoKeyPushedAction {});
+                            }
+                            KeyCode::Esc if self.store.state().ui.alert_log_open => {
+                                self.store.dispatch(TuiAlertLogKeyPushedAction {});
+                            }
+                            KeyCode::Char('m') => {
+                                self.store.dispatch(TuiMemoryPanelKeyPushedAction {});
+                            }
+                            KeyCode::Esc if self.store.state().ui.memory_panel_open => {
+                                self.store.dispatch(TuiMemoryPanelKeyPushedAction {});
+                            }
+                            KeyCode::Char('w') => {
+                                self.store.dispatch(LatencyWindowToggleAction {});
+                            }
+                            KeyCode::Char('g') => {
+                                self.store.dispatch(HistoryPromptToggleAction {});
+                            }
+                            KeyCode::Char('l') => {
+                                self.store.dispatch(LogsLevelFilterAction {});
+                            }
+                            KeyCode::Char('o') => {
+                                self.store.dispatch(LogsModuleFilterAction {});
+                            }
+                            KeyCode::Char('/') => {
+                                self.store.dispatch(LogsSearchToggleAction {});
+                            }
+                            KeyCode::Char('n') => {
+                                if let Some(replay) = replay {
+                                    replay.step();
+                                }
+                            }
+                            KeyCode::Esc if self.store.state().history.level.is_some() => {
+                                self.store.dispatch(HistoryCloseAction {});
+                            }
+                            KeyCode::Esc if self.store.state().blocks.details_open => {
+                                self.store.dispatch(BlockDetailsToggleAction {});
+                            }
+                            KeyCode::Esc if self.store.state().alerts.banner.is_some() => {
+                                self.store.dispatch(AlertDismissAction {});
+                            }
+                            KeyCode::Esc => {
+                                self.store.dispatch(ProtocolActivationDismissAction {});
+                            }
+                            KeyCode::Char(' ') => {
+                                self.store.dispatch(TuiColumnEditKeyPushedAction {
+                                    edit: ColumnEdit::ToggleVisible,
+                                });
+                            }
+                            KeyCode::Char('p') => {
+                                self.store.dispatch(TuiColumnEditKeyPushedAction {
+                                    edit: ColumnEdit::TogglePin,
+                                });
+                            }
+                            KeyCode::Char('[') => {
+                                self.store.dispatch(TuiColumnEditKeyPushedAction {
+                                    edit: ColumnEdit::MoveUp,
+                                });
+                            }
+                            KeyCode::Char(']') => {
+                                self.store.dispatch(TuiColumnEditKeyPushedAction {
+                                    edit: ColumnEdit::MoveDown,
+                                });
+                            }
+                            KeyCode::Char('+') | KeyCode::Char('=') => {
+                                self.store.dispatch(TuiColumnEditKeyPushedAction {
+                                    edit: ColumnEdit::Widen,
+                                });
+                            }
+                            KeyCode::Char('-') => {
+                                self.store.dispatch(TuiColumnEditKeyPushedAction {
+                                    edit: ColumnEdit::Narrow,
+                                });
+                            }
                             KeyCode::F(1) => {
                                 self.store.dispatch(ChangeScreenAction {
                                     screen: ActivePage::Endorsements,
@@ -97,18 +325,41 @@ impl<Serv: Service> Automaton<Serv> {
                                     screen: ActivePage::Baking,
                                 });
                             }
+                            KeyCode::F(3) => {
+                                self.store.dispatch(ChangeScreenAction {
+                                    screen: ActivePage::Rights,
+                                });
+                            }
+                            KeyCode::F(4) => {
+                                self.store.dispatch(ChangeScreenAction {
+                                    screen: ActivePage::Blocks,
+                                });
+                            }
+                            KeyCode::F(5) => {
+                                self.store.dispatch(ChangeScreenAction {
+                                    screen: ActivePage::Logs,
+                                });
+                            }
+                            KeyCode::F(6) => {
+                                self.store.dispatch(ChangeScreenAction {
+                                    screen: ActivePage::Inspector,
+                                });
+                            }
                             // Dissable for now
-                            // KeyCode::F(3) => {
+                            // KeyCode::F(7) => {
                             //     self.store.dispatch(OperationsStatisticsGetAction {});
                             //     self.store.dispatch(ChangeScreenAction {
                             //         screen: ActivePage::Statistics,
                             //     });
                             // }
-                            // KeyCode::F(4) => {
+                            // KeyCode::F(8) => {
                             //     self.store.dispatch(ChangeScreenAction {
                             //         screen: ActivePage::Synchronization,
                             //     });
                             // }
+                            KeyCode::Enter => {
+                                self.store.dispatch(BlockDetailsToggleAction {});
+                            }
                             KeyCode::Tab => {
                                 self.store.dispatch(TuiWidgetSelectionKeyPushedAction {});
                             }
@@ -152,24 +403,40 @@ impl<Serv: Service> Automaton<Serv> {
                                     operations_statistics: operations_statistics.clone(),
                                 });
                             }
-                            RpcResponse::ApplicationStatistics(application_stats) => {
+                            RpcResponse::ApplicationStatistics(level, application_stats) => {
                                 self.store.dispatch(ApplicationStatisticsReceivedAction {
-                                    application_statistics: application_stats.clone(),
+                                    level: Some(level),
+                                    application_statistics: application_stats,
                                 });
                             }
-                            RpcResponse::PerPeerBlockStatistics(per_peer_stats) => {
+                            RpcResponse::PerPeerBlockStatistics(level, per_peer_stats) => {
                                 self.store.dispatch(PerPeerBlockStatisticsReceivedAction {
-                                    per_peer_block_statistics: per_peer_stats.clone(),
+                                    level: Some(level),
+                                    per_peer_block_statistics: per_peer_stats,
+                                });
+                            }
+                            RpcResponse::LevelApplicationStatistics(level, application_stats) => {
+                                self.store.dispatch(HistoryApplicationStatisticsReceivedAction {
+                                    level,
+                                    application_statistics: application_stats,
                                 });
                             }
-                            RpcResponse::BakingRights(rights) => {
+                            RpcResponse::LevelPerPeerBlockStatistics(level, per_peer_stats) => {
+                                self.store.dispatch(HistoryPerPeerBlockStatisticsReceivedAction {
+                                    level,
+                                    per_peer_block_statistics: per_peer_stats,
+                                });
+                            }
+                            RpcResponse::BakingRights(cycle, rights) => {
                                 self.store.dispatch(BakingRightsReceivedAction {
-                                    rights: rights.clone(),
+                                    cycle,
+                                    rights,
                                 });
                             }
-                            RpcResponse::EndorsementRightsWithTime(rights) => {
+                            RpcResponse::EndorsementRightsWithTime(cycle, rights) => {
                                 self.store.dispatch(EndorsementsRightsWithTimeReceivedAction {
-                                    rights: rights.clone(),
+                                    cycle,
+                                    rights,
                                 });
                             }
                             RpcResponse::MempoolEndorsementStats(stats) => {
@@ -192,6 +459,16 @@ impl<Serv: Service> Automaton<Serv> {
                                     level
                                 });
                             }
+                            RpcResponse::Proposals(proposals) => {
+                                self.store.dispatch(ProposalsReceivedAction {
+                                    proposals
+                                });
+                            }
+                            RpcResponse::PendingOperations(size) => {
+                                self.store.dispatch(MempoolSizeReceivedAction {
+                                    size
+                                });
+                            }
                         }
                     }
                 }
@@ -214,63 +491,133 @@ where
     }
 }
 
-pub struct AutomatonManager {
-    automaton: Automaton<ServiceDefault>,
-    record_actions: bool,
+/// Services replaying a traffic recording instead of connecting to a node
+pub type ServiceReplay = ServiceDefault<RpcServiceReplay, WebsocketServiceReplay>;
+
+/// The node the tui connects to
+pub struct NodeConnection {
+    pub rpc_url: Url,
+    pub websocket_url: Url,
+    /// Records the traffic received from the node
+    pub recorder: Option<TrafficRecorder>,
+}
+
+pub struct AutomatonManager<Serv = ServiceDefault> {
+    automaton: Automaton<Serv>,
     tui_event_receiver: mpsc::Receiver<TuiEvent>,
+    replay: Option<ReplayControl>,
 }
 
-impl AutomatonManager {
-    const MPCS_QUEUE_MAX_CAPACITY: usize = 4096;
+const MPCS_QUEUE_MAX_CAPACITY: usize = 4096;
 
+impl AutomatonManager {
     pub fn new(
-        rpc_url: Url,
-        websocket_url: Url,
+        node: NodeConnection,
+        baker_address: Option<String>,
+        action_recorder: Option<ActionRecorder>,
+        config_path: PathBuf,
+        export_dir: PathBuf,
+        logs: LogServiceDefault,
+    ) -> Result<Self, TuiError> {
+        let log = logs.log.clone();
+        let mut rpc_service = RpcServiceDefault::new(MPCS_QUEUE_MAX_CAPACITY, node.rpc_url, &log);
+        let mut websocket_service =
+            WebsocketServiceDefault::new(MPCS_QUEUE_MAX_CAPACITY, node.websocket_url, &log);
+        if let Some(recorder) = node.recorder {
+            rpc_service = rpc_service.with_recorder(recorder.clone());
+            websocket_service = websocket_service.with_recorder(recorder);
+        }
+
+        let initial_state = State::new(baker_address, log);
+        Self::with_services(
+            rpc_service,
+            websocket_service,
+            initial_state,
+            action_recorder,
+            config_path,
+            export_dir,
+            logs,
+        )
+    }
+}
+
+impl AutomatonManager<ServiceReplay> {
+    /// Replay the recorded traffic at the pace instead of connecting to a node, 'n' steps the stepped pace
+    pub fn replay(
+        recording: Vec<TrafficRecord>,
+        pace: ReplayPace,
         baker_address: Option<String>,
-        record_actions: bool,
-        log: Logger,
-    ) -> Self {
-        let rpc_service = RpcServiceDefault::new(Self::MPCS_QUEUE_MAX_CAPACITY, rpc_url, &log);
-        let websocket_service =
-            WebsocketServiceDefault::new(Self::MPCS_QUEUE_MAX_CAPACITY, websocket_url, &log);
-        let tui_service = TuiServiceDefault::new();
+        action_recorder: Option<ActionRecorder>,
+        config_path: PathBuf,
+        export_dir: PathBuf,
+        logs: LogServiceDefault,
+    ) -> Result<Self, TuiError> {
+        let log = logs.log.clone();
+        let (rpc_service, websocket_service, control) =
+            start_replay(recording, pace, MPCS_QUEUE_MAX_CAPACITY, &log);
+
+        let initial_state = State::new(baker_address, log);
+        let mut manager = Self::with_services(
+            rpc_service,
+            websocket_service,
+            initial_state,
+            action_recorder,
+            config_path,
+            export_dir,
+            logs,
+        )?;
+        manager.replay = Some(control);
+        Ok(manager)
+    }
+}
+
+impl<Rpc: RpcService, Ws: WebsocketService> AutomatonManager<ServiceDefault<Rpc, Ws>> {
+    fn with_services(
+        rpc_service: Rpc,
+        websocket_service: Ws,
+        initial_state: State,
+        action_recorder: Option<ActionRecorder>,
+        config_path: PathBuf,
+        export_dir: PathBuf,
+        logs: LogServiceDefault,
+    ) -> Result<Self, TuiError> {
+        let log = &logs.log;
+        let tui_service = TuiServiceDefault::new()?;
         let tui_event_receiver = TuiServiceDefault::start(Duration::from_millis(1000));
+        let config_service = ConfigServiceDefault::new(config_path, log);
+        let config = config_service.load();
 
         let service = ServiceDefault {
             rpc: rpc_service,
             tui: tui_service,
             ws: websocket_service,
+            config: config_service,
+            export: ExportServiceDefault::new(export_dir),
+            alert: AlertServiceDefault::new(&config.alerts.sinks, log),
+            logs,
+            action_recorder,
         };
 
-        let initial_state = State::new(baker_address, record_actions, log.clone());
-
-        let automaton = Automaton::new(initial_state, service);
+        let automaton = Automaton::new(initial_state.with_config(config), service);
 
-        Self {
+        Ok(Self {
             automaton,
-            record_actions,
             tui_event_receiver,
-        }
+            replay: None,
+        })
     }
+}
 
+impl<Serv: Service> AutomatonManager<Serv> {
     pub async fn start(&mut self) {
-        let log = self.automaton.store.state().log.clone();
-
-        let init_state = self.automaton.store.state().clone();
         self.automaton
-            .make_progress(&mut self.tui_event_receiver)
+            .make_progress(&mut self.tui_event_receiver, self.replay.as_ref())
             .await;
 
-        let actions = self.automaton.store.state().recorded_actions.clone();
-
-        if self.record_actions {
-            info!(log, "Dumping recorded actions to automaton_dump.json");
-            let end_state = self.automaton.store.state().clone();
-            let dump = AutomatonDump::new(init_state, end_state, &actions);
-
-            // TODO: unwraps!
-            let file = File::create("automaton_dump.json").unwrap();
-            serde_json::to_writer(file, &dump).unwrap();
+        if let Some(recorder) = self.automaton.store.service().action_recorder().cloned() {
+            let state = self.automaton.store.state();
+            info!(state.log, "Recording the end state");
+            recorder.finish(state);
         }
 
         self.automaton.store.service().tui().restore_terminal();
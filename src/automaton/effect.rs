@@ -1,16 +1,30 @@
 use crate::{
-    baking::baking_effects, endorsements::endorsements_effects::endorsement_effects,
-    operations::operations_effects, rpc::rpc_effects::rpc_effects, terminal_ui::tui_effects,
+    alerts::alerts_effects, baking::baking_effects,
+    endorsements::endorsements_effects::endorsement_effects, export::export_effects,
+    governance::governance_effects, history::history_effects, inspector::inspector_effects,
+    logs::logs_effects, operations::operations_effects, rights::rights_effects,
+    rpc::rpc_effects::rpc_effects, terminal_ui::tui_effects, trends::trends_effects,
     websocket::websocket_effects,
 };
 
-use super::{ActionWithMeta, Service, Store};
+use super::{action_checkpoint_effects, action_recorder_effects, ActionWithMeta, Service, Store};
 
 pub fn effects<S: Service>(store: &mut Store<S>, action: &ActionWithMeta) {
+    // recorded before the effects dispatch the nested actions
+    action_recorder_effects(store, action);
     tui_effects(store, action);
     websocket_effects(store, action);
     rpc_effects(store, action);
     endorsement_effects(store, action);
     operations_effects(store, action);
     baking_effects(store, action);
+    rights_effects(store, action);
+    trends_effects(store, action);
+    export_effects(store, action);
+    governance_effects(store, action);
+    history_effects(store, action);
+    logs_effects(store, action);
+    inspector_effects(store, action);
+    alerts_effects(store, action);
+    action_checkpoint_effects(store, action);
 }
@@ -19,7 +19,11 @@ pub use reducer::*;
 pub mod effect;
 pub use effect::*;
 
-pub mod action_logger;
+pub mod action_recorder;
+pub use action_recorder::*;
+
+pub mod action_replay;
+pub use action_replay::*;
 
 #[derive(From, Clone)]
 pub struct Logger(slog::Logger);
@@ -1,9 +1,12 @@
 use redux_rs::chain_reducers;
 
 use crate::{
-    automaton::action_logger::action_logger_reducer, baking::baking_reducer,
-    endorsements::endorsementrs_reducer, operations::operations_reducer,
-    synchronization::synchronization_reducer, terminal_ui::tui_reducer,
+    alerts::alerts_reducer, baking::baking_reducer, blocks::blocks_reducer,
+    endorsements::endorsementrs_reducer, export::export_reducer, governance::governance_reducer,
+    history::history_reducer, inspector::inspector_reducer, latency::latency_reducer,
+    logs::logs_reducer, operations::operations_reducer, retention::retention_reducer,
+    rights::rights_reducer, synchronization::synchronization_reducer, terminal_ui::tui_reducer,
+    trends::trends_reducer,
 };
 
 use super::{ActionWithMeta, State};
@@ -12,11 +15,23 @@ pub fn reducer(state: &mut State, action: &ActionWithMeta) {
     chain_reducers!(
         state,
         action,
-        action_logger_reducer,
         tui_reducer,
         synchronization_reducer,
         endorsementrs_reducer,
         baking_reducer,
-        operations_reducer
+        operations_reducer,
+        rights_reducer,
+        blocks_reducer,
+        history_reducer,
+        latency_reducer,
+        trends_reducer,
+        logs_reducer,
+        inspector_reducer,
+        export_reducer,
+        governance_reducer,
+        // measures the memory usage of the slices changed by the reducers above
+        retention_reducer,
+        // the alert rules see the state changes of all the other reducers
+        alerts_reducer
     );
 }
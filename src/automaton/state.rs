@@ -2,16 +2,27 @@ use serde::{Deserialize, Serialize};
 use slog::Logger;
 
 use crate::{
+    alerts::AlertsState,
     baking::BakingState,
+    blocks::BlocksState,
+    configuration::{TableConfig, TuiConfig},
     endorsements::EndrosementsState,
+    export::ExportState,
+    extensions::{ColumnLayout, ExportFormat},
+    governance::GovernanceState,
+    history::HistoryState,
+    inspector::InspectorState,
+    latency::LatencyState,
+    logs::LogsState,
     operations::OperationsStatisticsState,
+    retention::RetentionState,
+    rights::RightsState,
     services::rpc_service_async::{CurrentHeadHeader, CurrentHeadMetadata, NetworkConstants},
     synchronization::SynchronizationState,
-    terminal_ui::UiState,
+    terminal_ui::{ActiveWidget, UiState},
+    trends::TrendsState,
 };
 
-use super::ActionWithMeta;
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct State {
     pub network_constants: NetworkConstants,
@@ -26,17 +37,39 @@ pub struct State {
     pub endorsmenents: EndrosementsState,
     pub operations_statistics: OperationsStatisticsState,
     pub baking: BakingState,
+    #[serde(default)]
+    pub rights: RightsState,
+    #[serde(default)]
+    pub blocks: BlocksState,
+    #[serde(default)]
+    pub history: HistoryState,
+    #[serde(default)]
+    pub latency: LatencyState,
+    #[serde(default)]
+    pub trends: TrendsState,
+    #[serde(default)]
+    pub export: ExportState,
+    #[serde(default)]
+    pub governance: GovernanceState,
+    #[serde(default)]
+    pub alerts: AlertsState,
+    #[serde(default)]
+    pub retention: RetentionState,
+    #[serde(default)]
+    pub logs: LogsState,
+    #[serde(default)]
+    pub inspector: InspectorState,
 
     pub delta_toggle: bool,
 
     pub ui: UiState,
 
+    /// Settings persisted between runs
+    #[serde(default)]
+    pub config: TuiConfig,
+
     #[serde(skip)]
     pub log: crate::automaton::Logger,
-    #[serde(skip)]
-    pub recorded_actions: Vec<ActionWithMeta>,
-    #[serde(skip)]
-    pub record_actions: bool,
 }
 
 impl PartialEq for State {
@@ -52,17 +85,28 @@ impl PartialEq for State {
             && self.endorsmenents == other.endorsmenents
             && self.operations_statistics == other.operations_statistics
             && self.baking == other.baking
+            && self.rights == other.rights
+            && self.blocks == other.blocks
+            && self.history == other.history
+            && self.latency == other.latency
+            && self.trends == other.trends
+            && self.export == other.export
+            && self.governance == other.governance
+            && self.alerts == other.alerts
+            && self.retention == other.retention
+            && self.logs == other.logs
+            && self.inspector == other.inspector
             && self.delta_toggle == other.delta_toggle
             && self.ui == other.ui
+            && self.config == other.config
     }
 }
 
 impl State {
-    pub fn new(baker_address: Option<String>, record_actions: bool, log: Logger) -> Self {
+    pub fn new(baker_address: Option<String>, log: Logger) -> Self {
         Self {
             log: crate::automaton::Logger(log),
             baker_address,
-            record_actions,
             delta_toggle: true,
             current_head_header: Default::default(),
             current_head_metadata: Default::default(),
@@ -72,10 +116,126 @@ impl State {
             endorsmenents: Default::default(),
             operations_statistics: Default::default(),
             baking: Default::default(),
+            rights: Default::default(),
+            blocks: Default::default(),
+            history: Default::default(),
+            latency: Default::default(),
+            trends: Default::default(),
+            export: Default::default(),
+            governance: Default::default(),
+            alerts: Default::default(),
+            retention: Default::default(),
+            logs: Default::default(),
+            inspector: Default::default(),
             ui: Default::default(),
             network_constants: Default::default(),
             best_remote_level: Default::default(),
-            recorded_actions: Vec::new(),
+            config: Default::default(),
         }
     }
+
+    /// The focused table, `None` if the focused widget is not a table
+    pub fn active_table(&self) -> Option<&dyn ColumnLayout> {
+        match self.ui.active_widget {
+            ActiveWidget::EndorserTable => Some(&self.endorsmenents.endorsement_table),
+            ActiveWidget::StatisticsMainTable => {
+                Some(&self.operations_statistics.main_operation_statistics_table)
+            }
+            ActiveWidget::StatisticsDetailsTable => Some(
+                &self
+                    .operations_statistics
+                    .details_operation_statistics_table,
+            ),
+            ActiveWidget::BakingTable => Some(&self.baking.baking_table),
+            ActiveWidget::PeriodInfo
+            | ActiveWidget::PeerTable
+            | ActiveWidget::RightsTable
+            | ActiveWidget::BlocksTable
+            | ActiveWidget::LogTable
+            | ActiveWidget::InspectorTable => None,
+        }
+    }
+
+    /// Export of the focused table named after the table, `None` if the focused widget is not a table
+    pub fn active_table_export(
+        &self,
+        format: ExportFormat,
+        raw: bool,
+    ) -> Option<(&'static str, String)> {
+        let delta_toggle = self.delta_toggle;
+        match self.ui.active_widget {
+            ActiveWidget::EndorserTable => Some((
+                "endorsements",
+                self.endorsmenents
+                    .endorsement_table
+                    .export(format, raw, delta_toggle),
+            )),
+            ActiveWidget::StatisticsMainTable => Some((
+                "operations",
+                self.operations_statistics
+                    .main_operation_statistics_table
+                    .export(format, raw, delta_toggle),
+            )),
+            ActiveWidget::StatisticsDetailsTable => Some((
+                "operation_details",
+                self.operations_statistics
+                    .details_operation_statistics_table
+                    .export(format, raw, delta_toggle),
+            )),
+            ActiveWidget::BakingTable => Some((
+                "baking",
+                self.baking.baking_table.export(format, raw, delta_toggle),
+            )),
+            ActiveWidget::PeriodInfo
+            | ActiveWidget::PeerTable
+            | ActiveWidget::RightsTable
+            | ActiveWidget::BlocksTable
+            | ActiveWidget::LogTable
+            | ActiveWidget::InspectorTable => None,
+        }
+    }
+
+    /// The focused table together with its persisted config
+    pub fn active_table_mut(&mut self) -> Option<(&mut dyn ColumnLayout, &mut TableConfig)> {
+        let tables = &mut self.config.tables;
+        match self.ui.active_widget {
+            ActiveWidget::EndorserTable => Some((
+                &mut self.endorsmenents.endorsement_table,
+                &mut tables.endorsements,
+            )),
+            ActiveWidget::StatisticsMainTable => Some((
+                &mut self.operations_statistics.main_operation_statistics_table,
+                &mut tables.operations,
+            )),
+            ActiveWidget::StatisticsDetailsTable => Some((
+                &mut self
+                    .operations_statistics
+                    .details_operation_statistics_table,
+                &mut tables.operation_details,
+            )),
+            ActiveWidget::BakingTable => Some((&mut self.baking.baking_table, &mut tables.baking)),
+            ActiveWidget::PeriodInfo
+            | ActiveWidget::PeerTable
+            | ActiveWidget::RightsTable
+            | ActiveWidget::BlocksTable
+            | ActiveWidget::LogTable
+            | ActiveWidget::InspectorTable => None,
+        }
+    }
+
+    /// Apply the persisted settings to the initial state
+    pub fn with_config(mut self, config: TuiConfig) -> Self {
+        self.endorsmenents
+            .endorsement_table
+            .apply_config(&config.tables.endorsements);
+        self.baking.baking_table.apply_config(&config.tables.baking);
+        self.operations_statistics
+            .main_operation_statistics_table
+            .apply_config(&config.tables.operations);
+        self.operations_statistics
+            .details_operation_statistics_table
+            .apply_config(&config.tables.operation_details);
+        self.config = config;
+        self
+    }
 }
@@ -20,6 +20,10 @@ impl EnablingCondition<State> for ApplicationStatisticsGetAction {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApplicationStatisticsReceivedAction {
+    /// Level the statistics were requested for
+    /// Missing in the recordings of the older versions, the current head level is used then
+    #[serde(default)]
+    pub level: Option<i32>,
     pub application_statistics: Vec<BlockApplicationStatistics>,
 }
 
@@ -42,6 +46,10 @@ impl EnablingCondition<State> for PerPeerBlockStatisticsGetAction {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PerPeerBlockStatisticsReceivedAction {
+    /// Level the statistics were requested for
+    /// Missing in the recordings of the older versions, the current head level is used then
+    #[serde(default)]
+    pub level: Option<i32>,
     pub per_peer_block_statistics: PerPeerBlockStatisticsVector,
 }
 
@@ -63,6 +71,9 @@ impl EnablingCondition<State> for BakingRightsGetAction {
 }
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BakingRightsReceivedAction {
+    /// Missing in the recordings of the older versions
+    #[serde(default)]
+    pub cycle: i32,
     pub rights: Vec<BakingRightsPerLevel>,
 }
 
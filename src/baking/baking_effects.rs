@@ -7,8 +7,6 @@ use crate::{
     },
 };
 
-use super::BakingRightsGetAction;
-
 pub fn baking_effects<S>(store: &mut Store<S>, action: &ActionWithMeta)
 where
     S: Service,
@@ -30,38 +28,30 @@ where
                 ),
             });
         }
-        Action::CycleChanged(action) => {
-            if store.state().baker_address.is_some() {
-                let preserved_cycles = store.state().network_constants.preserved_cycles;
-                store.dispatch(BakingRightsGetAction {
-                    cycle: action.new_cycle + preserved_cycles,
-                });
-            }
-        }
         Action::BakingRightsGet(action) => {
             if let Some(delegate) = store.state().baker_address.clone() {
+                // the priorities were replaced by the rounds in Tenderbake
+                let max_round = if store
+                    .state()
+                    .network_constants
+                    .consensus_committee_size
+                    .is_some()
+                {
+                    "max_round"
+                } else {
+                    "max_priority"
+                };
                 store.dispatch(RpcRequestAction {
                     call: RpcCall::new(
                         RpcTarget::BakingRights,
                         Some(format!(
-                            "?delegate={}&max_priority=0&cycle={}",
-                            delegate, action.cycle
+                            "?delegate={}&{}=0&cycle={}",
+                            delegate, max_round, action.cycle
                         )),
                     ),
                 });
             }
         }
-        Action::CurrentHeadMetadataChanged(action) => {
-            let is_empty = store.state().baking.baking_rights.rights.is_empty();
-            if is_empty {
-                let preserved_cycles = store.state().network_constants.preserved_cycles;
-                let current_cycle = action.new_metadata.level_info.cycle;
-
-                for cycle in current_cycle..=current_cycle + preserved_cycles {
-                    store.dispatch(BakingRightsGetAction { cycle });
-                }
-            }
-        }
         _ => {}
     }
 }
@@ -26,12 +26,18 @@ pub fn baking_reducer(state: &mut State, action: &ActionWithMeta) {
                     .insert(peer_stats.block_hash.clone(), extended.clone());
             }
 
-            state.baking.baking_table.content = extended;
-
-            state.baking.baking_table.sort_content(state.delta_toggle);
+            state
+                .baking
+                .baking_table
+                .set_content(extended, state.delta_toggle);
         }
         Action::CurrentHeadHeaderChanged(_) => {
             // state.baking.baking_table.content.clear();
+            // the previous head is still needed to detect a baked block on the next head
+            state
+                .baking
+                .baking_rights
+                .cleanup(&state.previous_head_header.level);
         }
         Action::BakingRightsReceived(action) => {
             state.baking.baking_rights.add(&action.rights);
@@ -1,6 +1,5 @@
 use std::collections::BTreeMap;
 
-use hdrhistogram::Histogram;
 use num::Zero;
 use serde::{Deserialize, Serialize};
 use time::{Duration, OffsetDateTime};
@@ -12,8 +11,8 @@ use tui::{
 
 use crate::{
     extensions::{
-        convert_time_to_unit_string, convert_time_to_unit_string_option, ExtendedTable,
-        SortableByFocus, StyledTime, TuiTableData,
+        convert_time_to_unit_string_option, ExtendedTable, SortKey, SortKeyByFocus, StyledTime,
+        TableRowKey, TuiTableData,
     },
     services::rpc_service_async::CurrentHeadHeader,
 };
@@ -46,6 +45,31 @@ pub struct BlockApplicationStatistics {
     pub injected: Option<u64>,
 }
 
+impl BlockApplicationStatistics {
+    /// Nanoseconds from the start of the download to the stored application result
+    pub fn total_application_time(&self) -> Option<u64> {
+        let start = [
+            self.download_block_header_start,
+            self.load_data_start,
+            self.precheck_start,
+            self.apply_block_start,
+        ]
+        .into_iter()
+        .flatten()
+        .min()?;
+        let end = [self.store_result_end, self.apply_block_end]
+            .into_iter()
+            .flatten()
+            .max()?;
+        end.checked_sub(start)
+    }
+
+    /// Nanoseconds between the block timestamp and the node receiving the block
+    pub fn received_delay(&self, block_timestamp: OffsetDateTime) -> Option<u64> {
+        u64::try_from(self.receive_timestamp as i128 - block_timestamp.unix_timestamp_nanos()).ok()
+    }
+}
+
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
 pub struct BlockApplicationSummary {
     pub precheck: Option<u64>,
@@ -368,37 +392,43 @@ impl From<PerPeerBlockStatistics> for PerPeerBlockStatisticsExtended {
     }
 }
 
-impl SortableByFocus for PerPeerBlockStatisticsExtendedVector {
-    fn sort_by_focus(&mut self, focus_index: usize, delta_toogle: bool) {
+impl SortKeyByFocus for PerPeerBlockStatisticsExtended {
+    fn sort_key(&self, focus_index: usize, delta_toogle: bool) -> Option<SortKey> {
         if delta_toogle {
             match focus_index {
-                0 => self.sort_by_key(|s| s.address.clone()),
-                1 => self.sort_by_key(|s| s.node_id.clone()),
-                2 => self.sort_by_key(|s| s.received_time_delta),
-                3 => self.sort_by_key(|s| s.sent_end_time),
-                4 => self.sort_by_key(|s| s.get_operations_recv_delta),
-                5 => self.sort_by_key(|s| s.operations_send_delta),
-                _ => {}
+                0 => Some(self.address.clone().into()),
+                1 => Some(self.node_id.clone().into()),
+                2 => self.received_time_delta.map(SortKey::from),
+                3 => self.sent_end_time.map(SortKey::from),
+                4 => self.get_operations_recv_delta.map(SortKey::from),
+                5 => self.operations_send_delta.map(SortKey::from),
+                _ => None,
             }
         } else {
             match focus_index {
-                0 => self.sort_by_key(|s| s.address.clone()),
-                1 => self.sort_by_key(|s| s.node_id.clone()),
-                2 => self.sort_by_key(|s| s.received_time),
-                3 => self.sort_by_key(|s| s.sent_end_time),
-                4 => self.sort_by_key(|s| s.get_operations_recv_end_time),
-                5 => self.sort_by_key(|s| s.operations_send_end_time),
-                _ => {}
+                0 => Some(self.address.clone().into()),
+                1 => Some(self.node_id.clone().into()),
+                2 => self.received_time.map(SortKey::from),
+                3 => self.sent_end_time.map(SortKey::from),
+                4 => self.get_operations_recv_end_time.map(SortKey::from),
+                5 => self.operations_send_end_time.map(SortKey::from),
+                _ => None,
             }
         }
     }
+}
 
-    fn rev(&mut self) {
-        self.reverse()
+impl TableRowKey for PerPeerBlockStatisticsExtended {
+    fn row_key(&self) -> String {
+        self.address.clone()
     }
 }
 
 impl TuiTableData for PerPeerBlockStatisticsExtended {
+    fn duration_columns() -> &'static [usize] {
+        &[2, 3, 4, 5]
+    }
+
     fn construct_tui_table_data(&self, delta_toggle: bool) -> Vec<(String, Style)> {
         let style = Style::default().fg(Color::Gray).add_modifier(Modifier::DIM);
 
@@ -495,7 +525,12 @@ impl From<PerPeerBlockStatisticsExtendedVector> for ApplicationSummary {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct BakingRightsPerLevel {
     pub level: i32,
+    /// Missing since Tenderbake, the round is sent instead
+    #[serde(default)]
     pub priority: u64,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub round: Option<u64>,
     pub delegate: String,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -525,13 +560,13 @@ impl BakingRights {
         level: i32,
         block_timestamp: &OffsetDateTime,
         block_delay: i32,
+        now: i64,
     ) -> Option<(i32, String)> {
         self.rights
             .range(level..)
             .next()
             .map(|(baking_level, time)| {
                 if time.is_some() {
-                    let now = OffsetDateTime::now_utc().unix_timestamp();
                     let block_time = block_timestamp.unix_timestamp();
                     let level_delta = baking_level - level;
                     let until_baking =
@@ -605,34 +640,3 @@ impl Default for BakingState {
         }
     }
 }
-
-pub trait ToHistogramData {
-    fn to_histogram_data(&self) -> Vec<(String, u64)>;
-}
-
-// TODO: we can use generic here?
-impl ToHistogramData for PerPeerBlockStatisticsVector {
-    fn to_histogram_data(&self) -> Vec<(String, u64)> {
-        // TODO: error handling
-        let mut histogram = Histogram::<u64>::new(1).unwrap();
-
-        for stats in self.iter() {
-            histogram
-                .record(stats.received_time.unwrap_or_default())
-                .unwrap()
-        }
-        // 100_000_000
-
-        histogram
-            .iter_linear(100_000_000)
-            .map(|iter_val| {
-                (
-                    convert_time_to_unit_string(iter_val.value_iterated_to()),
-                    iter_val.count_since_last_iteration(),
-                )
-            })
-            .collect()
-
-        // BTreeMap::new()
-    }
-}
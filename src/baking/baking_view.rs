@@ -1,16 +1,26 @@
-use itertools::Itertools;
 use tui::backend::Backend;
-use tui::layout::{Constraint, Corner, Direction, Layout};
+use tui::layout::{Constraint, Corner, Direction, Layout, Rect};
 use tui::style::{Color, Modifier, Style};
 use tui::text::{Span, Spans};
 use tui::widgets::{Block, Borders, Cell, Paragraph, Row, Table};
 use tui::Frame;
 
 use crate::automaton::State;
-use crate::common::{create_header_bar, create_help_bar, create_pages_tabs, create_quit};
-use crate::extensions::{CustomSeparator, Renderable};
-
-use super::{ApplicationSummary, BakingSummary, BlockApplicationSummary};
+use crate::common::{
+    create_header_bar, create_help_bar, create_pages_tabs, create_quit, draw_column_chooser,
+    HEADER_HEIGHT,
+};
+use crate::extensions::{CustomSeparator, Renderable, SplitArea, StyledTime};
+use crate::latency::draw_latency_panels;
+
+use super::{
+    ApplicationSummary, BakingSummary, BlockApplicationStatistics, BlockApplicationSummary,
+    PerPeerBlockStatisticsExtendedVector,
+};
+
+/// Rows of the latency panels below which they are hidden, the title, the borders,
+/// the percentiles, the bucket labels and two rows of bars
+const MIN_LATENCY_HEIGHT: u16 = 7;
 
 // TODO: will this be the actual homescreen?
 pub struct BakingScreen {}
@@ -30,19 +40,16 @@ impl<B: Backend> Renderable<B> for BakingScreen {
         let page_chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(2),
+                Constraint::Length(HEADER_HEIGHT),
                 Constraint::Min(5),
                 Constraint::Length(1),
             ])
             .split(size);
 
-        let (baking_table_chunk, summary_chunk) = Layout::default()
+        let [baking_table_chunk, summary_chunk] = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
-            .split(page_chunks[1])
-            .into_iter()
-            .collect_tuple()
-            .unwrap();
+            .split_area(page_chunks[1]);
 
         // ======================== SUMMARY PANEL (right) ========================
 
@@ -54,13 +61,10 @@ impl<B: Backend> Renderable<B> for BakingScreen {
             BlockApplicationSummary::default()
         };
 
-        let (top_panel, bottom_panel) = Layout::default()
+        let [top_panel, bottom_panel] = Layout::default()
             .direction(Direction::Vertical)
             .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-            .split(summary_chunk)
-            .into_iter()
-            .collect_tuple()
-            .unwrap();
+            .split_area(summary_chunk);
 
         let summary_panel = if state.baker_address.is_some() {
             bottom_panel
@@ -68,13 +72,10 @@ impl<B: Backend> Renderable<B> for BakingScreen {
             top_panel
         };
 
-        let (summary_title_chunk, summary_inner_chunk) = Layout::default()
+        let [summary_title_chunk, summary_inner_chunk] = Layout::default()
             .direction(Direction::Vertical)
             .constraints([Constraint::Length(4), Constraint::Min(17)])
-            .split(summary_panel)
-            .into_iter()
-            .collect_tuple()
-            .unwrap();
+            .split_area(summary_panel);
 
         let summary_title = Paragraph::new(Span::styled(
             " APPLICATION PROGRESS",
@@ -84,10 +85,6 @@ impl<B: Backend> Renderable<B> for BakingScreen {
 
         f.render_widget(summary_title, summary_title_chunk);
 
-        let selected_style = Style::default()
-            .remove_modifier(Modifier::DIM)
-            .bg(Color::Black);
-
         let mut application_stats_table_data = application_summary.to_table_data();
 
         let per_peer_stats = if let Some(per_peer_stats) = state
@@ -103,56 +100,21 @@ impl<B: Backend> Renderable<B> for BakingScreen {
         let baking_summary = ApplicationSummary::from(per_peer_stats.clone());
         baking_summary.extend_table_data(&mut application_stats_table_data);
 
-        let rows = application_stats_table_data
-            .clone()
-            .into_iter()
-            .enumerate()
-            .map(|(index, (tag, styled_time))| {
-                let height = 1;
-
-                let sequence_num = Cell::from(index.to_string());
-                let tag = Cell::from(tag);
-                let value = Cell::from(styled_time.get_string_representation())
-                    .style(styled_time.get_style().remove_modifier(Modifier::DIM));
-
-                // stripes to differentiate between lines
-                if index % 2 == 0 {
-                    Row::new(vec![sequence_num, tag, value])
-                        .height(height)
-                        .style(selected_style)
-                } else {
-                    Row::new(vec![sequence_num, tag, value]).height(height)
-                }
-            });
-
         let block = Block::default().borders(Borders::BOTTOM | Borders::LEFT | Borders::RIGHT);
-        let table = Table::new(rows)
-            // .header(header)
-            .block(block)
-            .widths(&[
-                Constraint::Length(2),
-                Constraint::Percentage(75),
-                Constraint::Percentage(25),
-            ]);
+        let table = application_progress_table(application_stats_table_data, block);
         f.render_widget(table, summary_inner_chunk);
 
         // ======================== SUMMRAY PANEL BAKING ========================
         if state.baker_address.is_some() {
-            let (
-                summary_baking_title_chunk,
-                summary_baking_level_chunk,
-                summary_baking_inner_chunk,
-            ) = Layout::default()
-                .direction(Direction::Vertical)
-                .constraints([
-                    Constraint::Length(3),
-                    Constraint::Length(2),
-                    Constraint::Min(15),
-                ])
-                .split(top_panel)
-                .into_iter()
-                .collect_tuple()
-                .unwrap();
+            let [summary_baking_title_chunk, summary_baking_level_chunk, summary_baking_inner_chunk] =
+                Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([
+                        Constraint::Length(3),
+                        Constraint::Length(2),
+                        Constraint::Min(15),
+                    ])
+                    .split_area(top_panel);
 
             let current_head_level = state.current_head_header.level;
             let current_head_timestamp = state.current_head_header.timestamp;
@@ -161,6 +123,7 @@ impl<B: Backend> Renderable<B> for BakingScreen {
                 current_head_level,
                 &current_head_timestamp,
                 state.network_constants.minimal_block_delay,
+                state.ui.draw_timestamp(),
             );
 
             let (next_baking_time_label, next_baking_delta_label) =
@@ -169,7 +132,12 @@ impl<B: Backend> Renderable<B> for BakingScreen {
                     (
                         Span::styled(format!("{} ", level), Style::default().fg(Color::White)),
                         Span::styled(
-                            format!("{} ({} blocks)", time, blocks_delta),
+                            // without an estimated time only the distance in blocks is known
+                            if time.is_empty() {
+                                format!("{} blocks", blocks_delta)
+                            } else {
+                                format!("{} ({} blocks)", time, blocks_delta)
+                            },
                             Style::default().fg(Color::White),
                         ),
                     )
@@ -222,6 +190,7 @@ impl<B: Backend> Renderable<B> for BakingScreen {
                 current_head_level,
                 &current_head_timestamp,
                 state.network_constants.minimal_block_delay,
+                state.ui.draw_timestamp(),
             );
             if let Some((level, _)) = next_baking {
                 // Only update on new baking
@@ -237,52 +206,28 @@ impl<B: Backend> Renderable<B> for BakingScreen {
                     state.baking.last_baking_summary.clone()
                 };
 
-                let rows = baking_summary
-                    .to_table_data()
-                    .clone()
-                    .into_iter()
-                    .enumerate()
-                    .map(|(index, (tag, styled_time))| {
-                        let height = 1;
-
-                        let sequence_num = Cell::from(index.to_string());
-                        let tag = Cell::from(tag);
-                        let value = Cell::from(styled_time.get_string_representation())
-                            .style(styled_time.get_style().remove_modifier(Modifier::DIM));
-
-                        // stripes to differentiate between lines
-                        if index % 2 == 0 {
-                            Row::new(vec![sequence_num, tag, value])
-                                .height(height)
-                                .style(selected_style)
-                        } else {
-                            Row::new(vec![sequence_num, tag, value]).height(height)
-                        }
-                    });
-
                 let block =
                     Block::default().borders(Borders::BOTTOM | Borders::LEFT | Borders::RIGHT);
-                let table = Table::new(rows)
-                    // .header(header)
-                    .block(block)
-                    .widths(&[
-                        Constraint::Length(2),
-                        Constraint::Percentage(75),
-                        Constraint::Percentage(25),
-                    ]);
+                let table = application_progress_table(baking_summary.to_table_data(), block);
                 f.render_widget(table, summary_baking_inner_chunk);
             }
         }
 
         // ======================== BAKING TABLE (help) ========================
 
-        let (help_chunk, baking_table_inner_chunk) = Layout::default()
+        // the table keeps most of the height on small terminals
+        let latency_height = match baking_table_chunk.height * 35 / 100 {
+            height if height < MIN_LATENCY_HEIGHT => 0,
+            height => height,
+        };
+        let [help_chunk, baking_table_inner_chunk, latency_chunk] = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Length(2), Constraint::Min(5)])
-            .split(baking_table_chunk)
-            .into_iter()
-            .collect_tuple()
-            .unwrap();
+            .constraints([
+                Constraint::Length(2),
+                Constraint::Min(5),
+                Constraint::Length(latency_height),
+            ])
+            .split_area(baking_table_chunk);
 
         create_help_bar(help_chunk, f, delta_toggle);
 
@@ -308,7 +253,12 @@ impl<B: Backend> Renderable<B> for BakingScreen {
             .baking_table
             .renderable_rows(&state.baking.baking_table.content, delta_toggle);
 
-        let highlight_symbol = "▶".to_string().to_ascii_uppercase();
+        // the pinned row gets a different marker so it is clear the selection is following it
+        let highlight_symbol = if state.baking.baking_table.followed().is_some() {
+            "●".to_string()
+        } else {
+            "▶".to_string()
+        };
 
         let table = Table::new(rows)
             .header(header)
@@ -333,46 +283,10 @@ impl<B: Backend> Renderable<B> for BakingScreen {
             .corner(Corner::TopRight);
         f.render_widget(vertical_right_separator, baking_table_inner_chunk);
 
-        // let histogram_data = state.baking.per_peer_block_statistics.to_histogram_data();
-
-        // let histogram: Vec<(&str, u64)> = histogram_data
-        //     .iter()
-        //     .map(|(label, value)| (&**label, *value))
-        //     .collect();
-
-        // let (chart_area, barchart_area) = Layout::default()
-        //     .direction(Direction::Horizontal)
-        //     .constraints([Constraint::Length(8), Constraint::Min(1)])
-        //     .split(page_chunks[2])
-        //     .into_iter()
-        //     .collect_tuple()
-        //     .unwrap();
-
-        // let dummy_dataset = Dataset::default()
-        //     .data(&[(5.0, 0.0)]);
-
-        // let chart = Chart::new(Vec::new()).y_axis(
-        //     Axis::default()
-        //         .bounds([0.0, 10.0])
-        //         .labels(
-        //             vec![
-        //                 Span::styled("0", Style::default().add_modifier(Modifier::BOLD)),
-        //                 Span::styled("5", Style::default().add_modifier(Modifier::BOLD)),
-        //                 Span::styled("10", Style::default().add_modifier(Modifier::BOLD))
-        //             ]
-        //         )
-        // );
-        // f.render_widget(chart, chart_area);
-
-        // let barchart = BarChart::default()
-        //     .data(histogram.as_slice())
-        //     .max(10)
-        //     .bar_width(8)
-        //     .value_style(Style::default().fg(Color::Black).bg(Color::Cyan))
-        //     .bar_style(Style::default().fg(Color::Cyan));
-
-        // // let test = Paragraph::new(format!("{:?}", histogram));
-        // f.render_widget(barchart, barchart_area);
+        // ======================== LATENCY ========================
+        if latency_chunk.height > 0 {
+            draw_latency_panels(state, latency_chunk, f);
+        }
 
         // ======================== HEADER ========================
         create_header_bar(page_chunks[0], state, f);
@@ -383,9 +297,101 @@ impl<B: Backend> Renderable<B> for BakingScreen {
 
         // ======================== Quit ========================
         create_quit(page_chunks[2], f);
+
+        // ======================== COLUMN CHOOSER ========================
+        draw_column_chooser(state, f);
     }
 }
 
-fn calculate_percentage(val: u16, perc: u16) -> u16 {
+/// Application phases and the per peer propagation of a block, laid out like the baking screen
+pub fn draw_application_details<B: Backend>(
+    state: &State,
+    level: i32,
+    application_statistics: Option<&BlockApplicationStatistics>,
+    per_peer_stats: &PerPeerBlockStatisticsExtendedVector,
+    area: Rect,
+    f: &mut Frame<B>,
+) {
+    let [application_chunk, peers_chunk] = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+        .split_area(area);
+
+    let title_style = Style::default().fg(Color::White);
+
+    // ======================== APPLICATION PROGRESS ========================
+    let block_summary = application_statistics
+        .cloned()
+        .map(BlockApplicationSummary::from)
+        .unwrap_or_default();
+    let mut table_data = block_summary.to_table_data();
+    ApplicationSummary::from(per_peer_stats.clone()).extend_table_data(&mut table_data);
+
+    let block = Block::default().borders(Borders::ALL).title(Span::styled(
+        format!(" APPLICATION PROGRESS - level {} ", level),
+        title_style,
+    ));
+    f.render_widget(
+        application_progress_table(table_data, block),
+        application_chunk,
+    );
+
+    // ======================== PROPAGATION ========================
+    // the columns follow the layout of the baking table
+    let baking_table = &state.baking.baking_table;
+    let selected_style = Style::default().remove_modifier(Modifier::DIM);
+    let header = Row::new(baking_table.renderable_headers(selected_style))
+        .style(title_style)
+        .height(1)
+        .bottom_margin(1);
+    let constraints = baking_table.renderable_constraints(peers_chunk.width);
+    let rows = baking_table.renderable_rows(per_peer_stats, state.delta_toggle);
+
+    let block = Block::default().borders(Borders::ALL).title(Span::styled(
+        format!(" PROPAGATION - {} peers ", per_peer_stats.len()),
+        title_style,
+    ));
+    let table = Table::new(rows)
+        .header(header)
+        .block(block)
+        .widths(&constraints);
+    f.render_widget(table, peers_chunk);
+}
+
+/// Table of the application phases with their durations, striped to differentiate between lines
+pub fn application_progress_table<'a>(
+    table_data: Vec<(Spans<'a>, StyledTime<u64>)>,
+    block: Block<'a>,
+) -> Table<'a> {
+    let stripe_style = Style::default()
+        .remove_modifier(Modifier::DIM)
+        .bg(Color::Black);
+
+    let rows: Vec<Row> = table_data
+        .into_iter()
+        .enumerate()
+        .map(|(index, (tag, styled_time))| {
+            let sequence_num = Cell::from(index.to_string());
+            let tag = Cell::from(tag);
+            let value = Cell::from(styled_time.get_string_representation())
+                .style(styled_time.get_style().remove_modifier(Modifier::DIM));
+
+            let row = Row::new(vec![sequence_num, tag, value]).height(1);
+            if index % 2 == 0 {
+                row.style(stripe_style)
+            } else {
+                row
+            }
+        })
+        .collect();
+
+    Table::new(rows).block(block).widths(&[
+        Constraint::Length(2),
+        Constraint::Percentage(75),
+        Constraint::Percentage(25),
+    ])
+}
+
+pub fn calculate_percentage(val: u16, perc: u16) -> u16 {
     (val * perc) / 100
 }
@@ -0,0 +1,64 @@
+use clap::Parser;
+use crossterm::{
+    event::{self, Event},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use std::{error::Error, io, path::PathBuf};
+use tezedge_tui::{
+    automaton::ActionRecording,
+    debugger::{draw_debugger, Debugger},
+    services::tui_service::install_panic_hook,
+};
+use tui::{backend::CrosstermBackend, Terminal};
+
+/// Step through the actions recorded with --record-actions
+#[derive(Parser)]
+#[clap(author, version, about)]
+struct DebuggerArgs {
+    /// Path to the action recording, or to one of its rotated files
+    #[clap(long, parse(from_os_str), default_value = "automaton_actions.ndjson")]
+    recording: PathBuf,
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args = DebuggerArgs::parse();
+    let recording = ActionRecording::load(&args.recording).map_err(|e| {
+        format!(
+            "Cannot load the recording {}: {}",
+            args.recording.display(),
+            e
+        )
+    })?;
+    let mut debugger = Debugger::new(recording);
+
+    install_panic_hook();
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(stdout))?;
+
+    let result = run(&mut debugger, &mut terminal);
+
+    // the terminal is restored even when drawing failed
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    disable_raw_mode()?;
+    terminal.show_cursor()?;
+
+    result
+}
+
+fn run(
+    debugger: &mut Debugger,
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+) -> Result<(), Box<dyn Error>> {
+    loop {
+        terminal.draw(|f| draw_debugger(debugger, f))?;
+
+        if let Event::Key(key) = event::read()? {
+            if !debugger.handle_key(key.code) {
+                return Ok(());
+            }
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use redux_rs::EnablingCondition;
+use serde::{Deserialize, Serialize};
+
+use crate::{automaton::State, terminal_ui::ActivePage};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockDetailsToggleAction {}
+
+impl EnablingCondition<State> for BlockDetailsToggleAction {
+    fn is_enabled(&self, state: &State) -> bool {
+        state.ui.active_page == ActivePage::Blocks
+            && (state.blocks.details_open || state.blocks.selected.is_some())
+    }
+}
@@ -0,0 +1,17 @@
+use crate::automaton::{Action, ActionWithMeta, State};
+
+use super::RecentBlock;
+
+pub fn blocks_reducer(state: &mut State, action: &ActionWithMeta) {
+    match &action.action {
+        Action::CurrentHeadHeaderChanged(action) => {
+            state
+                .blocks
+                .push(RecentBlock::from(&action.current_head_header));
+        }
+        Action::BlockDetailsToggle(_) => {
+            state.blocks.details_open = !state.blocks.details_open;
+        }
+        _ => {}
+    }
+}
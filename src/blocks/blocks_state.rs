@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::services::rpc_service_async::CurrentHeadHeader;
+
+/// Number of heads kept in the block explorer
+pub const RECENT_BLOCKS_CAPACITY: usize = 100;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RecentBlock {
+    pub level: i32,
+    pub hash: String,
+    pub priority: i32,
+    #[serde(with = "time::serde::rfc3339")]
+    pub timestamp: OffsetDateTime,
+}
+
+impl From<&CurrentHeadHeader> for RecentBlock {
+    fn from(header: &CurrentHeadHeader) -> Self {
+        Self {
+            level: header.level,
+            hash: header.hash.clone(),
+            priority: header.priority,
+            timestamp: header.timestamp,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BlocksState {
+    /// The last heads, the newest first
+    pub recent: Vec<RecentBlock>,
+    /// Index of the selected block in [BlocksState::recent]
+    pub selected: Option<usize>,
+    /// Application details of the selected block shown under the list
+    pub details_open: bool,
+}
+
+impl BlocksState {
+    /// Add a new head, keeping the selection on the same block
+    pub fn push(&mut self, block: RecentBlock) {
+        if matches!(self.recent.first(), Some(head) if head.hash == block.hash) {
+            return;
+        }
+        self.recent.insert(0, block);
+        self.recent.truncate(RECENT_BLOCKS_CAPACITY);
+        self.selected = self
+            .selected
+            .map(|selected| (selected + 1).min(self.recent.len() - 1));
+    }
+
+    pub fn selected_block(&self) -> Option<&RecentBlock> {
+        self.selected.and_then(|selected| self.recent.get(selected))
+    }
+}
@@ -0,0 +1,192 @@
+use time::format_description;
+use tui::backend::Backend;
+use tui::layout::{Constraint, Corner, Direction, Layout};
+use tui::style::{Color, Modifier, Style};
+use tui::text::{Span, Spans};
+use tui::widgets::{Block, Borders, Paragraph, Row, Table, TableState};
+use tui::Frame;
+
+use crate::automaton::State;
+use crate::baking::draw_application_details;
+use crate::common::{create_header_bar, create_pages_tabs, create_quit, HEADER_HEIGHT};
+use crate::extensions::{CustomSeparator, Renderable, SplitArea, StyledTime};
+
+pub struct BlocksScreen {}
+
+impl<B: Backend> Renderable<B> for BlocksScreen {
+    fn draw_screen(state: &State, f: &mut Frame<B>) {
+        let size = f.size();
+
+        let background = Block::default().style(Style::default().bg(Color::Rgb(31, 30, 30)));
+        f.render_widget(background, size);
+
+        let page_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(HEADER_HEIGHT),
+                Constraint::Min(5),
+                Constraint::Length(1),
+            ])
+            .split(size);
+
+        let selected_block = state.blocks.selected_block();
+        let (list_chunk, details_chunk) = match selected_block {
+            Some(_) if state.blocks.details_open => {
+                let [list, details] = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Percentage(45), Constraint::Percentage(55)])
+                    .split_area(page_chunks[1]);
+                (list, Some(details))
+            }
+            _ => (page_chunks[1], None),
+        };
+
+        let [summary_chunk, blocks_chunk] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(2), Constraint::Min(3)])
+            .split_area(list_chunk);
+
+        let dimmed_text_style = Style::default()
+            .fg(Color::White)
+            .add_modifier(Modifier::DIM);
+        let normal_style = Style::default().fg(Color::White);
+
+        // ======================== SUMMARY ========================
+        let details_hint = if state.blocks.details_open {
+            " - ENTER/ESC to close the details"
+        } else {
+            " - ENTER to show the details of the selected block"
+        };
+        let summary = Paragraph::new(Spans::from(vec![
+            Span::styled(" RECENT BLOCKS - ", normal_style),
+            Span::styled(state.blocks.recent.len().to_string(), normal_style),
+            Span::styled(" heads seen", dimmed_text_style),
+            Span::styled(details_hint, dimmed_text_style),
+        ]))
+        .block(Block::default().borders(Borders::TOP | Borders::LEFT | Borders::RIGHT));
+        f.render_widget(summary, summary_chunk);
+
+        // ======================== BLOCKS ========================
+        let format_desc =
+            format_description::parse("[year]-[month]-[day] [hour]:[minute]:[second]")
+                .unwrap_or_default();
+        let missing = || Span::styled(" - ", dimmed_text_style);
+        let styled_time = |time: Option<u64>| match time {
+            Some(time) => {
+                let styled = StyledTime::new(Some(time));
+                Span::styled(
+                    styled.get_string_representation(),
+                    styled.get_style().remove_modifier(Modifier::DIM),
+                )
+            }
+            None => missing(),
+        };
+
+        let rows: Vec<Row> = state
+            .blocks
+            .recent
+            .iter()
+            .map(|block| {
+                let statistics = state.baking.application_statistics.get(&block.hash);
+                let priority = statistics
+                    .and_then(|statistics| statistics.baker_priority)
+                    .map_or(block.priority, i32::from);
+                Row::new(vec![
+                    Span::styled(block.level.to_string(), normal_style),
+                    Span::styled(block.hash.clone(), dimmed_text_style),
+                    Span::styled(priority.to_string(), normal_style),
+                    Span::styled(
+                        block.timestamp.format(&format_desc).unwrap_or_default(),
+                        dimmed_text_style,
+                    ),
+                    styled_time(
+                        statistics
+                            .and_then(|statistics| statistics.received_delay(block.timestamp)),
+                    ),
+                    styled_time(
+                        statistics.and_then(|statistics| statistics.total_application_time()),
+                    ),
+                    statistics
+                        .and_then(|statistics| statistics.baker.clone())
+                        .map_or_else(missing, |baker| Span::styled(baker, dimmed_text_style)),
+                ])
+                .height(1)
+            })
+            .collect();
+
+        let header = Row::new(vec![
+            "Level",
+            "Hash",
+            "Priority",
+            "Timestamp",
+            "Received",
+            "Application",
+            "Baker",
+        ])
+        .style(normal_style)
+        .height(1)
+        .bottom_margin(1);
+
+        let selected_style = Style::default()
+            .remove_modifier(Modifier::DIM)
+            .bg(Color::Black);
+
+        let table = Table::new(rows)
+            .header(header)
+            .block(Block::default().borders(Borders::ALL))
+            .highlight_style(selected_style)
+            .highlight_symbol("▶")
+            .widths(&[
+                Constraint::Length(9),
+                Constraint::Length(18),
+                Constraint::Length(8),
+                Constraint::Length(19),
+                Constraint::Length(11),
+                Constraint::Length(11),
+                Constraint::Min(12),
+            ]);
+
+        let mut table_state = TableState::default();
+        table_state.select(state.blocks.selected);
+        f.render_stateful_widget(table, blocks_chunk, &mut table_state);
+
+        // overlap the block corners with special separators to make flush transition to the table block
+        let vertical_left_separator = CustomSeparator::default()
+            .separator("├")
+            .corner(Corner::TopLeft);
+        f.render_widget(vertical_left_separator, blocks_chunk);
+
+        let vertical_right_separator = CustomSeparator::default()
+            .separator("┤")
+            .corner(Corner::TopRight);
+        f.render_widget(vertical_right_separator, blocks_chunk);
+
+        // ======================== DETAILS ========================
+        if let (Some(block), Some(details_chunk)) = (selected_block, details_chunk) {
+            let per_peer_stats = state
+                .baking
+                .per_peer_block_statistics
+                .get(&block.hash)
+                .cloned()
+                .unwrap_or_default();
+            draw_application_details(
+                state,
+                block.level,
+                state.baking.application_statistics.get(&block.hash),
+                &per_peer_stats,
+                details_chunk,
+                f,
+            );
+        }
+
+        // ======================== HEADER ========================
+        create_header_bar(page_chunks[0], state, f);
+
+        // ======================== PAGES TABS ========================
+        let tabs = create_pages_tabs(&state.ui);
+        f.render_widget(tabs, page_chunks[2]);
+
+        // ======================== Quit ========================
+        create_quit(page_chunks[2], f);
+    }
+}
@@ -0,0 +1,11 @@
+pub mod blocks_actions;
+pub use blocks_actions::*;
+
+pub mod blocks_reducer;
+pub use blocks_reducer::*;
+
+pub mod blocks_state;
+pub use blocks_state::*;
+
+pub mod blocks_view;
+pub use blocks_view::*;
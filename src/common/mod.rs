@@ -1,3 +1,4 @@
+use itertools::Itertools;
 use strum::IntoEnumIterator;
 use time::Duration;
 use tui::{
@@ -5,20 +6,25 @@ use tui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Span, Spans},
-    widgets::{Block, Borders, Paragraph, Tabs},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, TableState, Tabs},
     Frame,
 };
 
 use crate::{
     automaton::State,
+    extensions::SplitArea,
     terminal_ui::{ActivePage, UiState},
+    trends::draw_header_trends,
 };
 
+/// Height of the header bar: the current values, the sparklines of the last blocks and the border
+pub const HEADER_HEIGHT: u16 = 3;
+
 pub fn create_pages_tabs(ui_state: &UiState) -> Tabs {
-    // Note: only the first two screens are enabled for now
-    // take(2) - takes only the first 2 variants of the enum (Endorsements, Baking)
+    // Note: only the first six screens are enabled for now
+    // take(6) - takes only the first 6 variants of the enum (Endorsements, Baking, Rights, Blocks, Logs, Inspector)
     let titles = ActivePage::iter()
-        .take(2)
+        .take(6)
         .map(|t| {
             Spans::from(vec![
                 Span::styled(
@@ -48,6 +54,7 @@ pub fn create_help_bar<B: Backend>(help_chunk: Rect, f: &mut Frame<B>, delta_tog
     let help_strings = vec![
         ("←→↑↓", "Navigate Table"),
         ("s", "Sort"),
+        ("S", "Add Sort Key"),
         (
             "d",
             if delta_toggle {
@@ -56,6 +63,11 @@ pub fn create_help_bar<B: Backend>(help_chunk: Rect, f: &mut Frame<B>, delta_tog
                 "Delta values"
             },
         ),
+        ("f", "Follow Row"),
+        ("c", "Columns"),
+        ("e", "Export"),
+        ("i", "Chain Info"),
+        ("a", "Alerts"),
         ("TAB", "Switch Focus"),
     ];
 
@@ -89,6 +101,17 @@ pub fn create_header_bar<B: Backend>(header_chunk: Rect, state: &State, f: &mut
         .border_style(Style::default().add_modifier(Modifier::DIM));
     f.render_widget(block, header_chunk);
 
+    let [values_chunk, trends_chunk, _] = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Min(0),
+        ])
+        .split_area(header_chunk);
+
+    draw_header_trends(state, trends_chunk, f);
+
     let header_chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
@@ -98,7 +121,7 @@ pub fn create_header_bar<B: Backend>(header_chunk: Rect, state: &State, f: &mut
             Constraint::Length(18),
             Constraint::Min(50),
         ])
-        .split(header_chunk);
+        .split(values_chunk);
 
     let block_hash_short = if !header.hash.is_empty() {
         let start = header.hash.chars().take(6).collect::<String>();
@@ -149,12 +172,12 @@ pub fn create_header_bar<B: Backend>(header_chunk: Rect, state: &State, f: &mut
 
     f.render_widget(remote_level_paragraph, header_chunks[2]);
 
-    // show only the shorter version of the protocol
-    let protocol_short = if !header.protocol.is_empty() {
-        header.protocol.split_at(8).0.to_string()
-    } else {
-        header.protocol.to_owned()
-    };
+    // show only the shorter version of the protocol, the shorter ones as they are
+    let protocol_short = header
+        .protocol
+        .get(..8)
+        .unwrap_or(&header.protocol)
+        .to_string();
 
     let block_protocol = Paragraph::new(Spans::from(vec![
         Span::styled(
@@ -179,12 +202,17 @@ pub fn create_header_bar<B: Backend>(header_chunk: Rect, state: &State, f: &mut
         // Baking in 59 minutes
         // Endorsement in 59 minutes
 
-        let baking_in = if let Some((_, time)) = state.baking.baking_rights.next_baking(
+        let baking_in = if let Some((level, time)) = state.baking.baking_rights.next_baking(
             header.level,
             &header.timestamp,
             state.network_constants.minimal_block_delay,
+            state.ui.draw_timestamp(),
         ) {
-            time
+            if time.is_empty() {
+                format!("{} blocks", level - header.level)
+            } else {
+                time
+            }
         } else {
             String::from("Never")
         };
@@ -200,7 +228,7 @@ pub fn create_header_bar<B: Backend>(header_chunk: Rect, state: &State, f: &mut
 
         f.render_widget(baking, baker_info_chunks[0]);
 
-        let endorsing_in = if let Some((_, time)) = state
+        let endorsing_in = if let Some((level, time)) = state
             .endorsmenents
             .endorsement_rights_with_time
             .next_endorsing(
@@ -209,8 +237,13 @@ pub fn create_header_bar<B: Backend>(header_chunk: Rect, state: &State, f: &mut
                     state.network_constants.minimal_block_delay.into(),
                 )),
                 state.network_constants.minimal_block_delay,
+                state.ui.draw_timestamp(),
             ) {
-            time
+            if time.is_empty() {
+                format!("{} blocks", level - header.level)
+            } else {
+                time
+            }
         } else {
             String::from("Never")
         };
@@ -245,3 +278,260 @@ pub fn create_quit<B: Backend>(last_chunk: Rect, f: &mut Frame<B>) {
     .alignment(Alignment::Right);
     f.render_widget(quit, last_chunk);
 }
+
+/// Draw the column chooser of the focused table as a popup over the screen
+pub fn draw_column_chooser<B: Backend>(state: &State, f: &mut Frame<B>) {
+    let (chooser, table) = match (&state.ui.column_chooser, state.active_table()) {
+        (Some(chooser), Some(table)) => (chooser, table),
+        _ => return,
+    };
+
+    let size = f.size();
+    let width = 48.min(size.width);
+    // borders, header with its margin and the help line
+    let height = (table.columns().len() as u16 + 6).min(size.height);
+    let area = Rect::new(
+        (size.width - width) / 2,
+        (size.height - height) / 2,
+        width,
+        height,
+    );
+
+    let dim_style = Style::default().fg(Color::Gray).add_modifier(Modifier::DIM);
+    let rows: Vec<Row> = table
+        .columns()
+        .iter()
+        .map(|setup| {
+            let constraint = table.column_constraint(setup.column);
+            let width = match constraint {
+                Constraint::Min(unit) | Constraint::Length(unit) => unit.to_string(),
+                _ => String::from("-"),
+            };
+            let style = if setup.visible {
+                Style::default().fg(Color::White)
+            } else {
+                dim_style
+            };
+            Row::new(vec![
+                Cell::from(if setup.visible { "[x]" } else { "[ ]" }),
+                Cell::from(table.header(setup.column).to_string()),
+                Cell::from(if setup.pinned { "pinned" } else { "" }),
+                Cell::from(width),
+            ])
+            .style(style)
+        })
+        .collect();
+
+    let header = Row::new(vec!["", "Column", "", "Width"])
+        .style(dim_style)
+        .bottom_margin(1);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("Columns")
+        .style(Style::default().bg(Color::Rgb(31, 30, 30)));
+
+    let chooser_table = Table::new(rows)
+        .header(header)
+        .block(block)
+        .highlight_style(Style::default().bg(Color::DarkGray))
+        .widths(&[
+            Constraint::Length(3),
+            Constraint::Min(16),
+            Constraint::Length(6),
+            Constraint::Length(5),
+        ]);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(area);
+
+    let mut table_state = TableState::default();
+    table_state.select(Some(chooser.cursor));
+
+    f.render_widget(Clear, area);
+    f.render_stateful_widget(chooser_table, chunks[0], &mut table_state);
+
+    let help = Paragraph::new(Spans::from(vec![Span::styled(
+        "SPACE show  p pin  [ ] move  +/- width  c close",
+        dim_style,
+    )]))
+    .alignment(Alignment::Center)
+    .style(Style::default().bg(Color::Rgb(31, 30, 30)));
+    f.render_widget(help, chunks[1]);
+}
+
+/// Draw the chain info panel with the protocol constants as a popup over the screen
+pub fn draw_chain_info<B: Backend>(state: &State, f: &mut Frame<B>) {
+    if !state.ui.chain_info_open {
+        return;
+    }
+
+    let header = &state.current_head_header;
+    let level_info = &state.current_head_metadata.level_info;
+    let constants = &state.network_constants;
+
+    let missing = || String::from("-");
+    let or_missing = |value: Option<String>| value.unwrap_or_else(missing);
+
+    let cycle = if constants.blocks_per_cycle > 0 {
+        format!(
+            "{} ({}/{})",
+            level_info.cycle, level_info.cycle_position, constants.blocks_per_cycle
+        )
+    } else {
+        level_info.cycle.to_string()
+    };
+    let voting_period = or_missing(state.current_head_metadata.voting_period_info.as_ref().map(
+        |info| {
+            format!(
+                "{} #{} (position {}, {} remaining)",
+                info.voting_period.kind, info.voting_period.index, info.position, info.remaining
+            )
+        },
+    ));
+    let time_between_blocks = if constants.time_between_blocks.is_empty() {
+        missing()
+    } else {
+        constants
+            .time_between_blocks
+            .iter()
+            .map(|delay| format!("{}s", delay))
+            .join(", ")
+    };
+
+    let chain_rows = vec![
+        ("Chain id", header.chain_id.clone()),
+        ("Protocol", header.protocol.clone()),
+        ("Level", level_info.level.to_string()),
+        ("Cycle", cycle),
+    ];
+    let constant_rows = vec![
+        (
+            "Minimal block delay",
+            format!("{}s", constants.minimal_block_delay),
+        ),
+        ("Time between blocks", time_between_blocks),
+        ("Blocks per cycle", constants.blocks_per_cycle.to_string()),
+        ("Preserved cycles", constants.preserved_cycles.to_string()),
+        (
+            "Voting period length",
+            or_missing(
+                constants
+                    .voting_period_length()
+                    .map(|length| length.to_string()),
+            ),
+        ),
+        (
+            "Endorsement slots",
+            or_missing(constants.endorsement_slots().map(|slots| slots.to_string())),
+        ),
+        (
+            "Delay per missed endorsement",
+            or_missing(
+                constants
+                    .delay_per_missed_endorsement
+                    .map(|delay| format!("{}s", delay)),
+            ),
+        ),
+    ];
+
+    let mut governance_rows = vec![(String::from("Voting period"), voting_period)];
+    // proposals of an earlier period are outdated
+    let current_period = state
+        .current_head_metadata
+        .voting_period_info
+        .as_ref()
+        .map(|info| info.voting_period.index);
+    if current_period.is_some() && state.governance.proposals_period == current_period {
+        let total_power: i64 = state
+            .governance
+            .proposals
+            .iter()
+            .map(|proposal| proposal.voting_power())
+            .sum();
+        governance_rows.extend(state.governance.proposals.iter().take(5).enumerate().map(
+            |(index, proposal)| {
+                let share = if total_power > 0 {
+                    proposal.voting_power() as f64 * 100.0 / total_power as f64
+                } else {
+                    0.0
+                };
+                (
+                    format!("Proposal #{}", index + 1),
+                    format!(
+                        "{} {} ({:.1}%)",
+                        proposal.protocol().chars().take(12).collect::<String>(),
+                        proposal.voting_power(),
+                        share
+                    ),
+                )
+            },
+        ));
+    }
+
+    let dim_style = Style::default().fg(Color::Gray).add_modifier(Modifier::DIM);
+    let value_style = Style::default().fg(Color::White);
+    let title_style = Style::default()
+        .fg(Color::White)
+        .add_modifier(Modifier::BOLD);
+
+    let to_rows = |title: &'static str, rows: Vec<(String, String)>| {
+        std::iter::once(Row::new(vec![Cell::from(title)]).style(title_style)).chain(
+            rows.into_iter().map(move |(label, value)| {
+                Row::new(vec![
+                    Cell::from(format!("  {}", label)).style(dim_style),
+                    Cell::from(value).style(value_style),
+                ])
+            }),
+        )
+    };
+    let labeled = |rows: Vec<(&str, String)>| -> Vec<(String, String)> {
+        rows.into_iter()
+            .map(|(label, value)| (label.to_string(), value))
+            .collect()
+    };
+    let rows: Vec<Row> = to_rows("CHAIN", labeled(chain_rows))
+        .chain(std::iter::once(Row::new(vec![""])))
+        .chain(to_rows("GOVERNANCE", governance_rows))
+        .chain(std::iter::once(Row::new(vec![""])))
+        .chain(to_rows("CONSTANTS", labeled(constant_rows)))
+        .collect();
+
+    let size = f.size();
+    let width = 86.min(size.width);
+    // borders and the help line
+    let height = (rows.len() as u16 + 3).min(size.height);
+    let area = Rect::new(
+        (size.width - width) / 2,
+        (size.height - height) / 2,
+        width,
+        height,
+    );
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("Chain Info")
+        .style(Style::default().bg(Color::Rgb(31, 30, 30)));
+
+    // borders, label column and the column spacing
+    let widths = [
+        Constraint::Length(31),
+        Constraint::Length(width.saturating_sub(34)),
+    ];
+    let info_table = Table::new(rows).block(block).widths(&widths);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(area);
+
+    f.render_widget(Clear, area);
+    f.render_widget(info_table, chunks[0]);
+
+    let help = Paragraph::new(Spans::from(vec![Span::styled("i close", dim_style)]))
+        .alignment(Alignment::Center)
+        .style(Style::default().bg(Color::Rgb(31, 30, 30)));
+    f.render_widget(help, chunks[1]);
+}
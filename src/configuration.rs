@@ -1,7 +1,10 @@
 use clap::Parser;
-use std::str::FromStr;
+use serde::{Deserialize, Serialize};
+use std::{path::PathBuf, str::FromStr};
 use url::Url;
 
+use crate::{alerts::AlertRule, extensions::SortSpec};
+
 #[derive(Parser)]
 #[clap(author, version, about)]
 pub struct TuiArgs {
@@ -17,7 +20,184 @@ pub struct TuiArgs {
     #[clap(long)]
     pub baker_address: Option<String>,
 
+    /// File the diagnostics are logged to, they are shown on the log screen too
+    #[clap(long, parse(from_os_str), default_value = "tui.log")]
+    pub log_file: PathBuf,
+
+    /// Append to the log file instead of truncating it on start
+    #[clap(long)]
+    pub log_append: bool,
+
     /// (Debug) Record automaton actions
     #[clap(long)]
     pub record_actions: bool,
+
+    /// (Debug) File the actions are streamed to, rotated to <FILE>.1, <FILE>.2... once it grows large
+    #[clap(long, parse(from_os_str), default_value = "automaton_actions.ndjson")]
+    pub record_actions_path: PathBuf,
+
+    /// Path to the file the TUI settings (table sorting...) are persisted in
+    #[clap(long, parse(from_os_str), default_value = "tui_config.json")]
+    pub config: PathBuf,
+
+    /// Directory the table and screen exports are written to
+    #[clap(long, parse(from_os_str), default_value = ".")]
+    pub export_dir: PathBuf,
+
+    /// (Development) Run against a local mock node simulating the chain, instead of --node and --websocket
+    #[clap(long)]
+    pub mock: bool,
+
+    /// (Development) Json file with the scenario of the mock node
+    #[clap(long, parse(from_os_str), requires = "mock")]
+    pub mock_scenario: Option<PathBuf>,
+
+    /// Record the rpc responses and websocket messages received from the node to the file
+    #[clap(long, parse(from_os_str))]
+    pub record_traffic: Option<PathBuf>,
+
+    /// Replay a traffic recording instead of connecting to a node
+    #[clap(long, parse(from_os_str), conflicts_with_all = &["mock", "record-traffic"])]
+    pub replay: Option<PathBuf>,
+
+    /// Speed of the replay, 2 replays twice as fast as recorded
+    #[clap(long, default_value_t = 1.0, requires = "replay")]
+    pub replay_speed: f64,
+
+    /// Replay block by block, 'n' steps to the next block
+    #[clap(long, requires = "replay")]
+    pub replay_step: bool,
+}
+
+/// Settings persisted between runs
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct TuiConfig {
+    #[serde(default)]
+    pub tables: TablesConfig,
+    #[serde(default)]
+    pub alerts: AlertsConfig,
+    #[serde(default)]
+    pub retention: RetentionConfig,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct TablesConfig {
+    #[serde(default)]
+    pub endorsements: TableConfig,
+    #[serde(default)]
+    pub baking: TableConfig,
+    #[serde(default)]
+    pub operations: TableConfig,
+    #[serde(default)]
+    pub operation_details: TableConfig,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct TableConfig {
+    /// Sort keys in order of priority
+    #[serde(default)]
+    pub sort: Vec<SortSpec>,
+
+    /// Column layout in display order, empty for the default layout
+    #[serde(default)]
+    pub columns: Vec<ColumnConfig>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ColumnConfig {
+    /// Header of the column
+    pub name: String,
+    pub visible: bool,
+    pub pinned: bool,
+    #[serde(default)]
+    pub width: Option<u16>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AlertsConfig {
+    /// Rules evaluated on every state change, empty to disable the alerts
+    #[serde(default = "AlertRule::defaults")]
+    pub rules: Vec<AlertRule>,
+
+    /// Destinations the fired alerts are delivered to besides the alert log
+    #[serde(default)]
+    pub sinks: Vec<AlertSinkConfig>,
+}
+
+impl Default for AlertsConfig {
+    fn default() -> Self {
+        Self {
+            rules: AlertRule::defaults(),
+            sinks: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AlertSinkConfig {
+    #[serde(flatten)]
+    pub kind: AlertSinkKind,
+
+    /// Most alerts delivered in a minute, 0 for no limit
+    #[serde(default = "AlertSinkConfig::default_max_per_minute")]
+    pub max_per_minute: usize,
+
+    /// Alerts with the same key are delivered once in this many seconds, 0 to deliver all
+    #[serde(default = "AlertSinkConfig::default_dedup_seconds")]
+    pub dedup_seconds: u64,
+}
+
+impl AlertSinkConfig {
+    fn default_max_per_minute() -> usize {
+        6
+    }
+
+    fn default_dedup_seconds() -> u64 {
+        300
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "sink", rename_all = "snake_case")]
+pub enum AlertSinkKind {
+    /// POST the alert as json
+    Webhook { url: String },
+    /// Run a shell command with the alert in the environment
+    Exec { command: String },
+    /// Append the alert as a json line
+    File { path: PathBuf },
+}
+
+/// How much per block history is kept in memory, the stricter of the two limits applies.
+/// Governs the statistics, the recent blocks, the latency window, the header trends and the rights calendar.
+/// The statistics of the past levels are fetched on request and usually older than the limits, they are only
+/// capped by their number like the log records, the inspected calls and the alert log
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RetentionConfig {
+    /// Number of the last levels kept, 0 for no limit
+    #[serde(default = "RetentionConfig::default_max_levels")]
+    pub max_levels: u32,
+
+    /// Levels older than this many seconds are dropped, 0 for no limit
+    #[serde(default = "RetentionConfig::default_max_age_seconds")]
+    pub max_age_seconds: u64,
+}
+
+impl RetentionConfig {
+    fn default_max_levels() -> u32 {
+        360
+    }
+
+    fn default_max_age_seconds() -> u64 {
+        6 * 60 * 60
+    }
+}
+
+impl Default for RetentionConfig {
+    fn default() -> Self {
+        Self {
+            max_levels: Self::default_max_levels(),
+            max_age_seconds: Self::default_max_age_seconds(),
+        }
+    }
 }
@@ -0,0 +1,74 @@
+use serde_json::Value;
+
+use crate::automaton::State;
+
+/// A value of the state changed by an action
+#[derive(Debug, Clone, PartialEq)]
+pub struct StateChange {
+    /// Path of the value in the serialized state, e.g. `blocks.heads[2].level`
+    pub path: String,
+    /// `None` when the action added the value
+    pub before: Option<Value>,
+    /// `None` when the action removed the value
+    pub after: Option<Value>,
+}
+
+/// Part of the state changed by every action, left out of the diffs
+const UNDIFFED_FIELD: &str = "inspector";
+
+/// Structural difference of the serialized states, the changed fields and items down to the leaves
+pub fn diff_states(before: &State, after: &State) -> Vec<StateChange> {
+    // the state is serialized to json for the dumps too, it does not fail
+    let mut before = serde_json::to_value(before).unwrap_or_default();
+    let mut after = serde_json::to_value(after).unwrap_or_default();
+    for state in [&mut before, &mut after] {
+        if let Value::Object(fields) = state {
+            fields.remove(UNDIFFED_FIELD);
+        }
+    }
+    diff_values(&before, &after)
+}
+
+/// Structural difference of the json values, objects are compared by the keys and arrays by the indexes
+pub fn diff_values(before: &Value, after: &Value) -> Vec<StateChange> {
+    let mut changes = Vec::new();
+    collect_changes(String::new(), Some(before), Some(after), &mut changes);
+    changes
+}
+
+fn collect_changes(
+    path: String,
+    before: Option<&Value>,
+    after: Option<&Value>,
+    changes: &mut Vec<StateChange>,
+) {
+    match (before, after) {
+        (Some(Value::Object(before)), Some(Value::Object(after))) => {
+            let added = after.keys().filter(|key| !before.contains_key(*key));
+            for key in before.keys().chain(added) {
+                let path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                collect_changes(path, before.get(key), after.get(key), changes);
+            }
+        }
+        (Some(Value::Array(before)), Some(Value::Array(after))) => {
+            for index in 0..before.len().max(after.len()) {
+                collect_changes(
+                    format!("{}[{}]", path, index),
+                    before.get(index),
+                    after.get(index),
+                    changes,
+                );
+            }
+        }
+        (before, after) if before != after => changes.push(StateChange {
+            path,
+            before: before.cloned(),
+            after: after.cloned(),
+        }),
+        _ => {}
+    }
+}
@@ -0,0 +1,90 @@
+use crossterm::event::KeyCode;
+
+use crate::automaton::ActionRecording;
+
+use super::{diff_states, StateChange, Timeline};
+
+/// Actions skipped by a page up/down
+pub const PAGE_ACTIONS: usize = 100;
+
+/// Pane scrolled by the scroll keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebuggerPane {
+    Payload,
+    Diff,
+}
+
+/// The debugger of a recorded run, the selected action with its payload and the state changes it made
+pub struct Debugger {
+    pub timeline: Timeline,
+    pub focus: DebuggerPane,
+    pub payload_scroll: u16,
+    pub diff_scroll: u16,
+    /// Pretty json of the selected action
+    pub payload: String,
+    /// The changes of the state made by the selected action
+    pub changes: Vec<StateChange>,
+}
+
+impl Debugger {
+    pub fn new(recording: ActionRecording) -> Self {
+        let mut debugger = Self {
+            timeline: Timeline::new(recording),
+            focus: DebuggerPane::Diff,
+            payload_scroll: 0,
+            diff_scroll: 0,
+            payload: String::new(),
+            changes: Vec::new(),
+        };
+        debugger.refresh();
+        debugger
+    }
+
+    /// Handle the pressed key, `false` once the debugger should quit
+    pub fn handle_key(&mut self, key: KeyCode) -> bool {
+        let position = self.timeline.position();
+        match key {
+            KeyCode::F(10) | KeyCode::Char('q') => return false,
+            KeyCode::Right => self.timeline.step_forward(),
+            KeyCode::Left => self.timeline.step_backward(),
+            KeyCode::Down => self.timeline.next_top_level(),
+            KeyCode::Up => self.timeline.previous_top_level(),
+            KeyCode::PageDown => self.timeline.seek(position.saturating_add(PAGE_ACTIONS)),
+            KeyCode::PageUp => self.timeline.seek(position.saturating_sub(PAGE_ACTIONS)),
+            KeyCode::Home => self.timeline.seek(0),
+            KeyCode::End => self.timeline.seek(usize::MAX),
+            KeyCode::Tab => {
+                self.focus = match self.focus {
+                    DebuggerPane::Payload => DebuggerPane::Diff,
+                    DebuggerPane::Diff => DebuggerPane::Payload,
+                }
+            }
+            KeyCode::Char('j') => *self.scroll_mut() = self.scroll_mut().saturating_add(1),
+            KeyCode::Char('k') => *self.scroll_mut() = self.scroll_mut().saturating_sub(1),
+            _ => {}
+        }
+
+        if self.timeline.position() != position {
+            self.refresh();
+        }
+        true
+    }
+
+    fn scroll_mut(&mut self) -> &mut u16 {
+        match self.focus {
+            DebuggerPane::Payload => &mut self.payload_scroll,
+            DebuggerPane::Diff => &mut self.diff_scroll,
+        }
+    }
+
+    fn refresh(&mut self) {
+        self.payload = self
+            .timeline
+            .action()
+            .and_then(|action| serde_json::to_string_pretty(&action.action).ok())
+            .unwrap_or_default();
+        self.changes = diff_states(self.timeline.state_before(), self.timeline.state_after());
+        self.payload_scroll = 0;
+        self.diff_scroll = 0;
+    }
+}
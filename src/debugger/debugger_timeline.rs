@@ -0,0 +1,165 @@
+use crate::automaton::{reducer, ActionRecording, ActionWithMeta, State};
+
+/// Number of actions between the kept states, seeking replays at most this many actions
+pub const CHECKPOINT_INTERVAL: usize = 256;
+
+/// The states of a recorded run, reproduced by applying the recorded actions through the reducer
+pub struct Timeline {
+    actions: Vec<ActionWithMeta>,
+    /// State before every `CHECKPOINT_INTERVAL`th action
+    checkpoints: Vec<State>,
+    /// Number of the recorded checkpoints the replayed states were compared to
+    verified_checkpoints: usize,
+    /// Index of the action before the first recorded checkpoint the replayed state differs from
+    diverged_at: Option<usize>,
+    /// Index of the selected action
+    position: usize,
+    before: State,
+    after: State,
+}
+
+impl Timeline {
+    pub fn new(recording: ActionRecording) -> Self {
+        let ActionRecording {
+            checkpoints: recorded,
+            actions,
+            ..
+        } = recording;
+        let mut recorded = recorded.into_iter();
+        // a loaded recording starts with a checkpoint
+        let init_state = recorded
+            .next()
+            .map(|checkpoint| checkpoint.state)
+            .unwrap_or_else(|| State::new(None, slog::Logger::root(slog::Discard, slog::o!())));
+
+        let mut checkpoints = Vec::with_capacity(actions.len() / CHECKPOINT_INTERVAL + 1);
+        let mut verified_checkpoints = 0;
+        let mut diverged_at = None;
+        let mut recorded = recorded.peekable();
+        let mut state = init_state.clone();
+        for index in 0..=actions.len() {
+            while let Some(checkpoint) = recorded.next_if(|checkpoint| checkpoint.position == index)
+            {
+                verified_checkpoints += 1;
+                if diverged_at.is_none() && checkpoint.state != state {
+                    diverged_at = Some(index);
+                }
+            }
+            if let Some(action) = actions.get(index) {
+                if index % CHECKPOINT_INTERVAL == 0 {
+                    checkpoints.push(state.clone());
+                }
+                reducer(&mut state, action);
+            }
+        }
+
+        let mut timeline = Self {
+            actions,
+            checkpoints,
+            verified_checkpoints,
+            diverged_at,
+            position: 0,
+            before: init_state.clone(),
+            after: init_state,
+        };
+        timeline.seek(0);
+        timeline
+    }
+
+    pub fn len(&self) -> usize {
+        self.actions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn actions(&self) -> &[ActionWithMeta] {
+        &self.actions
+    }
+
+    /// The selected action, `None` if the dump has no actions
+    pub fn action(&self) -> Option<&ActionWithMeta> {
+        self.actions.get(self.position)
+    }
+
+    /// State before the selected action
+    pub fn state_before(&self) -> &State {
+        &self.before
+    }
+
+    /// State after the selected action
+    pub fn state_after(&self) -> &State {
+        &self.after
+    }
+
+    pub fn verified_checkpoints(&self) -> usize {
+        self.verified_checkpoints
+    }
+
+    /// Index of the action before the first recorded checkpoint the replay differs from, `None` if all of them match
+    pub fn diverged_at(&self) -> Option<usize> {
+        self.diverged_at
+    }
+
+    /// Select the action, the positions past the end select the last action
+    pub fn seek(&mut self, position: usize) {
+        if self.actions.is_empty() {
+            return;
+        }
+        let position = position.min(self.actions.len() - 1);
+
+        let checkpoint = position / CHECKPOINT_INTERVAL;
+        let mut state = self.checkpoints[checkpoint].clone();
+        for action in &self.actions[checkpoint * CHECKPOINT_INTERVAL..position] {
+            reducer(&mut state, action);
+        }
+        self.before = state.clone();
+        reducer(&mut state, &self.actions[position]);
+        self.after = state;
+        self.position = position;
+    }
+
+    pub fn step_forward(&mut self) {
+        let next = self.position + 1;
+        if next >= self.actions.len() {
+            return;
+        }
+        // the next action starts from the state the selected one ended in
+        self.before = self.after.clone();
+        reducer(&mut self.after, &self.actions[next]);
+        self.position = next;
+    }
+
+    pub fn step_backward(&mut self) {
+        if self.position > 0 {
+            self.seek(self.position - 1);
+        }
+    }
+
+    /// Select the next action dispatched from outside of the effects
+    pub fn next_top_level(&mut self) {
+        let next = self
+            .actions
+            .iter()
+            .skip(self.position + 1)
+            .position(|action| action.depth == 0);
+        if let Some(offset) = next {
+            self.seek(self.position + 1 + offset);
+        }
+    }
+
+    /// Select the previous action dispatched from outside of the effects
+    pub fn previous_top_level(&mut self) {
+        let previous = self.actions[..self.position]
+            .iter()
+            .rposition(|action| action.depth == 0);
+        if let Some(position) = previous {
+            self.seek(position);
+        }
+    }
+}
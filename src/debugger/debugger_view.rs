@@ -0,0 +1,197 @@
+use serde_json::Value;
+use tui::{
+    backend::Backend,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use super::{Debugger, DebuggerPane};
+
+/// Draw the recorded actions, the payload of the selected one and the state changes it made
+pub fn draw_debugger<B: Backend>(debugger: &Debugger, f: &mut Frame<B>) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Min(3),
+            Constraint::Length(1),
+        ])
+        .split(f.size());
+
+    draw_position(debugger, chunks[0], f);
+
+    let body = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(35), Constraint::Percentage(65)])
+        .split(chunks[1]);
+    let details = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+        .split(body[1]);
+
+    draw_actions(debugger, body[0], f);
+    draw_payload(debugger, details[0], f);
+    draw_changes(debugger, details[1], f);
+
+    let dim_style = Style::default().fg(Color::Gray).add_modifier(Modifier::DIM);
+    let help = Paragraph::new(Spans::from(vec![Span::styled(
+        "←/→ step  ↑/↓ top level action  PgUp/PgDn 100 actions  Home/End  TAB focus  j/k scroll  q quit",
+        dim_style,
+    )]))
+    .alignment(Alignment::Center);
+    f.render_widget(help, chunks[2]);
+}
+
+fn draw_position<B: Backend>(debugger: &Debugger, area: Rect, f: &mut Frame<B>) {
+    let timeline = &debugger.timeline;
+    let dim_style = Style::default().fg(Color::Gray).add_modifier(Modifier::DIM);
+    let value_style = Style::default().fg(Color::White);
+
+    let mut spans = match timeline.action() {
+        Some(action) => {
+            let start = timeline
+                .actions()
+                .first()
+                .map(|first| first.time_as_nanos())
+                .unwrap_or_default();
+            let since_start = action.time_as_nanos().saturating_sub(start) as f64 / 1_000_000_000.0;
+            vec![
+                Span::styled("Action: ", dim_style),
+                Span::styled(
+                    format!("{}/{} ", timeline.position() + 1, timeline.len()),
+                    value_style,
+                ),
+                Span::styled(
+                    format!("{:?} ", action.action.kind()),
+                    value_style.add_modifier(Modifier::BOLD),
+                ),
+                Span::styled("Depth: ", dim_style),
+                Span::styled(format!("{} ", action.depth), value_style),
+                Span::styled("Time: ", dim_style),
+                Span::styled(format!("+{:.3}s ", since_start), value_style),
+            ]
+        }
+        None => vec![Span::styled("The dump has no actions ", dim_style)],
+    };
+
+    spans.push(match timeline.diverged_at() {
+        None => Span::styled(
+            format!(
+                "replay matches the recorded checkpoints ({})",
+                timeline.verified_checkpoints()
+            ),
+            dim_style,
+        ),
+        Some(position) => Span::styled(
+            format!(
+                "replay differs from the recorded checkpoint before action {}",
+                position + 1
+            ),
+            Style::default().fg(Color::Red),
+        ),
+    });
+
+    f.render_widget(Paragraph::new(Spans::from(spans)), area);
+}
+
+fn draw_actions<B: Backend>(debugger: &Debugger, area: Rect, f: &mut Frame<B>) {
+    let timeline = &debugger.timeline;
+    let height = area.height.saturating_sub(2) as usize;
+    // keep the selected action in the middle
+    let first = timeline
+        .position()
+        .saturating_sub(height / 2)
+        .min(timeline.len().saturating_sub(height));
+
+    let lines: Vec<Spans> = timeline
+        .actions()
+        .iter()
+        .enumerate()
+        .skip(first)
+        .take(height)
+        .map(|(index, action)| {
+            let style = if index == timeline.position() {
+                Style::default().add_modifier(Modifier::REVERSED)
+            } else if action.depth == 0 {
+                Style::default().fg(Color::White)
+            } else {
+                Style::default().fg(Color::Gray)
+            };
+            Spans::from(Span::styled(
+                format!(
+                    "{:>7} {}{:?}",
+                    index + 1,
+                    "  ".repeat(action.depth as usize),
+                    action.action.kind()
+                ),
+                style,
+            ))
+        })
+        .collect();
+
+    let block = Block::default().borders(Borders::ALL).title("Actions");
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+fn draw_payload<B: Backend>(debugger: &Debugger, area: Rect, f: &mut Frame<B>) {
+    let block = pane_block("Payload", debugger.focus == DebuggerPane::Payload);
+    let payload = Paragraph::new(debugger.payload.as_str())
+        .block(block)
+        .scroll((debugger.payload_scroll, 0));
+    f.render_widget(payload, area);
+}
+
+fn draw_changes<B: Backend>(debugger: &Debugger, area: Rect, f: &mut Frame<B>) {
+    let dim_style = Style::default().fg(Color::Gray).add_modifier(Modifier::DIM);
+
+    let lines: Vec<Spans> = if debugger.changes.is_empty() {
+        vec![Spans::from(Span::styled(
+            "the action did not change the state",
+            dim_style,
+        ))]
+    } else {
+        debugger
+            .changes
+            .iter()
+            .map(|change| {
+                Spans::from(vec![
+                    Span::styled(change.path.clone(), Style::default().fg(Color::White)),
+                    Span::styled(": ", dim_style),
+                    Span::styled(
+                        display_value(change.before.as_ref()),
+                        Style::default().fg(Color::Red),
+                    ),
+                    Span::styled(" → ", dim_style),
+                    Span::styled(
+                        display_value(change.after.as_ref()),
+                        Style::default().fg(Color::Green),
+                    ),
+                ])
+            })
+            .collect()
+    };
+
+    let title = format!("State changes ({})", debugger.changes.len());
+    let block = pane_block(&title, debugger.focus == DebuggerPane::Diff);
+    let changes = Paragraph::new(lines)
+        .block(block)
+        .scroll((debugger.diff_scroll, 0));
+    f.render_widget(changes, area);
+}
+
+fn pane_block(title: &str, focused: bool) -> Block<'_> {
+    Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(Style::default().fg(if focused { Color::Blue } else { Color::White }))
+}
+
+fn display_value(value: Option<&Value>) -> String {
+    match value {
+        Some(value) => value.to_string(),
+        None => "(none)".to_string(),
+    }
+}
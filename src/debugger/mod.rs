@@ -0,0 +1,11 @@
+pub mod debugger_diff;
+pub use debugger_diff::*;
+
+pub mod debugger_timeline;
+pub use debugger_timeline::*;
+
+pub mod debugger_state;
+pub use debugger_state::*;
+
+pub mod debugger_view;
+pub use debugger_view::*;
@@ -63,7 +63,9 @@ impl EnablingCondition<State> for DrawEndorsementsScreenAction {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct EndorsementsRightsWithTimeGetAction {}
+pub struct EndorsementsRightsWithTimeGetAction {
+    pub cycle: i32,
+}
 
 impl EnablingCondition<State> for EndorsementsRightsWithTimeGetAction {
     fn is_enabled(&self, _: &State) -> bool {
@@ -73,6 +75,9 @@ impl EnablingCondition<State> for EndorsementsRightsWithTimeGetAction {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EndorsementsRightsWithTimeReceivedAction {
+    /// Missing in the recordings of the older versions
+    #[serde(default)]
+    pub cycle: i32,
     pub rights: Vec<EndorsementRightsWithTimePerLevel>,
 }
 
@@ -7,8 +7,6 @@ use crate::{
     },
 };
 
-use super::EndorsementsRightsWithTimeGetAction;
-
 pub fn endorsement_effects<S>(store: &mut Store<S>, action: &ActionWithMeta)
 where
     S: Service,
@@ -29,25 +27,13 @@ where
                     )),
                 ),
             });
-
-            let is_empty = store
-                .state()
-                .endorsmenents
-                .endorsement_rights_with_time
-                .rights
-                .is_empty();
-            if is_empty {
-                store.dispatch(EndorsementsRightsWithTimeGetAction {});
-            }
         }
-        Action::EndorsementsRightsWithTimeGet(_) => {
-            // TODO: change this to correnct cycle
-            let cycle = store.state().current_head_header.level / 4096;
+        Action::EndorsementsRightsWithTimeGet(action) => {
             if let Some(delegate) = store.state().baker_address.clone() {
                 store.dispatch(RpcRequestAction {
                     call: RpcCall::new(
                         RpcTarget::EndorsementRightsWithTime,
-                        Some(format!("?delegate={}&cycle={}", delegate, cycle)),
+                        Some(format!("?delegate={}&cycle={}", delegate, action.cycle)),
                     ),
                 });
             }
@@ -3,12 +3,12 @@ use std::collections::BTreeMap;
 use crate::automaton::{Action, ActionWithMeta, State};
 
 use super::{
-    EndorsementOperationSummary, EndorsementRightsWithTime, EndorsementState, EndorsementStatus,
-    EndorsementStatusSortable, EndorsementStatusSortableVec,
+    EndorsementOperationSummary, EndorsementState, EndorsementStatus, EndorsementStatusSortable,
+    EndorsementStatusSortableVec,
 };
 
 pub fn endorsementrs_reducer(state: &mut State, action: &ActionWithMeta) {
-    // let action_time = action.time_as_nanos();
+    let now = (action.time_as_nanos() / 1_000_000_000) as i64;
 
     match &action.action {
         Action::EndorsementsRightsReceived(action) => {
@@ -49,18 +49,17 @@ pub fn endorsementrs_reducer(state: &mut State, action: &ActionWithMeta) {
                 let delta_toggle = state.delta_toggle;
 
                 state.endorsmenents.endoresement_status_summary = sumary;
-                state.endorsmenents.endorsement_table.content =
-                    endorsement_operation_time_statistics;
-
                 state
                     .endorsmenents
                     .endorsement_table
-                    .sort_content(delta_toggle);
+                    .set_content(endorsement_operation_time_statistics, delta_toggle);
             }
         }
         Action::EndorsementsRightsWithTimeReceived(action) => {
-            state.endorsmenents.endorsement_rights_with_time =
-                EndorsementRightsWithTime::new(&action.rights);
+            state
+                .endorsmenents
+                .endorsement_rights_with_time
+                .add(&action.rights);
         }
         Action::MempoolEndorsementStatsReceived(stats) => {
             // let injected_endorsement_stats = stats.stats.iter().find(|(oph, stats)| stats.is_injected());
@@ -87,6 +86,7 @@ pub fn endorsementrs_reducer(state: &mut State, action: &ActionWithMeta) {
                     state.previous_head_header.level,
                     state.previous_head_header.timestamp,
                     state.network_constants.minimal_block_delay,
+                    now,
                 )
             {
                 if endorsing_level == state.previous_head_header.level {
@@ -114,6 +114,12 @@ pub fn endorsementrs_reducer(state: &mut State, action: &ActionWithMeta) {
                         injected_endorsement_summary;
                 }
             }
+
+            // the previous head is still needed for the summary above on the next head
+            state
+                .endorsmenents
+                .endorsement_rights_with_time
+                .cleanup(&state.previous_head_header.level);
         }
         _ => {}
     }
@@ -13,8 +13,8 @@ use tui::{
 use crate::{
     baking::BlockApplicationStatistics,
     extensions::{
-        convert_time_to_unit_string, get_time_style, ExtendedTable, SortableByFocus, StyledTime,
-        TuiTableData,
+        convert_time_to_unit_string, get_time_style, ExtendedTable, SortKey, SortKeyByFocus,
+        StyledTime, TableRowKey, TuiTableData,
     },
     operations::OperationStats,
 };
@@ -42,13 +42,15 @@ pub struct EndorsementRightsWithTime {
 }
 
 impl EndorsementRightsWithTime {
-    pub fn new(raw: &[EndorsementRightsWithTimePerLevel]) -> Self {
+    pub fn add(&mut self, raw: &[EndorsementRightsWithTimePerLevel]) {
         let organized = raw
             .iter()
-            .map(|rights_per_level| (rights_per_level.level, rights_per_level.estimated_time))
-            .collect();
+            .map(|rights_per_level| (rights_per_level.level, rights_per_level.estimated_time));
+        self.rights.extend(organized);
+    }
 
-        Self { rights: organized }
+    pub fn cleanup(&mut self, to_level: &i32) {
+        self.rights.retain(|key, _| key >= to_level);
     }
 
     // TODO: same thing as in baking rights, move to common trait?
@@ -57,13 +59,13 @@ impl EndorsementRightsWithTime {
         level: i32,
         block_timestamp: OffsetDateTime,
         block_delay: i32,
+        now: i64,
     ) -> Option<(i32, String)> {
         self.rights
             .range(level..)
             .next()
             .map(|(endorsement_level, time)| {
                 if time.is_some() {
-                    let now = OffsetDateTime::now_utc().unix_timestamp();
                     let block_time = block_timestamp.unix_timestamp();
                     let level_delta = endorsement_level - level;
                     let until_endorsing =
@@ -151,56 +153,34 @@ impl Default for EndrosementsState {
     }
 }
 
-impl SortableByFocus for EndorsementStatusSortableVec {
-    fn sort_by_focus(&mut self, focus_index: usize, delta_toggle: bool) {
+impl SortKeyByFocus for EndorsementStatusSortable {
+    fn sort_key(&self, focus_index: usize, delta_toggle: bool) -> Option<SortKey> {
+        let pick = |concrete: Option<u64>, delta: Option<u64>| {
+            if delta_toggle {
+                delta
+            } else {
+                concrete
+            }
+        };
+
         match focus_index {
-            0 => self.sort_by_key(|k| k.slot_count),
-            1 => self.sort_by_key(|k| k.baker.clone()),
-            2 => self.sort_by_key(|k| k.state.clone()),
-            3 => self.sort_by_key(|k| k.delta),
-            4 => self.sort_by_key(|k| k.received_hash_time),
-            5 => self.sort_by_key(|k| {
-                if delta_toggle {
-                    k.received_contents_time_delta
-                } else {
-                    k.received_contents_time
-                }
-            }),
-            6 => self.sort_by_key(|k| {
-                if delta_toggle {
-                    k.decoded_time_delta
-                } else {
-                    k.decoded_time
-                }
-            }),
-            7 => self.sort_by_key(|k| {
-                if delta_toggle {
-                    k.prechecked_time_delta
-                } else {
-                    k.prechecked_time
-                }
-            }),
-            8 => self.sort_by_key(|k| {
-                if delta_toggle {
-                    k.applied_time_delta
-                } else {
-                    k.applied_time
-                }
-            }),
-            9 => self.sort_by_key(|k| {
-                if delta_toggle {
-                    k.broadcast_time_delta
-                } else {
-                    k.broadcast_time
-                }
-            }),
-            _ => {}
+            0 => Some(self.slot_count.into()),
+            1 => Some(self.baker.clone().into()),
+            2 => Some(SortKey::Number(self.state.clone() as i128)),
+            3 => self.delta.map(SortKey::from),
+            4 => self.received_hash_time.map(SortKey::from),
+            5 => pick(
+                self.received_contents_time,
+                self.received_contents_time_delta,
+            )
+            .map(SortKey::from),
+            6 => pick(self.decoded_time, self.decoded_time_delta).map(SortKey::from),
+            7 => pick(self.prechecked_time, self.prechecked_time_delta).map(SortKey::from),
+            8 => pick(self.applied_time, self.applied_time_delta).map(SortKey::from),
+            9 => pick(self.broadcast_time, self.broadcast_time_delta).map(SortKey::from),
+            _ => None,
         }
     }
-
-    fn rev(&mut self) {
-        self.reverse()
-    }
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -334,7 +314,17 @@ impl EndorsementStatusSortable {
     }
 }
 
+impl TableRowKey for EndorsementStatusSortable {
+    fn row_key(&self) -> String {
+        self.baker.clone()
+    }
+}
+
 impl TuiTableData for EndorsementStatusSortable {
+    fn duration_columns() -> &'static [usize] {
+        &[3, 4, 5, 6, 7, 8, 9]
+    }
+
     fn construct_tui_table_data(&self, delta_toggle: bool) -> Vec<(String, Style)> {
         let mut final_vec = Vec::with_capacity(9);
         let missing_value = (String::from('-'), Style::default().fg(Color::DarkGray));
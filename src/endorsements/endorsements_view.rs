@@ -11,12 +11,14 @@ use tui::{
     Frame,
 };
 
-use itertools::Itertools;
 use strum::IntoEnumIterator;
 
 use crate::automaton::State;
-use crate::common::{create_header_bar, create_help_bar, create_pages_tabs, create_quit};
-use crate::extensions::{CustomSeparator, Renderable};
+use crate::common::{
+    create_header_bar, create_help_bar, create_pages_tabs, create_quit, draw_column_chooser,
+    HEADER_HEIGHT,
+};
+use crate::extensions::{CustomSeparator, Renderable, SplitArea};
 
 use super::{EndorsementOperationSummary, EndorsementState};
 pub struct EndorsementsScreen {}
@@ -34,25 +36,19 @@ impl<B: Backend> Renderable<B> for EndorsementsScreen {
             .constraints([Constraint::Min(5), Constraint::Length(1)])
             .split(size);
 
-        let (header_chunk, summary_chunk, endorsements_chunk) = Layout::default()
+        let [header_chunk, summary_chunk, endorsements_chunk] = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(2),
+                Constraint::Length(HEADER_HEIGHT),
                 Constraint::Length(1),
                 Constraint::Min(1),
             ])
-            .split(page_chunks[0])
-            .into_iter()
-            .collect_tuple()
-            .unwrap(); // safe as we specify 3 elements in constraints and collecting into tuple of size 3
+            .split_area(page_chunks[0]);
 
-        let (endorsement_table_chunk, endorsing_panel_chunk) = Layout::default()
+        let [endorsement_table_chunk, endorsing_panel_chunk] = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
-            .split(endorsements_chunk)
-            .into_iter()
-            .collect_tuple()
-            .unwrap();
+            .split_area(endorsements_chunk);
 
         // ======================== HEADER ========================
         create_header_bar(header_chunk, state, f);
@@ -110,13 +106,10 @@ impl<B: Backend> Renderable<B> for EndorsementsScreen {
         } else {
             endorsements_chunk
         };
-        let (endorsement_table_help_chunk, endorsement_table_inner_chunk) = Layout::default()
+        let [endorsement_table_help_chunk, endorsement_table_inner_chunk] = Layout::default()
             .direction(Direction::Vertical)
             .constraints([Constraint::Length(2), Constraint::Min(1)])
-            .split(table_chunk)
-            .into_iter()
-            .collect_tuple()
-            .unwrap();
+            .split_area(table_chunk);
 
         create_help_bar(endorsement_table_help_chunk, f, delta_toggle);
 
@@ -130,7 +123,7 @@ impl<B: Backend> Renderable<B> for EndorsementsScreen {
         let renderable_constraints = state
             .endorsmenents
             .endorsement_table
-            .renderable_constraints(f.size().width - 2);
+            .renderable_constraints(f.size().width.saturating_sub(2));
         let header_cells = state
             .endorsmenents
             .endorsement_table
@@ -145,7 +138,12 @@ impl<B: Backend> Renderable<B> for EndorsementsScreen {
             .endorsement_table
             .renderable_rows(&state.endorsmenents.endorsement_table.content, delta_toggle);
 
-        let highlight_symbol = "▶".to_string().to_ascii_uppercase();
+        // the pinned row gets a different marker so it is clear the selection is following it
+        let highlight_symbol = if state.endorsmenents.endorsement_table.followed().is_some() {
+            "●".to_string()
+        } else {
+            "▶".to_string()
+        };
 
         let table = Table::new(rows)
             .header(header)
@@ -175,21 +173,15 @@ impl<B: Backend> Renderable<B> for EndorsementsScreen {
 
         // ======================== BAKER ENDORSING PANEL ========================
         if state.baker_address.is_some() {
-            let (
-                endorsing_panel_title_chunk,
-                endorsing_panel_level_chunk,
-                endorsing_panel_inner_chunk,
-            ) = Layout::default()
-                .direction(Direction::Vertical)
-                .constraints([
-                    Constraint::Length(3),
-                    Constraint::Length(2),
-                    Constraint::Min(1),
-                ])
-                .split(endorsing_panel_chunk)
-                .into_iter()
-                .collect_tuple()
-                .unwrap();
+            let [endorsing_panel_title_chunk, endorsing_panel_level_chunk, endorsing_panel_inner_chunk] =
+                Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([
+                        Constraint::Length(3),
+                        Constraint::Length(2),
+                        Constraint::Min(1),
+                    ])
+                    .split_area(endorsing_panel_chunk);
 
             // f.render_widget(endorser_panel_title, endorsing_panel_title_chunk);
             let current_head_level = state.current_head_header.level;
@@ -205,6 +197,7 @@ impl<B: Backend> Renderable<B> for EndorsementsScreen {
                         state.network_constants.minimal_block_delay.into(),
                     )),
                     state.network_constants.minimal_block_delay,
+                    state.ui.draw_timestamp(),
                 );
 
             let (next_endorsing_time_label, next_endorsing_delta_label) =
@@ -213,7 +206,12 @@ impl<B: Backend> Renderable<B> for EndorsementsScreen {
                     (
                         Span::styled(format!("{} ", level), Style::default().fg(Color::White)),
                         Span::styled(
-                            format!("{} ({} blocks)", time, blocks_delta),
+                            // without an estimated time only the distance in blocks is known
+                            if time.is_empty() {
+                                format!("{} blocks", blocks_delta)
+                            } else {
+                                format!("{} ({} blocks)", time, blocks_delta)
+                            },
                             Style::default().fg(Color::White),
                         ),
                     )
@@ -259,6 +257,7 @@ impl<B: Backend> Renderable<B> for EndorsementsScreen {
                     current_head_level,
                     *current_head_timestamp,
                     state.network_constants.minimal_block_delay,
+                    state.ui.draw_timestamp(),
                 );
 
             let last_endorsement_level_string = if let Some((level, _)) = next_endorsing {
@@ -363,5 +362,8 @@ impl<B: Backend> Renderable<B> for EndorsementsScreen {
 
         // ======================== Quit ========================
         create_quit(page_chunks[1], f);
+
+        // ======================== COLUMN CHOOSER ========================
+        draw_column_chooser(state, f);
     }
 }
@@ -0,0 +1,66 @@
+use redux_rs::EnablingCondition;
+use serde::{Deserialize, Serialize};
+
+use crate::{automaton::State, extensions::ExportFormat};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportMenuToggleAction {}
+
+impl EnablingCondition<State> for ExportMenuToggleAction {
+    fn is_enabled(&self, _: &State) -> bool {
+        true
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportRawToggleAction {}
+
+impl EnablingCondition<State> for ExportRawToggleAction {
+    fn is_enabled(&self, state: &State) -> bool {
+        state.export.menu_open
+    }
+}
+
+/// Export the focused table
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportTableAction {
+    pub format: ExportFormat,
+}
+
+impl EnablingCondition<State> for ExportTableAction {
+    fn is_enabled(&self, state: &State) -> bool {
+        state.export.menu_open && state.active_table().is_some()
+    }
+}
+
+/// Export the current screen as plain text
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportScreenAction {}
+
+impl EnablingCondition<State> for ExportScreenAction {
+    fn is_enabled(&self, state: &State) -> bool {
+        state.export.menu_open
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportSuccessAction {
+    pub path: String,
+}
+
+impl EnablingCondition<State> for ExportSuccessAction {
+    fn is_enabled(&self, _: &State) -> bool {
+        true
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportFailureAction {
+    pub error: String,
+}
+
+impl EnablingCondition<State> for ExportFailureAction {
+    fn is_enabled(&self, _: &State) -> bool {
+        true
+    }
+}
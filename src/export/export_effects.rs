@@ -0,0 +1,76 @@
+use tui::{backend::TestBackend, buffer::Buffer, Terminal};
+
+use crate::{
+    automaton::{Action, ActionWithMeta, Store},
+    services::{export_service::ExportService, tui_service::TuiService, Service},
+    terminal_ui::draw_active_page,
+};
+
+use super::{ExportFailureAction, ExportSuccessAction};
+
+pub fn export_effects<S>(store: &mut Store<S>, action: &ActionWithMeta)
+where
+    S: Service,
+{
+    let result = match &action.action {
+        Action::ExportTable(action) => {
+            let raw = store.state().export.raw;
+            match store.state().active_table_export(action.format, raw) {
+                Some((name, content)) => store
+                    .service()
+                    .export()
+                    .export(name, action.format.extension(), &content)
+                    .map_err(|e| e.to_string()),
+                None => return,
+            }
+        }
+        Action::ExportScreen(_) => {
+            // draw the screen without the export menu on top of it
+            let mut state = store.state().clone();
+            state.export.menu_open = false;
+
+            store
+                .service()
+                .tui()
+                .terminal()
+                .size()
+                .and_then(|size| Terminal::new(TestBackend::new(size.width, size.height)))
+                .and_then(|mut terminal| {
+                    terminal.draw(|f| draw_active_page(&state, f))?;
+                    Ok(buffer_to_text(terminal.backend().buffer()))
+                })
+                .and_then(|text| store.service().export().export("screen", "txt", &text))
+                .map_err(|e| e.to_string())
+        }
+        _ => return,
+    };
+
+    match result {
+        Ok(path) => {
+            store.dispatch(ExportSuccessAction {
+                path: path.display().to_string(),
+            });
+        }
+        Err(error) => {
+            store.dispatch(ExportFailureAction { error });
+        }
+    }
+}
+
+/// The rendered frame as lines of text, trailing spaces removed
+fn buffer_to_text(buffer: &Buffer) -> String {
+    let width = buffer.area.width as usize;
+    let lines: Vec<String> = buffer
+        .content
+        .chunks(width.max(1))
+        .map(|line| {
+            line.iter()
+                .map(|cell| cell.symbol.as_str())
+                .collect::<String>()
+                .trim_end()
+                .to_string()
+        })
+        .collect();
+
+    lines.join("\n") + "\n"
+}
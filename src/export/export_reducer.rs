@@ -0,0 +1,20 @@
+use crate::automaton::{Action, ActionWithMeta, State};
+
+pub fn export_reducer(state: &mut State, action: &ActionWithMeta) {
+    match &action.action {
+        Action::ExportMenuToggle(_) => {
+            state.export.menu_open = !state.export.menu_open;
+            state.export.last_result = None;
+        }
+        Action::ExportRawToggle(_) => {
+            state.export.raw = !state.export.raw;
+        }
+        Action::ExportSuccess(action) => {
+            state.export.last_result = Some(Ok(action.path.clone()));
+        }
+        Action::ExportFailure(action) => {
+            state.export.last_result = Some(Err(action.error.clone()));
+        }
+        _ => {}
+    }
+}
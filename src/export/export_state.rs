@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ExportState {
+    /// The export menu is shown over the screen
+    pub menu_open: bool,
+
+    /// Export durations in nanoseconds instead of the rendered values
+    pub raw: bool,
+
+    /// Path of the last written export, or the error it failed with
+    pub last_result: Option<Result<String, String>>,
+}
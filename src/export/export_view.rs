@@ -0,0 +1,84 @@
+use tui::{
+    backend::Backend,
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::automaton::State;
+
+/// Draw the export menu as a popup over the screen
+pub fn draw_export_menu<B: Backend>(state: &State, f: &mut Frame<B>) {
+    if !state.export.menu_open {
+        return;
+    }
+
+    let size = f.size();
+    let width = 60.min(size.width);
+    let height = 11.min(size.height);
+    let area = Rect::new(
+        (size.width - width) / 2,
+        (size.height - height) / 2,
+        width,
+        height,
+    );
+
+    let key_style = Style::default().fg(Color::White);
+    let dim_style = Style::default().fg(Color::Gray).add_modifier(Modifier::DIM);
+    // the table exports are only available when a table is focused
+    let table_style = if state.active_table().is_some() {
+        dim_style
+    } else {
+        Style::default().fg(Color::DarkGray)
+    };
+
+    let item = |key: &'static str, label: String, style: Style| {
+        Spans::from(vec![
+            Span::styled(format!(" {:<3}", key), key_style),
+            Span::styled(label, style),
+        ])
+    };
+
+    let mut lines = vec![
+        item("c", String::from("Table as CSV"), table_style),
+        item("j", String::from("Table as JSON"), table_style),
+        item("m", String::from("Table as Markdown"), table_style),
+        item(
+            "r",
+            format!(
+                "Raw nanoseconds: {}",
+                if state.export.raw { "on" } else { "off" }
+            ),
+            dim_style,
+        ),
+        item("t", String::from("Screen as text"), dim_style),
+        Spans::from(""),
+    ];
+
+    match &state.export.last_result {
+        Some(Ok(path)) => lines.push(Spans::from(Span::styled(
+            format!(" Saved to {}", path),
+            Style::default().fg(Color::Green),
+        ))),
+        Some(Err(error)) => lines.push(Spans::from(Span::styled(
+            format!(" Export failed: {}", error),
+            Style::default().fg(Color::Red),
+        ))),
+        None => {}
+    }
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("Export (e to close)")
+        .style(Style::default().bg(Color::Rgb(31, 30, 30)));
+
+    let menu = Paragraph::new(lines)
+        .block(block)
+        .alignment(Alignment::Left)
+        .wrap(Wrap { trim: false });
+
+    f.render_widget(Clear, area);
+    f.render_widget(menu, area);
+}
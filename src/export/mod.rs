@@ -0,0 +1,14 @@
+pub mod export_actions;
+pub use export_actions::*;
+
+pub mod export_effects;
+pub use export_effects::*;
+
+pub mod export_reducer;
+pub use export_reducer::*;
+
+pub mod export_state;
+pub use export_state::*;
+
+pub mod export_view;
+pub use export_view::*;
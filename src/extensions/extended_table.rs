@@ -1,3 +1,5 @@
+use std::cmp::Ordering;
+
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use tui::{
@@ -7,11 +9,19 @@ use tui::{
 };
 
 use super::{vec_constraint, TableStateDef};
+use crate::configuration::{ColumnConfig, TableConfig};
 
 const SIDE_PADDINGS: u16 = 1;
 const INITIAL_PADDING: u16 = 2;
 
+/// Maximum number of columns the table can be sorted by at once
+pub const MAX_SORT_KEYS: usize = 3;
+
+const MIN_COLUMN_WIDTH: u16 = 3;
+
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(from = "ExtendedTableRecord<S>")]
+#[serde(bound(deserialize = "S: SortableByFocus + Deserialize<'de>"))]
 pub struct ExtendedTable<S: SortableByFocus> {
     #[serde(with = "TableStateDef")]
     pub table_state: TableState,
@@ -20,6 +30,9 @@ pub struct ExtendedTable<S: SortableByFocus> {
     /// The header strings of the table in order
     headers: Vec<String>,
 
+    /// Column layout in display order, pinned columns first
+    columns: Vec<ColumnSetup>,
+
     modified_headers: Vec<String>,
 
     /// Constrainst of the colums
@@ -38,11 +51,74 @@ pub struct ExtendedTable<S: SortableByFocus> {
     /// selected table column
     selected: usize,
 
-    /// The index the table is sorted by
-    sorted_by: usize,
+    /// The columns the table is sorted by, in order of priority
+    sort_specs: Vec<SortSpec>,
+
+    /// Key of the row pinned by the follow mode
+    followed: Option<String>,
+}
+
+/// Deserialized form of the table, also reads the tables of the states recorded by the older versions
+#[derive(Deserialize)]
+#[serde(bound(deserialize = "S: Deserialize<'de>"))]
+struct ExtendedTableRecord<S> {
+    #[serde(with = "TableStateDef")]
+    table_state: TableState,
+    content: S,
+    headers: Vec<String>,
+    #[serde(default)]
+    columns: Vec<ColumnSetup>,
+    modified_headers: Vec<String>,
+    #[serde(with = "vec_constraint")]
+    constraints: Vec<Constraint>,
+    rendered: usize,
+    fixed_count: usize,
+    first_rendered_index: usize,
+    selected: usize,
+    #[serde(default)]
+    sort_specs: Option<Vec<SortSpec>>,
+    #[serde(default)]
+    followed: Option<String>,
+    /// Sort column of the older versions, before the tables were sorted by more columns
+    #[serde(default)]
+    sorted_by: Option<usize>,
+    #[serde(default)]
+    sort_order: Option<SortOrder>,
+}
 
-    /// Sort order
-    sort_order: SortOrder,
+impl<S: SortableByFocus> From<ExtendedTableRecord<S>> for ExtendedTable<S> {
+    fn from(record: ExtendedTableRecord<S>) -> Self {
+        // the older versions had no column setup, every column was shown in the order of the headers
+        let columns = if record.columns.is_empty() {
+            (0..record.headers.len())
+                .map(|column| ColumnSetup::new(column, column < record.fixed_count))
+                .collect()
+        } else {
+            record.columns
+        };
+        let sort_specs = match (record.sort_specs, record.sorted_by) {
+            (Some(sort_specs), _) => sort_specs,
+            (None, Some(column)) => vec![SortSpec::new(
+                column,
+                record.sort_order.unwrap_or_default(),
+            )],
+            (None, None) => default_sort_specs(),
+        };
+        Self {
+            table_state: record.table_state,
+            content: record.content,
+            headers: record.headers,
+            columns,
+            modified_headers: record.modified_headers,
+            constraints: record.constraints,
+            rendered: record.rendered,
+            fixed_count: record.fixed_count,
+            first_rendered_index: record.first_rendered_index,
+            selected: record.selected,
+            sort_specs,
+            followed: record.followed,
+        }
+    }
 }
 
 impl<S: SortableByFocus + PartialEq> PartialEq for ExtendedTable<S> {
@@ -50,42 +126,75 @@ impl<S: SortableByFocus + PartialEq> PartialEq for ExtendedTable<S> {
         self.table_state.selected() == other.table_state.selected()
             && self.content == other.content
             && self.headers == other.headers
+            && self.columns == other.columns
             && self.modified_headers == other.modified_headers
             && self.constraints == other.constraints
             && self.rendered == other.rendered
             && self.fixed_count == other.fixed_count
             && self.first_rendered_index == other.first_rendered_index
             && self.selected == other.selected
-            && self.sorted_by == other.sorted_by
-            && self.sort_order == other.sort_order
+            && self.sort_specs == other.sort_specs
+            && self.followed == other.followed
     }
 }
 
 impl<S: SortableByFocus + Default> ExtendedTable<S> {
     pub fn new(headers: Vec<String>, constraints: Vec<Constraint>, fixed_count: usize) -> Self {
+        let columns = (0..headers.len())
+            .map(|column| ColumnSetup::new(column, column < fixed_count))
+            .collect();
         Self {
+            columns,
             headers: headers.clone(),
             modified_headers: headers,
             constraints,
             fixed_count,
             rendered: 0,
             first_rendered_index: fixed_count,
+            sort_specs: default_sort_specs(),
             ..Default::default()
         }
     }
 
+    /// The primary sort column
     pub fn sorted_by(&self) -> usize {
-        self.sorted_by
+        self.sort_specs.first().map(|spec| spec.column).unwrap_or(0)
+    }
+
+    /// The order of the primary sort column
+    pub fn sort_order(&self) -> SortOrder {
+        self.sort_specs
+            .first()
+            .map(|spec| spec.order.clone())
+            .unwrap_or_default()
     }
 
-    pub fn sort_order(&self) -> &SortOrder {
-        &self.sort_order
+    pub fn sort_specs(&self) -> &[SortSpec] {
+        &self.sort_specs
     }
 
+    /// Display position of the selected column
     pub fn selected(&self) -> usize {
         self.selected
     }
 
+    /// Index of the selected column in the headers and the row data
+    pub fn selected_column(&self) -> usize {
+        self.visible_columns()
+            .get(self.selected)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Indexes of the visible columns in display order
+    pub fn visible_columns(&self) -> Vec<usize> {
+        self.columns
+            .iter()
+            .filter(|setup| setup.visible)
+            .map(|setup| setup.column)
+            .collect()
+    }
+
     pub fn rendered(&self) -> usize {
         self.rendered
     }
@@ -110,44 +219,133 @@ impl<S: SortableByFocus + Default> ExtendedTable<S> {
         self.fixed_count = fixed
     }
 
-    pub fn set_sort_order(&mut self, sort_order: SortOrder) {
-        self.sort_order = sort_order
+    /// Sort by the selected column
+    ///
+    /// When `append` is false the selected column becomes the only sort key, pressing it again flips its order.
+    /// When `append` is true the selected column is added as the next sort key, or its order is flipped
+    /// if the table is already sorted by it.
+    pub fn sort_by_selected(&mut self, append: bool) {
+        let column = self.selected_column();
+        let position = self
+            .sort_specs
+            .iter()
+            .position(|spec| spec.column == column);
+
+        match (append, position) {
+            (true, Some(index)) | (false, Some(index @ 0)) => {
+                let spec = &mut self.sort_specs[index];
+                spec.order = spec.order.switch();
+            }
+            (true, None) => {
+                self.sort_specs.truncate(MAX_SORT_KEYS - 1);
+                self.sort_specs
+                    .push(SortSpec::new(column, SortOrder::Ascending));
+            }
+            (false, _) => {
+                self.sort_specs = vec![SortSpec::new(column, SortOrder::Ascending)];
+            }
+        }
     }
 
-    pub fn set_sorted_by(&mut self, sorted_by: usize) {
-        self.sorted_by = sorted_by
+    /// Apply a persisted table setup, columns out of range are ignored
+    pub fn apply_config(&mut self, config: &TableConfig) {
+        let mut sort_specs: Vec<SortSpec> = Vec::new();
+        for spec in &config.sort {
+            if spec.column < self.headers.len()
+                && sort_specs.iter().all(|s| s.column != spec.column)
+            {
+                sort_specs.push(spec.clone());
+            }
+        }
+        sort_specs.truncate(MAX_SORT_KEYS);
+
+        if !sort_specs.is_empty() {
+            self.sort_specs = sort_specs;
+        }
+
+        if config.columns.is_empty() {
+            return;
+        }
+
+        // columns are matched by their header, so the config survives added or reordered columns
+        let mut columns: Vec<ColumnSetup> = Vec::new();
+        for column_config in &config.columns {
+            let column = self.headers.iter().position(|h| *h == column_config.name);
+            if let Some(column) = column {
+                if columns.iter().all(|setup| setup.column != column) {
+                    columns.push(ColumnSetup {
+                        column,
+                        visible: column_config.visible,
+                        pinned: column_config.pinned,
+                        width: column_config.width,
+                    });
+                }
+            }
+        }
+        for column in 0..self.headers.len() {
+            if columns.iter().all(|setup| setup.column != column) {
+                columns.push(ColumnSetup::new(column, false));
+            }
+        }
+
+        self.columns = columns;
+        self.layout_changed();
+    }
+
+    /// Keep the fixed region and the selection consistent with the column layout
+    fn layout_changed(&mut self) {
+        self.fixed_count = self
+            .columns
+            .iter()
+            .filter(|setup| setup.visible && setup.pinned)
+            .count();
+        self.first_rendered_index = self.fixed_count;
+
+        let visible_count = self.columns.iter().filter(|setup| setup.visible).count();
+        if self.selected >= visible_count {
+            self.selected = visible_count.saturating_sub(1);
+        }
+    }
+
+    pub fn followed(&self) -> Option<&String> {
+        self.followed.as_ref()
+    }
+
+    /// Select a row by index, moving the selection manually stops following the pinned row
+    pub fn select(&mut self, index: Option<usize>) {
+        self.table_state.select(index);
+        self.followed = None;
     }
 
     pub fn next(&mut self) {
-        let last_render_index = self.first_rendered_index + (self.rendered - self.fixed_count) - 1;
+        let visible_count = self.visible_columns().len();
+        let last_render_index = (self.first_rendered_index
+            + self.rendered.saturating_sub(self.fixed_count))
+        .saturating_sub(1);
         let next_index = self.selected + 1;
-        if next_index < self.headers.len() {
+        if next_index < visible_count {
             self.selected = next_index
         }
 
+        // the last column is scrolled to, but never past
         if self.selected >= last_render_index
             && self.first_rendered_index != last_render_index
-            && self.rendered != self.headers.len()
+            && self.first_rendered_index + 1 < visible_count
+            && self.rendered != visible_count
         {
             self.first_rendered_index += 1;
         }
     }
 
-    pub fn sort_content(&mut self, delta_toggle: bool) {
-        self.content.sort_by_focus(self.sorted_by, delta_toggle);
-        if let SortOrder::Descending = self.sort_order {
-            self.content.rev();
-        }
-    }
-
     pub fn previous(&mut self) {
-        if self.selected != 0 && self.selected != self.headers.len() {
+        let visible_count = self.visible_columns().len();
+        if self.selected != 0 && self.selected != visible_count {
             self.selected -= 1;
         }
 
-        if self.selected == self.first_rendered_index - 1
-            && self.first_rendered_index != self.fixed_count
-            && self.rendered != self.headers.len()
+        if self.first_rendered_index != self.fixed_count
+            && self.selected + 1 == self.first_rendered_index
+            && self.rendered != visible_count
         {
             self.first_rendered_index -= 1;
         }
@@ -156,11 +354,19 @@ impl<S: SortableByFocus + Default> ExtendedTable<S> {
     pub fn highlight_sorting(&mut self) {
         let mut headers = self.headers.clone();
 
-        // add ▼/▲ to the selected sorted table
-        if let Some(v) = headers.get_mut(self.sorted_by) {
-            match self.sort_order {
-                SortOrder::Ascending => *v = format!("{} ▲", v),
-                SortOrder::Descending => *v = format!("{} ▼", v),
+        // add ▼/▲ to the sorted columns, numbered by priority when sorting by more of them
+        let numbered = self.sort_specs.len() > 1;
+        for (priority, spec) in self.sort_specs.iter().enumerate() {
+            if let Some(v) = headers.get_mut(spec.column) {
+                let arrow = match spec.order {
+                    SortOrder::Ascending => "▲",
+                    SortOrder::Descending => "▼",
+                };
+                if numbered {
+                    *v = format!("{} {}{}", v, arrow, priority + 1);
+                } else {
+                    *v = format!("{} {}", v, arrow);
+                }
             }
         }
 
@@ -168,95 +374,73 @@ impl<S: SortableByFocus + Default> ExtendedTable<S> {
     }
 
     pub fn renderable_constraints(&self, max_size: u16) -> Vec<Constraint> {
-        let mut acc: u16 = INITIAL_PADDING
-            + self
-                .constraints
-                .iter()
-                .take(self.fixed_count)
-                .map(|c| {
-                    if let Constraint::Min(unit) = c {
-                        *unit
-                    } else {
-                        0
-                    }
-                })
-                .reduce(|mut acc, unit| {
-                    acc += unit;
-                    acc
-                })
-                .unwrap_or(0);
+        let visible = self.visible_columns();
 
-        let mut to_render: Vec<Constraint> = self
-            .constraints
+        let to_render: Vec<Constraint> = visible
             .iter()
             .take(self.fixed_count)
-            .cloned()
+            .map(|column| self.column_constraint(*column))
             .collect();
 
-        let dynamic_to_render: Vec<Constraint> = self
-            .constraints
+        let mut acc: u16 = INITIAL_PADDING
+            + to_render
+                .iter()
+                .filter_map(constraint_width)
+                .fold(0u16, |acc, unit| acc.saturating_add(unit));
+
+        let dynamic_to_render: Vec<Constraint> = visible
             .iter()
             .skip(self.first_rendered_index)
+            .map(|column| self.column_constraint(*column))
             .take_while_ref(|constraint| {
-                if let Constraint::Min(unit) = constraint {
-                    acc += unit + SIDE_PADDINGS;
+                if let Some(unit) = constraint_width(constraint) {
+                    acc = acc.saturating_add(unit + SIDE_PADDINGS);
                     acc <= max_size
                 } else {
                     // TODO
                     false
                 }
             })
-            .cloned()
             .collect();
 
-        to_render.extend(dynamic_to_render);
-
-        // TODO
-        // self.rendered = to_render.len();
-        to_render
+        to_render.into_iter().chain(dynamic_to_render).collect()
     }
 
-    pub fn renderable_headers(&self, selected_style: Style) -> Vec<Cell> {
-        let selected = self.selected;
-        let fixed_header_cells = self
-            .modified_headers
+    /// Pairs of (display position, column index) of the rendered columns, the fixed ones first
+    fn rendered_columns(&self) -> Vec<(usize, usize)> {
+        let visible = self.visible_columns();
+        let fixed = visible.iter().copied().enumerate().take(self.fixed_count);
+        let dynamic = visible
             .iter()
+            .copied()
             .enumerate()
-            .take(self.fixed_count)
-            .map(|(index, h)| {
-                if index == selected {
-                    Cell::from(h.as_str().to_ascii_uppercase()).style(selected_style)
-                } else {
-                    Cell::from(h.as_str().to_ascii_uppercase()).style(
-                        Style::default()
-                            .fg(Color::White)
-                            .add_modifier(Modifier::DIM),
-                    )
-                }
-            });
+            .skip(self.first_rendered_index);
 
-        let dynamic_header_cells = self
-            .modified_headers
-            .iter()
-            .enumerate()
-            .skip(self.first_rendered_index)
-            .map(|(index, h)| {
-                if index == selected {
-                    Cell::from(h.as_str().to_ascii_uppercase()).style(selected_style)
+        fixed.chain(dynamic).collect()
+    }
+
+    pub fn renderable_headers(&self, selected_style: Style) -> Vec<Cell> {
+        let selected = self.selected;
+        self.rendered_columns()
+            .into_iter()
+            .map(|(position, column)| {
+                let h = self.modified_headers[column].as_str().to_ascii_uppercase();
+                if position == selected {
+                    Cell::from(h).style(selected_style)
                 } else {
-                    Cell::from(h.as_str().to_ascii_uppercase()).style(
+                    Cell::from(h).style(
                         Style::default()
                             .fg(Color::White)
                             .add_modifier(Modifier::DIM),
                     )
                 }
-            });
-
-        fixed_header_cells.chain(dynamic_header_cells).collect()
+            })
+            .collect()
     }
 
     pub fn renderable_rows<T: TuiTableData>(&self, content: &[T], delta_toggle: bool) -> Vec<Row> {
         let selected = self.selected();
+        let rendered_columns = self.rendered_columns();
         content
             .iter()
             .map(|item| {
@@ -267,31 +451,245 @@ impl<S: SortableByFocus + Default> ExtendedTable<S> {
                     .max()
                     .unwrap_or(0)
                     + 1;
-                let fixed_cells = item.iter().enumerate().take(self.fixed_count).map(
-                    |(index, (content, style))| {
-                        if index == selected {
-                            Cell::from(content.clone()).style(style.remove_modifier(Modifier::DIM))
-                        } else {
-                            Cell::from(content.clone()).style(*style)
-                        }
-                    },
-                );
-                let dynamic_cells = item.iter().enumerate().skip(self.first_rendered_index).map(
-                    |(index, (content, style))| {
-                        if index == selected {
+                let cells = rendered_columns
+                    .iter()
+                    .filter_map(|(position, column)| item.get(*column).map(|cell| (position, cell)))
+                    .map(|(position, (content, style))| {
+                        if *position == selected {
                             Cell::from(content.clone()).style(style.remove_modifier(Modifier::DIM))
                         } else {
                             Cell::from(content.clone()).style(*style)
                         }
-                    },
-                );
-                let cells = fixed_cells.chain(dynamic_cells);
+                    });
                 Row::new(cells).height(height as u16)
             })
             .collect()
     }
 }
 
+impl<S: SortableByFocus + Default> ColumnLayout for ExtendedTable<S> {
+    fn table_config(&self) -> TableConfig {
+        TableConfig {
+            sort: self.sort_specs.clone(),
+            columns: self
+                .columns
+                .iter()
+                .map(|setup| ColumnConfig {
+                    name: self.headers[setup.column].clone(),
+                    visible: setup.visible,
+                    pinned: setup.pinned,
+                    width: setup.width,
+                })
+                .collect(),
+        }
+    }
+
+    fn columns(&self) -> &[ColumnSetup] {
+        &self.columns
+    }
+
+    fn header(&self, column: usize) -> &str {
+        self.headers
+            .get(column)
+            .map(|h| h.as_str())
+            .unwrap_or_default()
+    }
+
+    fn column_constraint(&self, column: usize) -> Constraint {
+        let width = self
+            .columns
+            .iter()
+            .find(|setup| setup.column == column)
+            .and_then(|setup| setup.width);
+
+        match (width, self.constraints.get(column)) {
+            (Some(width), Some(Constraint::Length(_))) => Constraint::Length(width),
+            (Some(width), _) => Constraint::Min(width),
+            (None, Some(constraint)) => *constraint,
+            (None, None) => Constraint::Min(0),
+        }
+    }
+
+    fn edit_column(&mut self, index: usize, edit: &ColumnEdit) -> usize {
+        if index >= self.columns.len() {
+            return index;
+        }
+
+        let new_index = match edit {
+            ColumnEdit::ToggleVisible => {
+                // keep at least one column on the screen
+                let visible_count = self.columns.iter().filter(|setup| setup.visible).count();
+                let setup = &mut self.columns[index];
+                if !setup.visible || visible_count > 1 {
+                    setup.visible = !setup.visible;
+                }
+                index
+            }
+            ColumnEdit::MoveUp => {
+                // pinned columns can't be moved out of the pinned region and vice versa
+                if index > 0 && self.columns[index - 1].pinned == self.columns[index].pinned {
+                    self.columns.swap(index - 1, index);
+                    index - 1
+                } else {
+                    index
+                }
+            }
+            ColumnEdit::MoveDown => {
+                if index + 1 < self.columns.len()
+                    && self.columns[index + 1].pinned == self.columns[index].pinned
+                {
+                    self.columns.swap(index, index + 1);
+                    index + 1
+                } else {
+                    index
+                }
+            }
+            ColumnEdit::TogglePin => {
+                let mut setup = self.columns.remove(index);
+                setup.pinned = !setup.pinned;
+                // the column goes to the end of the pinned region, or to the start of the unpinned one
+                let pinned_count = self.columns.iter().filter(|setup| setup.pinned).count();
+                self.columns.insert(pinned_count, setup);
+                pinned_count
+            }
+            ColumnEdit::Widen | ColumnEdit::Narrow => {
+                let column = self.columns[index].column;
+                let current = constraint_width(&self.column_constraint(column)).unwrap_or(0);
+                let width = if let ColumnEdit::Widen = edit {
+                    current.saturating_add(1)
+                } else {
+                    current.saturating_sub(1).max(MIN_COLUMN_WIDTH)
+                };
+                self.columns[index].width = Some(width);
+                index
+            }
+        };
+
+        self.layout_changed();
+        new_index
+    }
+}
+
+impl<T> ExtendedTable<Vec<T>>
+where
+    T: TableRowKey,
+    Vec<T>: SortableByFocus + Default,
+{
+    /// Sort the content, the selection stays on the same row
+    pub fn sort_content(&mut self, delta_toggle: bool) {
+        let selected_key = self.selected_row_key();
+        self.sort_content_unkeyed(delta_toggle);
+        self.restore_selection(selected_key);
+    }
+
+    /// Replace the content and sort it, the selection stays on the same row if it is still present
+    pub fn set_content(&mut self, content: Vec<T>, delta_toggle: bool) {
+        let selected_key = self.selected_row_key();
+        self.content = content;
+        self.sort_content_unkeyed(delta_toggle);
+        self.restore_selection(selected_key);
+    }
+
+    /// Pin the selected row, or unpin the followed one
+    pub fn toggle_follow(&mut self) {
+        if self.followed.is_some() {
+            self.followed = None;
+        } else {
+            self.followed = self.selected_row_key();
+        }
+    }
+
+    fn sort_content_unkeyed(&mut self, delta_toggle: bool) {
+        self.content.sort_by_focus(&self.sort_specs, delta_toggle);
+    }
+
+    fn selected_row_key(&self) -> Option<String> {
+        self.followed.clone().or_else(|| {
+            self.table_state
+                .selected()
+                .and_then(|index| self.content.get(index))
+                .map(|row| row.row_key())
+        })
+    }
+
+    fn restore_selection(&mut self, key: Option<String>) {
+        let position = key.and_then(|key| self.content.iter().position(|row| row.row_key() == key));
+
+        if let Some(index) = position {
+            self.table_state.select(Some(index));
+        } else if let Some(selected) = self.table_state.selected() {
+            // the row is gone, keep the selection in bounds
+            if self.content.is_empty() {
+                self.table_state.select(None);
+            } else if selected >= self.content.len() {
+                self.table_state.select(Some(self.content.len() - 1));
+            }
+        }
+    }
+}
+
+/// Display setup of a single column
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ColumnSetup {
+    /// Index of the column in the headers and the row data
+    pub column: usize,
+    pub visible: bool,
+    /// Pinned columns are always rendered on the left side of the table
+    pub pinned: bool,
+    /// Custom width overriding the default constraint
+    pub width: Option<u16>,
+}
+
+impl ColumnSetup {
+    pub fn new(column: usize, pinned: bool) -> Self {
+        Self {
+            column,
+            visible: true,
+            pinned,
+            width: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum ColumnEdit {
+    ToggleVisible,
+    MoveUp,
+    MoveDown,
+    TogglePin,
+    Widen,
+    Narrow,
+}
+
+fn constraint_width(constraint: &Constraint) -> Option<u16> {
+    match constraint {
+        Constraint::Min(unit) | Constraint::Length(unit) => Some(*unit),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SortSpec {
+    pub column: usize,
+    pub order: SortOrder,
+}
+
+impl SortSpec {
+    pub fn new(column: usize, order: SortOrder) -> Self {
+        Self { column, order }
+    }
+}
+
+impl Default for SortSpec {
+    fn default() -> Self {
+        Self::new(0, SortOrder::Ascending)
+    }
+}
+
+fn default_sort_specs() -> Vec<SortSpec> {
+    vec![SortSpec::default()]
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum SortOrder {
     Ascending,
@@ -313,11 +711,116 @@ impl SortOrder {
     }
 }
 
+/// Column layout of a table, independent of its content type
+pub trait ColumnLayout {
+    /// The persistable part of the table setup
+    fn table_config(&self) -> TableConfig;
+
+    fn columns(&self) -> &[ColumnSetup];
+
+    fn header(&self, column: usize) -> &str;
+
+    /// The constraint of the column with the custom width applied
+    fn column_constraint(&self, column: usize) -> Constraint;
+
+    /// Apply an edit to the column on `index` of the column layout, returns the new index of the edited column
+    fn edit_column(&mut self, index: usize, edit: &ColumnEdit) -> usize;
+}
+
 pub trait TuiTableData {
     fn construct_tui_table_data(&self, delta_toggle: bool) -> Vec<(String, Style)>;
+
+    /// Columns holding durations or timestamps, exported in nanoseconds when raw values are requested
+    fn duration_columns() -> &'static [usize]
+    where
+        Self: Sized,
+    {
+        &[]
+    }
+}
+
+/// Stable identity of a row (baker address, operation hash, peer address...)
+pub trait TableRowKey {
+    fn row_key(&self) -> String;
 }
 
 pub trait SortableByFocus {
-    fn sort_by_focus(&mut self, focus_index: usize, delta_toogle: bool);
-    fn rev(&mut self);
+    fn sort_by_focus(&mut self, sort_specs: &[SortSpec], delta_toogle: bool);
+}
+
+/// Value of a cell the rows are compared by
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SortKey {
+    Number(i128),
+    Text(String),
+}
+
+macro_rules! sort_key_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for SortKey {
+                fn from(value: $t) -> Self {
+                    SortKey::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+sort_key_from_number!(u64, i64, u32, i32, usize);
+
+impl From<String> for SortKey {
+    fn from(value: String) -> Self {
+        SortKey::Text(value)
+    }
+}
+
+impl From<&str> for SortKey {
+    fn from(value: &str) -> Self {
+        SortKey::Text(value.to_string())
+    }
+}
+
+/// Sort key of a single row for the column on `focus_index`, `None` for missing values
+pub trait SortKeyByFocus {
+    fn sort_key(&self, focus_index: usize, delta_toogle: bool) -> Option<SortKey>;
+}
+
+/// Stable multi column sort, missing values are always sorted last and the remaining ties are broken by the row key
+impl<T: SortKeyByFocus + TableRowKey> SortableByFocus for Vec<T> {
+    fn sort_by_focus(&mut self, sort_specs: &[SortSpec], delta_toogle: bool) {
+        let mut keyed: Vec<(Vec<Option<SortKey>>, String, T)> = self
+            .drain(..)
+            .map(|row| {
+                let keys = sort_specs
+                    .iter()
+                    .map(|spec| row.sort_key(spec.column, delta_toogle))
+                    .collect();
+                (keys, row.row_key(), row)
+            })
+            .collect();
+
+        keyed.sort_by(|(a_keys, a_row_key, _), (b_keys, b_row_key, _)| {
+            sort_specs
+                .iter()
+                .zip(a_keys.iter().zip(b_keys))
+                .map(|(spec, (a, b))| compare_sort_keys(a, b, &spec.order))
+                .find(|ordering| *ordering != Ordering::Equal)
+                .unwrap_or_else(|| a_row_key.cmp(b_row_key))
+        });
+
+        self.extend(keyed.into_iter().map(|(_, _, row)| row));
+    }
+}
+
+fn compare_sort_keys(a: &Option<SortKey>, b: &Option<SortKey>, order: &SortOrder) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => match order {
+            SortOrder::Ascending => a.cmp(b),
+            SortOrder::Descending => b.cmp(a),
+        },
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
 }
@@ -1,17 +1,20 @@
 pub mod extended_table;
 
 pub use extended_table::*;
+
+pub mod table_export;
 use num::{FromPrimitive, ToPrimitive};
 use serde::{Deserialize, Serialize};
+pub use table_export::*;
 use tui::{
     backend::Backend,
-    layout::Constraint,
+    layout::{Constraint, Layout, Rect},
     style::{Color, Modifier, Style},
     widgets::TableState,
     Frame,
 };
 
-use crate::automaton::{ActionWithMeta, State};
+use crate::automaton::State;
 
 pub mod custom_border_separator;
 pub use custom_border_separator::*;
@@ -20,6 +23,23 @@ pub trait Renderable<B: Backend> {
     fn draw_screen(state: &State, f: &mut Frame<B>);
 }
 
+/// Splitting of an area into a known number of chunks
+pub trait SplitArea {
+    /// Split the area like `Layout::split`, into as many chunks as the array has.
+    /// Chunks without a constraint are empty, the chunks past the end of the array are dropped
+    fn split_area<const N: usize>(&self, area: Rect) -> [Rect; N];
+}
+
+impl SplitArea for Layout {
+    fn split_area<const N: usize>(&self, area: Rect) -> [Rect; N] {
+        let mut chunks = [Rect::default(); N];
+        for (chunk, split) in chunks.iter_mut().zip(self.split(area)) {
+            *chunk = split;
+        }
+        chunks
+    }
+}
+
 pub fn get_time_style<T: FromPrimitive + PartialOrd>(value: T) -> Style {
     let style = Style::default();
     if value < FromPrimitive::from_u64(20000000).unwrap() {
@@ -227,20 +247,3 @@ mod vec_constraint {
         seq.end()
     }
 }
-
-#[derive(Clone, Debug, Deserialize, Serialize)]
-pub struct AutomatonDump {
-    pub init_state: State,
-    pub end_state: State,
-    pub actions: Vec<ActionWithMeta>,
-}
-
-impl AutomatonDump {
-    pub fn new(init_state: State, end_state: State, actions: &[ActionWithMeta]) -> Self {
-        Self {
-            init_state,
-            end_state,
-            actions: actions.to_vec(),
-        }
-    }
-}
@@ -0,0 +1,160 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use super::{ColumnLayout, ExtendedTable, SortKey, SortKeyByFocus, SortableByFocus, TuiTableData};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+    Markdown,
+}
+
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+            ExportFormat::Markdown => "md",
+        }
+    }
+}
+
+/// A single exported cell, raw values keep their number type
+#[derive(Debug, Clone, PartialEq)]
+enum ExportedCell {
+    Text(String),
+    Number(i128),
+    Missing,
+}
+
+impl ExportedCell {
+    fn to_text(&self) -> String {
+        match self {
+            ExportedCell::Text(text) => text.clone(),
+            ExportedCell::Number(number) => number.to_string(),
+            ExportedCell::Missing => String::new(),
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        match self {
+            ExportedCell::Text(text) => Value::from(text.as_str()),
+            // nanosecond timestamps fit into i64/u64
+            ExportedCell::Number(number) => i64::try_from(*number)
+                .map(Value::from)
+                .unwrap_or_else(|_| Value::from(number.to_string())),
+            ExportedCell::Missing => Value::Null,
+        }
+    }
+}
+
+impl<T> ExtendedTable<Vec<T>>
+where
+    T: TuiTableData + SortKeyByFocus,
+    Vec<T>: SortableByFocus + Default,
+{
+    /// Export the content in its current order with the visible columns in display order
+    ///
+    /// The cells have the same values as rendered, with `raw` the duration columns are exported in nanoseconds
+    pub fn export(&self, format: ExportFormat, raw: bool, delta_toggle: bool) -> String {
+        let columns = self.visible_columns();
+        let headers: Vec<String> = columns
+            .iter()
+            .map(|column| self.header(*column).to_string())
+            .collect();
+
+        let rows: Vec<Vec<ExportedCell>> = self
+            .content
+            .iter()
+            .map(|row| {
+                let data = row.construct_tui_table_data(delta_toggle);
+                columns
+                    .iter()
+                    .map(|column| {
+                        if raw && T::duration_columns().contains(column) {
+                            match row.sort_key(*column, delta_toggle) {
+                                Some(SortKey::Number(number)) => ExportedCell::Number(number),
+                                Some(SortKey::Text(text)) => ExportedCell::Text(text),
+                                None => ExportedCell::Missing,
+                            }
+                        } else {
+                            data.get(*column)
+                                .map(|(content, _)| ExportedCell::Text(content.clone()))
+                                .unwrap_or(ExportedCell::Missing)
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        match format {
+            ExportFormat::Csv => to_csv(&headers, &rows),
+            ExportFormat::Json => to_json(&headers, &rows),
+            ExportFormat::Markdown => to_markdown(&headers, &rows),
+        }
+    }
+}
+
+fn to_csv(headers: &[String], rows: &[Vec<ExportedCell>]) -> String {
+    let csv_field = |field: String| {
+        if field.contains(&[',', '"', '\n', '\r'][..]) {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        } else {
+            field
+        }
+    };
+
+    let mut lines = vec![headers
+        .iter()
+        .cloned()
+        .map(csv_field)
+        .collect::<Vec<_>>()
+        .join(",")];
+    lines.extend(rows.iter().map(|row| {
+        row.iter()
+            .map(|cell| csv_field(cell.to_text()))
+            .collect::<Vec<_>>()
+            .join(",")
+    }));
+
+    lines.join("\n") + "\n"
+}
+
+fn to_json(headers: &[String], rows: &[Vec<ExportedCell>]) -> String {
+    let rows: Vec<Value> = rows
+        .iter()
+        .map(|row| {
+            let object: Map<String, Value> = headers
+                .iter()
+                .cloned()
+                .zip(row.iter().map(ExportedCell::to_json))
+                .collect();
+            Value::Object(object)
+        })
+        .collect();
+
+    // serializing a Value can't fail
+    serde_json::to_string_pretty(&Value::Array(rows)).unwrap_or_default() + "\n"
+}
+
+fn to_markdown(headers: &[String], rows: &[Vec<ExportedCell>]) -> String {
+    let markdown_row = |cells: Vec<String>| {
+        let cells: Vec<String> = cells
+            .into_iter()
+            .map(|cell| cell.replace('|', "\\|").replace('\n', " "))
+            .collect();
+        format!("| {} |", cells.join(" | "))
+    };
+
+    let mut lines = vec![
+        markdown_row(headers.to_vec()),
+        markdown_row(headers.iter().map(|_| String::from("---")).collect()),
+    ];
+    lines.extend(
+        rows.iter()
+            .map(|row| markdown_row(row.iter().map(ExportedCell::to_text).collect())),
+    );
+
+    lines.join("\n") + "\n"
+}
@@ -0,0 +1,46 @@
+use redux_rs::EnablingCondition;
+use serde::{Deserialize, Serialize};
+
+use crate::automaton::State;
+
+use super::{Proposal, ProtocolActivation};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProtocolActivatedAction {
+    pub activation: ProtocolActivation,
+}
+
+impl EnablingCondition<State> for ProtocolActivatedAction {
+    fn is_enabled(&self, _: &State) -> bool {
+        true
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProtocolActivationDismissAction {}
+
+impl EnablingCondition<State> for ProtocolActivationDismissAction {
+    fn is_enabled(&self, state: &State) -> bool {
+        state.governance.activation_banner.is_some()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProposalsGetAction {}
+
+impl EnablingCondition<State> for ProposalsGetAction {
+    fn is_enabled(&self, _: &State) -> bool {
+        true
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProposalsReceivedAction {
+    pub proposals: Vec<Proposal>,
+}
+
+impl EnablingCondition<State> for ProposalsReceivedAction {
+    fn is_enabled(&self, _: &State) -> bool {
+        true
+    }
+}
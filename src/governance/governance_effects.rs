@@ -0,0 +1,62 @@
+use slog::warn;
+
+use crate::{
+    automaton::{Action, ActionWithMeta, Store},
+    rpc::RpcRequestAction,
+    services::{
+        rpc_service_async::{RpcCall, RpcTarget},
+        Service,
+    },
+    terminal_ui::NetworkConstantsGetAction,
+};
+
+use super::{ProposalsGetAction, ProtocolActivatedAction, ProtocolActivation};
+
+pub fn governance_effects<S>(store: &mut Store<S>, action: &ActionWithMeta)
+where
+    S: Service,
+{
+    match &action.action {
+        Action::CurrentHeadHeaderChanged(action) => {
+            // in this context the previous head is the head before the new one
+            let previous_protocol = store.state().previous_head_header.protocol.clone();
+            let header = &action.current_head_header;
+            if !previous_protocol.is_empty() && previous_protocol != header.protocol {
+                store.dispatch(ProtocolActivatedAction {
+                    activation: ProtocolActivation {
+                        level: header.level,
+                        previous_protocol,
+                        protocol: header.protocol.clone(),
+                        timestamp: header.timestamp,
+                    },
+                });
+            }
+        }
+        Action::ProtocolActivated(action) => {
+            warn!(
+                store.state().log,
+                "Protocol {} activated at level {}, previous protocol: {}",
+                action.activation.protocol,
+                action.activation.level,
+                action.activation.previous_protocol
+            );
+            // the constants may differ between protocols
+            store.dispatch(NetworkConstantsGetAction {});
+        }
+        Action::CurrentHeadMetadataChanged(action) => {
+            let in_proposal_period = matches!(
+                &action.new_metadata.voting_period_info,
+                Some(info) if info.voting_period.kind == "proposal"
+            );
+            if in_proposal_period {
+                store.dispatch(ProposalsGetAction {});
+            }
+        }
+        Action::ProposalsGet(_) => {
+            store.dispatch(RpcRequestAction {
+                call: RpcCall::new(RpcTarget::Proposals, None),
+            });
+        }
+        _ => {}
+    }
+}
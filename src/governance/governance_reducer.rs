@@ -0,0 +1,28 @@
+use crate::automaton::{Action, ActionWithMeta, State};
+
+pub fn governance_reducer(state: &mut State, action: &ActionWithMeta) {
+    match &action.action {
+        Action::ProtocolActivated(action) => {
+            state.governance.activations.push(action.activation.clone());
+            state.governance.activation_banner = Some(action.activation.clone());
+        }
+        Action::ProtocolActivationDismiss(_) => {
+            state.governance.activation_banner = None;
+        }
+        Action::ProposalsReceived(action) => {
+            let mut proposals = action.proposals.clone();
+            proposals.sort_by(|a, b| {
+                b.voting_power()
+                    .cmp(&a.voting_power())
+                    .then_with(|| a.protocol().cmp(b.protocol()))
+            });
+            state.governance.proposals = proposals;
+            state.governance.proposals_period = state
+                .current_head_metadata
+                .voting_period_info
+                .as_ref()
+                .map(|info| info.voting_period.index);
+        }
+        _ => {}
+    }
+}
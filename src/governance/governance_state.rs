@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+/// Proposed protocol with the voting power of the delegates upvoting it
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Proposal(
+    pub String,
+    #[serde(deserialize_with = "serde_aux::prelude::deserialize_number_from_string")] pub i64,
+);
+
+impl Proposal {
+    pub fn protocol(&self) -> &str {
+        &self.0
+    }
+
+    pub fn voting_power(&self) -> i64 {
+        self.1
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ProtocolActivation {
+    pub level: i32,
+    pub previous_protocol: String,
+    pub protocol: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub timestamp: OffsetDateTime,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GovernanceState {
+    /// Protocol activations seen since the start
+    pub activations: Vec<ProtocolActivation>,
+    /// The last activation stays on the screen until dismissed
+    pub activation_banner: Option<ProtocolActivation>,
+    /// Proposals ordered by voting power, the strongest first
+    pub proposals: Vec<Proposal>,
+    /// Index of the voting period the proposals belong to
+    pub proposals_period: Option<i32>,
+}
@@ -0,0 +1,45 @@
+use tui::{
+    backend::Backend,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Clear, Paragraph},
+    Frame,
+};
+
+use crate::automaton::State;
+
+/// Draw the last protocol activation as a bar over the top of the screen
+pub fn draw_protocol_banner<B: Backend>(state: &State, f: &mut Frame<B>) {
+    let activation = match &state.governance.activation_banner {
+        Some(activation) => activation,
+        None => return,
+    };
+
+    let size = f.size();
+    let area = Rect::new(0, 0, size.width, 1.min(size.height));
+
+    let banner_style = Style::default().fg(Color::Black).bg(Color::Yellow);
+    let banner = Paragraph::new(Spans::from(vec![
+        Span::styled(
+            " PROTOCOL ACTIVATED ",
+            banner_style.add_modifier(Modifier::BOLD),
+        ),
+        // the hint goes first so it is not cut off by the long protocol hashes
+        Span::styled(
+            "(ESC to dismiss) ",
+            banner_style.add_modifier(Modifier::DIM),
+        ),
+        Span::styled(
+            format!(
+                "at level {}: {} -> {}",
+                activation.level, activation.previous_protocol, activation.protocol
+            ),
+            banner_style,
+        ),
+    ]))
+    .style(banner_style);
+
+    f.render_widget(Clear, area);
+    f.render_widget(banner, area);
+}
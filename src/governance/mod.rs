@@ -0,0 +1,14 @@
+pub mod governance_actions;
+pub use governance_actions::*;
+
+pub mod governance_effects;
+pub use governance_effects::*;
+
+pub mod governance_reducer;
+pub use governance_reducer::*;
+
+pub mod governance_state;
+pub use governance_state::*;
+
+pub mod governance_view;
+pub use governance_view::*;
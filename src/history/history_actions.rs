@@ -0,0 +1,94 @@
+use redux_rs::EnablingCondition;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    automaton::State,
+    baking::{BlockApplicationStatistics, PerPeerBlockStatisticsVector},
+};
+
+use super::{PromptEdit, LEVEL_PROMPT_LENGTH};
+
+/// Open or close the "go to level" prompt
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryPromptToggleAction {}
+
+impl EnablingCondition<State> for HistoryPromptToggleAction {
+    fn is_enabled(&self, _: &State) -> bool {
+        true
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryPromptEditAction {
+    pub edit: PromptEdit,
+}
+
+impl EnablingCondition<State> for HistoryPromptEditAction {
+    fn is_enabled(&self, state: &State) -> bool {
+        match (&state.history.prompt, &self.edit) {
+            (Some(prompt), PromptEdit::Push(digit)) => {
+                digit.is_ascii_digit() && prompt.len() < LEVEL_PROMPT_LENGTH
+            }
+            (Some(prompt), PromptEdit::Pop) => !prompt.is_empty(),
+            (None, _) => false,
+        }
+    }
+}
+
+/// Show the level entered in the prompt
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryLevelSubmitAction {}
+
+impl EnablingCondition<State> for HistoryLevelSubmitAction {
+    fn is_enabled(&self, state: &State) -> bool {
+        state.history.prompt_level().is_some()
+    }
+}
+
+/// Fetch the statistics of a past level, unless they are cached
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryLevelGetAction {
+    pub level: i32,
+}
+
+impl EnablingCondition<State> for HistoryLevelGetAction {
+    fn is_enabled(&self, state: &State) -> bool {
+        !state
+            .history
+            .is_cached(self.level, state.current_head_header.level)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryApplicationStatisticsReceivedAction {
+    pub level: i32,
+    pub application_statistics: Vec<BlockApplicationStatistics>,
+}
+
+impl EnablingCondition<State> for HistoryApplicationStatisticsReceivedAction {
+    fn is_enabled(&self, state: &State) -> bool {
+        state.history.cache.contains_key(&self.level)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryPerPeerBlockStatisticsReceivedAction {
+    pub level: i32,
+    pub per_peer_block_statistics: PerPeerBlockStatisticsVector,
+}
+
+impl EnablingCondition<State> for HistoryPerPeerBlockStatisticsReceivedAction {
+    fn is_enabled(&self, state: &State) -> bool {
+        state.history.cache.contains_key(&self.level)
+    }
+}
+
+/// Close the statistics of the past level
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryCloseAction {}
+
+impl EnablingCondition<State> for HistoryCloseAction {
+    fn is_enabled(&self, state: &State) -> bool {
+        state.history.level.is_some()
+    }
+}
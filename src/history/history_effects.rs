@@ -0,0 +1,38 @@
+use crate::{
+    automaton::{Action, ActionWithMeta, Store},
+    rpc::RpcRequestAction,
+    services::{
+        rpc_service_async::{RpcCall, RpcTarget},
+        Service,
+    },
+};
+
+use super::HistoryLevelGetAction;
+
+pub fn history_effects<S>(store: &mut Store<S>, action: &ActionWithMeta)
+where
+    S: Service,
+{
+    match &action.action {
+        Action::HistoryLevelSubmit(_) => {
+            if let Some(level) = store.state().history.level {
+                store.dispatch(HistoryLevelGetAction { level });
+            }
+        }
+        Action::HistoryLevelGet(action) => {
+            store.dispatch(RpcRequestAction {
+                call: RpcCall::new(
+                    RpcTarget::LevelApplicationStatistics(action.level),
+                    Some(format!("?level={}", action.level)),
+                ),
+            });
+            store.dispatch(RpcRequestAction {
+                call: RpcCall::new(
+                    RpcTarget::LevelPerPeerBlockStatistics(action.level),
+                    Some(format!("?level={}", action.level)),
+                ),
+            });
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{
+    automaton::{Action, ActionWithMeta, State},
+    baking::{PerPeerBlockStatisticsExtended, PerPeerBlockStatisticsExtendedVector},
+};
+
+use super::PromptEdit;
+
+pub fn history_reducer(state: &mut State, action: &ActionWithMeta) {
+    match &action.action {
+        Action::HistoryPromptToggle(_) => {
+            state.history.prompt = match state.history.prompt {
+                Some(_) => None,
+                None => Some(String::new()),
+            };
+        }
+        Action::HistoryPromptEdit(action) => {
+            if let Some(prompt) = state.history.prompt.as_mut() {
+                match action.edit {
+                    PromptEdit::Push(digit) => prompt.push(digit),
+                    PromptEdit::Pop => {
+                        prompt.pop();
+                    }
+                }
+            }
+        }
+        Action::HistoryLevelSubmit(_) => {
+            state.history.level = state.history.prompt_level();
+            state.history.prompt = None;
+        }
+        Action::HistoryLevelGet(action) => {
+            state.history.start_fetch(action.level);
+        }
+        Action::HistoryApplicationStatisticsReceived(action) => {
+            if let Some(statistics) = state.history.cache.get_mut(&action.level) {
+                statistics.application_statistics = Some(action.application_statistics.clone());
+            }
+        }
+        Action::HistoryPerPeerBlockStatisticsReceived(action) => {
+            if let Some(statistics) = state.history.cache.get_mut(&action.level) {
+                let extended: PerPeerBlockStatisticsExtendedVector = action
+                    .per_peer_block_statistics
+                    .clone()
+                    .into_iter()
+                    .map(PerPeerBlockStatisticsExtended::from)
+                    .collect();
+                statistics.per_peer_block_statistics = Some(extended);
+            }
+        }
+        Action::HistoryClose(_) => {
+            state.history.level = None;
+        }
+        Action::RpcRequestNotSent(action) => {
+            state.history.fail_fetch(&action.call, &action.reason);
+        }
+        Action::InspectorRpcReportsReceived(action) => {
+            // a failed call is only reported, no response is received
+            for report in &action.reports {
+                if let Some(error) = &report.error {
+                    state.history.fail_fetch(&report.call, error);
+                }
+            }
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,102 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    baking::{BlockApplicationStatistics, PerPeerBlockStatisticsExtendedVector},
+    services::rpc_service_async::{RpcCall, RpcTarget},
+};
+
+/// Number of past levels kept in the cache
+pub const LEVEL_HISTORY_CAPACITY: usize = 50;
+
+/// Digits accepted by the level prompt, enough for any level
+pub const LEVEL_PROMPT_LENGTH: usize = 9;
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LevelStatistics {
+    /// `None` until the node responds
+    pub application_statistics: Option<Vec<BlockApplicationStatistics>>,
+    /// `None` until the node responds
+    pub per_peer_block_statistics: Option<PerPeerBlockStatisticsExtendedVector>,
+    /// Error of a failed request, its statistics will not arrive
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+impl LevelStatistics {
+    pub fn is_complete(&self) -> bool {
+        self.application_statistics.is_some() && self.per_peer_block_statistics.is_some()
+    }
+
+    /// Application statistics of the block the per peer statistics are for, the first block otherwise
+    pub fn block_statistics(&self) -> Option<&BlockApplicationStatistics> {
+        let statistics = self.application_statistics.as_ref()?;
+        let peers_block = self
+            .per_peer_block_statistics
+            .as_ref()
+            .and_then(|per_peer| per_peer.first())
+            .map(|peer_stats| &peer_stats.block_hash);
+        statistics
+            .iter()
+            .find(|stats| Some(&stats.block_hash) == peers_block)
+            .or_else(|| statistics.first())
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HistoryState {
+    /// Level typed in the "go to level" prompt, `None` when the prompt is closed
+    pub prompt: Option<String>,
+    /// Past level shown over the screen
+    pub level: Option<i32>,
+    /// Statistics fetched for the past levels
+    pub cache: BTreeMap<i32, LevelStatistics>,
+}
+
+impl HistoryState {
+    /// Level entered in the prompt
+    pub fn prompt_level(&self) -> Option<i32> {
+        self.prompt.as_ref()?.parse().ok()
+    }
+
+    /// The cached statistics are final once complete for a level below the head
+    pub fn is_cached(&self, level: i32, head_level: i32) -> bool {
+        level < head_level
+            && self
+                .cache
+                .get(&level)
+                .map(LevelStatistics::is_complete)
+                .unwrap_or(false)
+    }
+
+    /// Start a new cache entry for the level, dropping the lowest other level when full
+    pub fn start_fetch(&mut self, level: i32) {
+        self.cache.insert(level, LevelStatistics::default());
+        while self.cache.len() > LEVEL_HISTORY_CAPACITY {
+            let evicted = self.cache.keys().copied().find(|cached| *cached != level);
+            match evicted {
+                Some(evicted) => self.cache.remove(&evicted),
+                None => break,
+            };
+        }
+    }
+
+    /// Record the error of a failed request for the statistics of a past level
+    pub fn fail_fetch(&mut self, call: &RpcCall, error: &str) {
+        let level = match call.target {
+            RpcTarget::LevelApplicationStatistics(level)
+            | RpcTarget::LevelPerPeerBlockStatistics(level) => level,
+            _ => return,
+        };
+        if let Some(statistics) = self.cache.get_mut(&level) {
+            statistics.error = Some(error.to_string());
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum PromptEdit {
+    Push(char),
+    Pop,
+}
@@ -0,0 +1,116 @@
+use tui::{
+    backend::Backend,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::{automaton::State, baking::draw_application_details};
+
+use super::LevelStatistics;
+
+/// Draw the statistics of the past level as a popup over the screen, laid out like the baking screen
+pub fn draw_level_history<B: Backend>(state: &State, f: &mut Frame<B>) {
+    let level = match state.history.level {
+        Some(level) => level,
+        None => return,
+    };
+
+    let size = f.size();
+    let width = (size.width * 9 / 10).max(40.min(size.width));
+    let height = (size.height * 7 / 10).max(16.min(size.height));
+    let area = Rect::new(
+        (size.width - width) / 2,
+        (size.height - height) / 2,
+        width,
+        height,
+    );
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(area);
+
+    f.render_widget(Clear, area);
+    f.render_widget(
+        Block::default().style(Style::default().bg(Color::Rgb(31, 30, 30))),
+        area,
+    );
+
+    let statistics = state.history.cache.get(&level);
+    let per_peer_stats = statistics
+        .and_then(|statistics| statistics.per_peer_block_statistics.clone())
+        .unwrap_or_default();
+    draw_application_details(
+        state,
+        level,
+        statistics.and_then(|statistics| statistics.block_statistics()),
+        &per_peer_stats,
+        chunks[0],
+        f,
+    );
+
+    let dim_style = Style::default().fg(Color::Gray).add_modifier(Modifier::DIM);
+    let error_style = Style::default().fg(Color::Red);
+    let status = match statistics {
+        Some(LevelStatistics {
+            error: Some(error), ..
+        }) => Span::styled(
+            format!("fetching the statistics failed: {}  ", error),
+            error_style,
+        ),
+        Some(statistics) if !statistics.is_complete() => {
+            Span::styled("fetching the statistics...  ", dim_style)
+        }
+        Some(statistics) if statistics.block_statistics().is_none() => {
+            Span::styled("no statistics for the level  ", dim_style)
+        }
+        _ => Span::raw(""),
+    };
+    let help = Paragraph::new(Spans::from(vec![
+        status,
+        Span::styled("g go to level  ESC close", dim_style),
+    ]))
+    .alignment(Alignment::Center);
+    f.render_widget(help, chunks[1]);
+}
+
+/// Draw the "go to level" prompt as a popup over the screen
+pub fn draw_level_prompt<B: Backend>(state: &State, f: &mut Frame<B>) {
+    let prompt = match &state.history.prompt {
+        Some(prompt) => prompt,
+        None => return,
+    };
+
+    let size = f.size();
+    let width = 44.min(size.width);
+    let height = 4.min(size.height);
+    let area = Rect::new(
+        (size.width - width) / 2,
+        (size.height - height) / 2,
+        width,
+        height,
+    );
+
+    let dim_style = Style::default().fg(Color::Gray).add_modifier(Modifier::DIM);
+    let value_style = Style::default().fg(Color::White);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("Go to level")
+        .style(Style::default().bg(Color::Rgb(31, 30, 30)));
+    let input = Paragraph::new(vec![
+        Spans::from(vec![
+            Span::styled("Level: ", dim_style),
+            Span::styled(prompt.clone(), value_style),
+            Span::styled("_", value_style.add_modifier(Modifier::SLOW_BLINK)),
+        ]),
+        Spans::from(vec![Span::styled("ENTER fetch  ESC cancel", dim_style)]),
+    ])
+    .block(block);
+
+    f.render_widget(Clear, area);
+    f.render_widget(input, area);
+}
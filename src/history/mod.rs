@@ -0,0 +1,14 @@
+pub mod history_actions;
+pub use history_actions::*;
+
+pub mod history_effects;
+pub use history_effects::*;
+
+pub mod history_reducer;
+pub use history_reducer::*;
+
+pub mod history_state;
+pub use history_state::*;
+
+pub mod history_view;
+pub use history_view::*;
@@ -0,0 +1,25 @@
+use redux_rs::EnablingCondition;
+use serde::{Deserialize, Serialize};
+
+use crate::{automaton::State, services::rpc_service_async::RpcCallReport};
+
+/// Take the reports of the rpc calls finished since the last tick
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InspectorRpcReportsGetAction {}
+
+impl EnablingCondition<State> for InspectorRpcReportsGetAction {
+    fn is_enabled(&self, _: &State) -> bool {
+        true
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InspectorRpcReportsReceivedAction {
+    pub reports: Vec<RpcCallReport>,
+}
+
+impl EnablingCondition<State> for InspectorRpcReportsReceivedAction {
+    fn is_enabled(&self, _: &State) -> bool {
+        !self.reports.is_empty()
+    }
+}
@@ -0,0 +1,16 @@
+use crate::{
+    automaton::{Action, ActionWithMeta, Store},
+    services::{rpc_service_async::RpcService, Service},
+};
+
+use super::InspectorRpcReportsReceivedAction;
+
+pub fn inspector_effects<S>(store: &mut Store<S>, action: &ActionWithMeta)
+where
+    S: Service,
+{
+    if let Action::InspectorRpcReportsGet(_) = &action.action {
+        let reports = store.service().rpc().take_reports();
+        store.dispatch(InspectorRpcReportsReceivedAction { reports });
+    }
+}
@@ -0,0 +1,30 @@
+use crate::automaton::{Action, ActionWithMeta, State};
+
+pub fn inspector_reducer(state: &mut State, action: &ActionWithMeta) {
+    let inspector = &mut state.inspector;
+    inspector.count_action(action.action.as_ref(), action.depth, action.time_as_nanos());
+
+    match &action.action {
+        Action::RpcRequest(request) => {
+            inspector.push_call(
+                action.id.into(),
+                request.call.clone(),
+                action.time_as_nanos(),
+            );
+        }
+        Action::RpcRequestNotSent(action) => {
+            inspector.not_sent(action.id, &action.reason);
+        }
+        Action::InspectorRpcReportsReceived(action) => {
+            for report in &action.reports {
+                inspector.finish(report);
+            }
+        }
+        Action::WebsocketMessageReceived(action) => {
+            for message in &action.websocket_message {
+                inspector.count_websocket_message(message.into());
+            }
+        }
+        _ => {}
+    }
+}
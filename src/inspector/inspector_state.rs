@@ -0,0 +1,180 @@
+use std::collections::{BTreeMap, VecDeque};
+
+use serde::{Deserialize, Serialize};
+
+use crate::services::rpc_service_async::{RpcCall, RpcCallReport};
+
+/// Number of rpc calls kept for the inspector screen
+pub const INSPECTED_CALLS_CAPACITY: usize = 200;
+
+const MINUTE_NANOS: u64 = 60_000_000_000;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum RpcCallStatus {
+    /// Sent, the response did not arrive yet
+    Pending,
+    /// The request queue of the rpc service did not accept the call
+    NotSent(String),
+    Finished(RpcCallReport),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InspectedRpcCall {
+    /// Id of the request action, the report of the call carries it
+    #[serde(default)]
+    pub id: u64,
+    pub call: RpcCall,
+    /// Time of the request action in nanoseconds
+    pub sent: u64,
+    pub status: RpcCallStatus,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ActionStatistics {
+    pub count: u64,
+    pub min_depth: u32,
+    pub max_depth: u32,
+    /// Time of the last action in nanoseconds
+    pub last: u64,
+    /// Start of the minute the actions are counted in
+    pub minute_start: u64,
+    pub minute_count: u64,
+    /// Actions in the previous minute, `None` until a minute passes
+    pub previous_minute_count: Option<u64>,
+}
+
+impl ActionStatistics {
+    fn new(depth: u32, time: u64) -> Self {
+        Self {
+            count: 1,
+            min_depth: depth,
+            max_depth: depth,
+            last: time,
+            minute_start: time,
+            minute_count: 1,
+            previous_minute_count: None,
+        }
+    }
+
+    fn count(&mut self, depth: u32, time: u64) {
+        let elapsed = time.saturating_sub(self.minute_start);
+        if elapsed >= MINUTE_NANOS {
+            // nothing was dispatched in the minutes in between
+            self.previous_minute_count = Some(if elapsed < 2 * MINUTE_NANOS {
+                self.minute_count
+            } else {
+                0
+            });
+            self.minute_start = time;
+            self.minute_count = 0;
+        }
+        self.count += 1;
+        self.minute_count += 1;
+        self.min_depth = self.min_depth.min(depth);
+        self.max_depth = self.max_depth.max(depth);
+        self.last = time;
+    }
+
+    /// Actions dispatched in the last finished minute before `now`, `None` until a minute passes
+    pub fn per_minute(&self, now: u64) -> Option<u64> {
+        let elapsed = now.saturating_sub(self.minute_start);
+        if elapsed >= 2 * MINUTE_NANOS {
+            Some(0)
+        } else if elapsed >= MINUTE_NANOS {
+            Some(self.minute_count)
+        } else {
+            self.previous_minute_count
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InspectorState {
+    /// The last rpc calls, the oldest first
+    pub calls: VecDeque<InspectedRpcCall>,
+    /// Index of the selected call, the newest calls are followed when `None`
+    pub selected: Option<usize>,
+    /// Dispatched actions by the name of their [crate::automaton::ActionKind]
+    pub actions: BTreeMap<String, ActionStatistics>,
+    /// Received websocket messages by the message type
+    pub websocket_messages: BTreeMap<String, u64>,
+    /// Time of the last dispatched action in nanoseconds
+    pub last_action: u64,
+}
+
+impl InspectorState {
+    /// Add the sent call, keeping the selection on the same call while it is kept
+    pub fn push_call(&mut self, id: u64, call: RpcCall, sent: u64) {
+        self.calls.push_back(InspectedRpcCall {
+            id,
+            call,
+            sent,
+            status: RpcCallStatus::Pending,
+        });
+        if self.calls.len() > INSPECTED_CALLS_CAPACITY {
+            self.calls.pop_front();
+            self.selected = self.selected.map(|selected| selected.saturating_sub(1));
+        }
+    }
+
+    /// Mark the pending call as not sent
+    pub fn not_sent(&mut self, id: u64, reason: &str) {
+        if let Some(inspected) = self.pending_call(id) {
+            inspected.status = RpcCallStatus::NotSent(reason.to_string());
+        }
+    }
+
+    /// Attach the report to its call, the reports of the dropped calls are ignored
+    pub fn finish(&mut self, report: &RpcCallReport) {
+        if let Some(inspected) = self.pending_call(report.id) {
+            inspected.status = RpcCallStatus::Finished(report.clone());
+        }
+    }
+
+    fn pending_call(&mut self, id: u64) -> Option<&mut InspectedRpcCall> {
+        self.calls
+            .iter_mut()
+            .find(|inspected| inspected.id == id && inspected.status == RpcCallStatus::Pending)
+    }
+
+    pub fn count_action(&mut self, kind: &str, depth: u32, time: u64) {
+        match self.actions.get_mut(kind) {
+            Some(statistics) => statistics.count(depth, time),
+            None => {
+                self.actions
+                    .insert(kind.to_string(), ActionStatistics::new(depth, time));
+            }
+        }
+        self.last_action = time;
+    }
+
+    pub fn count_websocket_message(&mut self, message_type: &str) {
+        *self
+            .websocket_messages
+            .entry(message_type.to_string())
+            .or_default() += 1;
+    }
+
+    pub fn selected_call(&self) -> Option<&InspectedRpcCall> {
+        self.selected.and_then(|selected| self.calls.get(selected))
+    }
+
+    /// Move the selection to the older call, the newest one is selected first
+    pub fn select_previous(&mut self) {
+        let count = self.calls.len();
+        self.selected = match self.selected {
+            _ if count == 0 => None,
+            None => Some(count - 1),
+            Some(selected) => Some(selected.saturating_sub(1).min(count - 1)),
+        };
+    }
+
+    /// Move the selection to the newer call, past the newest one the calls are followed again
+    pub fn select_next(&mut self) {
+        let count = self.calls.len();
+        self.selected = match self.selected {
+            Some(selected) if selected + 1 < count => Some(selected + 1),
+            _ => None,
+        };
+    }
+}
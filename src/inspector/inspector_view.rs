@@ -0,0 +1,318 @@
+use std::cmp::Reverse;
+
+use time::{format_description, OffsetDateTime};
+use tui::backend::Backend;
+use tui::layout::{Constraint, Corner, Direction, Layout};
+use tui::style::{Color, Modifier, Style};
+use tui::text::{Span, Spans};
+use tui::widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState, Wrap};
+use tui::Frame;
+
+use crate::automaton::State;
+use crate::common::{create_header_bar, create_pages_tabs, create_quit, HEADER_HEIGHT};
+use crate::extensions::{CustomSeparator, Renderable, SplitArea, StyledTime};
+use crate::retention::format_bytes;
+
+use super::{InspectedRpcCall, RpcCallStatus};
+
+pub struct InspectorScreen {}
+
+impl<B: Backend> Renderable<B> for InspectorScreen {
+    fn draw_screen(state: &State, f: &mut Frame<B>) {
+        let size = f.size();
+
+        let background = Block::default().style(Style::default().bg(Color::Rgb(31, 30, 30)));
+        f.render_widget(background, size);
+
+        let [header_chunk, body_chunk, tabs_chunk] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(HEADER_HEIGHT),
+                Constraint::Min(5),
+                Constraint::Length(1),
+            ])
+            .split_area(size);
+
+        let [calls_chunk, details_chunk] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split_area(body_chunk);
+
+        let [summary_chunk, calls_chunk] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(2), Constraint::Min(3)])
+            .split_area(calls_chunk);
+
+        let [preview_chunk, actions_chunk] = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+            .split_area(details_chunk);
+
+        let [preview_chunk, messages_chunk] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+            .split_area(preview_chunk);
+
+        let dimmed_text_style = Style::default()
+            .fg(Color::White)
+            .add_modifier(Modifier::DIM);
+        let normal_style = Style::default().fg(Color::White);
+        let failed_style = Style::default().fg(Color::Red);
+
+        let inspector = &state.inspector;
+
+        // ======================== SUMMARY ========================
+        let pending = inspector
+            .calls
+            .iter()
+            .filter(|inspected| inspected.status == RpcCallStatus::Pending)
+            .count();
+        let failed = inspector
+            .calls
+            .iter()
+            .filter(|inspected| is_failed(inspected))
+            .count();
+        let summary = Paragraph::new(Spans::from(vec![
+            Span::styled(" RPC CALLS - ", normal_style),
+            Span::styled(inspector.calls.len().to_string(), normal_style),
+            Span::styled(" kept - ", dimmed_text_style),
+            Span::styled(pending.to_string(), normal_style),
+            Span::styled(" pending - ", dimmed_text_style),
+            Span::styled(failed.to_string(), normal_style),
+            Span::styled(" failed", dimmed_text_style),
+        ]))
+        .block(Block::default().borders(Borders::TOP | Borders::LEFT | Borders::RIGHT));
+        f.render_widget(summary, summary_chunk);
+
+        // ======================== CALLS ========================
+        // borders, the header and its margin
+        let visible_rows = calls_chunk.height.saturating_sub(4) as usize;
+        // without a selection the newest calls are followed
+        let skipped = match inspector.selected {
+            Some(_) => 0,
+            None => inspector.calls.len().saturating_sub(visible_rows),
+        };
+
+        let format_desc = format_description::parse("[hour]:[minute]:[second]").unwrap_or_default();
+        let rows: Vec<Row> = inspector
+            .calls
+            .iter()
+            .skip(skipped)
+            .map(|inspected| {
+                let sent = OffsetDateTime::from_unix_timestamp_nanos(inspected.sent.into())
+                    .ok()
+                    .and_then(|sent| sent.format(&format_desc).ok())
+                    .unwrap_or_default();
+                let (status, latency, size) = match &inspected.status {
+                    RpcCallStatus::Pending => (
+                        Cell::from("pending").style(dimmed_text_style),
+                        Cell::from(" - ").style(dimmed_text_style),
+                        Cell::from(" - ").style(dimmed_text_style),
+                    ),
+                    RpcCallStatus::NotSent(_) => (
+                        Cell::from("not sent").style(failed_style),
+                        Cell::from(" - ").style(dimmed_text_style),
+                        Cell::from(" - ").style(dimmed_text_style),
+                    ),
+                    RpcCallStatus::Finished(report) => {
+                        let status = report
+                            .status
+                            .map_or_else(|| String::from("failed"), |status| status.to_string());
+                        let status_style = if is_failed(inspected) {
+                            failed_style
+                        } else {
+                            normal_style
+                        };
+                        let latency = StyledTime::new(Some(report.latency));
+                        (
+                            Cell::from(status).style(status_style),
+                            Cell::from(latency.get_string_representation())
+                                .style(latency.get_style()),
+                            Cell::from(format_bytes(report.size)).style(dimmed_text_style),
+                        )
+                    }
+                };
+                Row::new(vec![
+                    Cell::from(sent).style(dimmed_text_style),
+                    Cell::from(format!("{:?}", inspected.call.target)).style(normal_style),
+                    status,
+                    latency,
+                    size,
+                    Cell::from(inspected.call.query_arg().unwrap_or("-").to_string())
+                        .style(dimmed_text_style),
+                ])
+                .height(1)
+            })
+            .collect();
+
+        let header = Row::new(vec!["Sent", "Target", "Status", "Latency", "Size", "Query"])
+            .style(normal_style)
+            .height(1)
+            .bottom_margin(1);
+
+        let selected_style = Style::default()
+            .remove_modifier(Modifier::DIM)
+            .bg(Color::Black);
+
+        let widths = [
+            Constraint::Length(8),
+            Constraint::Length(34),
+            Constraint::Length(8),
+            Constraint::Length(9),
+            Constraint::Length(10),
+            // borders, the highlight symbol, the fixed columns and the column spacing
+            Constraint::Length(calls_chunk.width.saturating_sub(77)),
+        ];
+        let table = Table::new(rows)
+            .header(header)
+            .block(Block::default().borders(Borders::ALL))
+            .highlight_style(selected_style)
+            .highlight_symbol("▶")
+            .widths(&widths);
+
+        let mut table_state = TableState::default();
+        table_state.select(inspector.selected);
+        f.render_stateful_widget(table, calls_chunk, &mut table_state);
+
+        // overlap the block corners with special separators to make flush transition to the table block
+        let vertical_left_separator = CustomSeparator::default()
+            .separator("├")
+            .corner(Corner::TopLeft);
+        f.render_widget(vertical_left_separator, calls_chunk);
+
+        let vertical_right_separator = CustomSeparator::default()
+            .separator("┤")
+            .corner(Corner::TopRight);
+        f.render_widget(vertical_right_separator, calls_chunk);
+
+        // ======================== RESPONSE PREVIEW ========================
+        let preview = match inspector.selected_call() {
+            None => vec![Spans::from(Span::styled(
+                "Select a call to see its response",
+                dimmed_text_style,
+            ))],
+            Some(inspected) => {
+                let mut lines = vec![Spans::from(Span::styled(
+                    match inspected.call.query_arg() {
+                        Some(query) => format!("{}{}", inspected.call.to_url(), query),
+                        None => inspected.call.to_url().to_string(),
+                    },
+                    normal_style,
+                ))];
+                match &inspected.status {
+                    RpcCallStatus::Pending => lines.push(Spans::from(Span::styled(
+                        "Waiting for the response",
+                        dimmed_text_style,
+                    ))),
+                    RpcCallStatus::NotSent(reason) => lines.push(Spans::from(Span::styled(
+                        format!("Not sent: {}", reason),
+                        failed_style,
+                    ))),
+                    RpcCallStatus::Finished(report) => {
+                        if let Some(error) = &report.error {
+                            lines.push(Spans::from(Span::styled(error.clone(), failed_style)));
+                        }
+                        lines.push(Spans::from(Span::styled(
+                            report.preview.clone(),
+                            dimmed_text_style,
+                        )));
+                    }
+                }
+                lines
+            }
+        };
+        let preview = Paragraph::new(preview)
+            .block(Block::default().borders(Borders::ALL).title("Response"))
+            .wrap(Wrap { trim: false });
+        f.render_widget(preview, preview_chunk);
+
+        // ======================== ACTIONS ========================
+        let mut actions: Vec<_> = inspector.actions.iter().collect();
+        // the most frequent first
+        actions.sort_by_key(|(_, statistics)| Reverse(statistics.count));
+        let rows: Vec<Row> = actions
+            .into_iter()
+            .map(|(kind, statistics)| {
+                let depth = if statistics.min_depth == statistics.max_depth {
+                    statistics.min_depth.to_string()
+                } else {
+                    format!("{}-{}", statistics.min_depth, statistics.max_depth)
+                };
+                let per_minute = statistics
+                    .per_minute(inspector.last_action)
+                    .map_or_else(|| String::from(" - "), |count| count.to_string());
+                Row::new(vec![
+                    Cell::from(kind.clone()).style(normal_style),
+                    Cell::from(depth).style(dimmed_text_style),
+                    Cell::from(statistics.count.to_string()).style(normal_style),
+                    Cell::from(per_minute).style(dimmed_text_style),
+                ])
+            })
+            .collect();
+
+        let header = Row::new(vec!["Action", "Depth", "Count", "Per min"])
+            .style(normal_style)
+            .height(1)
+            .bottom_margin(1);
+        let widths = [
+            // borders, the fixed columns and the column spacing
+            Constraint::Length(actions_chunk.width.saturating_sub(28)),
+            Constraint::Length(5),
+            Constraint::Length(8),
+            Constraint::Length(8),
+        ];
+        let table = Table::new(rows)
+            .header(header)
+            .block(Block::default().borders(Borders::ALL).title("Actions"))
+            .widths(&widths);
+        f.render_widget(table, actions_chunk);
+
+        // ======================== WEBSOCKET MESSAGES ========================
+        let rows: Vec<Row> = inspector
+            .websocket_messages
+            .iter()
+            .map(|(message_type, count)| {
+                Row::new(vec![
+                    Cell::from(message_type.clone()).style(normal_style),
+                    Cell::from(count.to_string()).style(normal_style),
+                ])
+            })
+            .collect();
+
+        let header = Row::new(vec!["Message", "Count"])
+            .style(normal_style)
+            .height(1)
+            .bottom_margin(1);
+        let widths = [
+            // borders, the fixed column and the column spacing
+            Constraint::Length(messages_chunk.width.saturating_sub(11)),
+            Constraint::Length(8),
+        ];
+        let table = Table::new(rows)
+            .header(header)
+            .block(Block::default().borders(Borders::ALL).title("Websocket"))
+            .widths(&widths);
+        f.render_widget(table, messages_chunk);
+
+        // ======================== HEADER ========================
+        create_header_bar(header_chunk, state, f);
+
+        // ======================== PAGES TABS ========================
+        let tabs = create_pages_tabs(&state.ui);
+        f.render_widget(tabs, tabs_chunk);
+
+        // ======================== Quit ========================
+        create_quit(tabs_chunk, f);
+    }
+}
+
+fn is_failed(inspected: &InspectedRpcCall) -> bool {
+    match &inspected.status {
+        RpcCallStatus::Pending => false,
+        RpcCallStatus::NotSent(_) => true,
+        RpcCallStatus::Finished(report) => match report.status {
+            Some(status) => report.error.is_some() || status >= 400,
+            None => true,
+        },
+    }
+}
@@ -0,0 +1,14 @@
+pub mod inspector_actions;
+pub use inspector_actions::*;
+
+pub mod inspector_effects;
+pub use inspector_effects::*;
+
+pub mod inspector_reducer;
+pub use inspector_reducer::*;
+
+pub mod inspector_state;
+pub use inspector_state::*;
+
+pub mod inspector_view;
+pub use inspector_view::*;
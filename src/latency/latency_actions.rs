@@ -0,0 +1,14 @@
+use redux_rs::EnablingCondition;
+use serde::{Deserialize, Serialize};
+
+use crate::automaton::State;
+
+/// Switch the latency panels between the last block and the rolling window
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LatencyWindowToggleAction {}
+
+impl EnablingCondition<State> for LatencyWindowToggleAction {
+    fn is_enabled(&self, _: &State) -> bool {
+        true
+    }
+}
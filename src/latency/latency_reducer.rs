@@ -0,0 +1,20 @@
+use crate::automaton::{Action, ActionWithMeta, State};
+
+use super::LevelLatencies;
+
+pub fn latency_reducer(state: &mut State, action: &ActionWithMeta) {
+    match &action.action {
+        Action::CurrentHeadHeaderChanged(_) => {
+            // the tui reducer already moved the finished block to the previous head
+            let finished = &state.previous_head_header;
+            if !finished.hash.is_empty() {
+                let latencies = LevelLatencies::collect(state, finished);
+                state.latency.push(latencies);
+            }
+        }
+        Action::LatencyWindowToggle(_) => {
+            state.latency.rolling = !state.latency.rolling;
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,128 @@
+use std::collections::VecDeque;
+
+use hdrhistogram::Histogram;
+use serde::{Deserialize, Serialize};
+
+use crate::{automaton::State, services::rpc_service_async::CurrentHeadHeader};
+
+/// Number of the last finished levels in the rolling window
+pub const LATENCY_WINDOW_LEVELS: usize = 60;
+
+/// Latencies measured for a single block, in nanoseconds
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LevelLatencies {
+    pub level: i32,
+    /// Time the header was sent to each peer
+    pub propagation: Vec<u64>,
+    /// Total application time of the block
+    pub application: Vec<u64>,
+    /// Delta between receiving and broadcasting each endorsement
+    pub endorsement: Vec<u64>,
+}
+
+impl LevelLatencies {
+    /// Collect the latencies of the block from the statistics in the state
+    pub fn collect(state: &State, header: &CurrentHeadHeader) -> Self {
+        let propagation = state
+            .baking
+            .per_peer_block_statistics
+            .get(&header.hash)
+            .map(|per_peer| {
+                per_peer
+                    .iter()
+                    .filter_map(|peer_stats| peer_stats.sent_end_time)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let application = state
+            .baking
+            .application_statistics
+            .get(&header.hash)
+            .and_then(|statistics| statistics.total_application_time())
+            .into_iter()
+            .collect();
+
+        let endorsement = state
+            .endorsmenents
+            .endorsement_table
+            .content
+            .iter()
+            .filter_map(|status| status.delta)
+            .collect();
+
+        Self {
+            level: header.level,
+            propagation,
+            application,
+            endorsement,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LatencyState {
+    /// Latencies of the last finished levels, the newest last
+    pub window: VecDeque<LevelLatencies>,
+    /// Show the rolling window instead of the last block
+    pub rolling: bool,
+}
+
+impl LatencyState {
+    pub fn push(&mut self, latencies: LevelLatencies) {
+        self.window.push_back(latencies);
+        while self.window.len() > LATENCY_WINDOW_LEVELS {
+            self.window.pop_front();
+        }
+    }
+}
+
+/// Percentiles and histogram buckets of a set of latencies
+#[derive(Debug, Clone, PartialEq)]
+pub struct LatencyDistribution {
+    pub count: u64,
+    pub p50: u64,
+    pub p90: u64,
+    pub p99: u64,
+    pub max: u64,
+    /// Upper bound of each bucket with the number of latencies in it
+    pub buckets: Vec<(u64, u64)>,
+}
+
+impl LatencyDistribution {
+    /// Number of the histogram buckets, the buckets split the range up to the maximum evenly
+    pub const BUCKETS: u64 = 6;
+
+    /// `None` without any latencies
+    pub fn new<I: IntoIterator<Item = u64>>(latencies: I) -> Option<Self> {
+        let mut histogram = Histogram::<u64>::new(3).ok()?;
+        for latency in latencies {
+            // the histogram resizes to fit the value, so the recording does not fail
+            let _ = histogram.record(latency);
+        }
+        if histogram.is_empty() {
+            return None;
+        }
+
+        let max = histogram.max();
+        let step = (max / Self::BUCKETS + 1).max(1);
+        let buckets = histogram
+            .iter_linear(step)
+            .map(|bucket| {
+                (
+                    bucket.value_iterated_to(),
+                    bucket.count_since_last_iteration(),
+                )
+            })
+            .collect();
+
+        Some(Self {
+            count: histogram.len(),
+            p50: histogram.value_at_quantile(0.5),
+            p90: histogram.value_at_quantile(0.9),
+            p99: histogram.value_at_quantile(0.99),
+            max,
+            buckets,
+        })
+    }
+}
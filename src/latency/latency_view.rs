@@ -0,0 +1,202 @@
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{BarChart, Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::{automaton::State, extensions::SplitArea};
+
+use super::{LatencyDistribution, LevelLatencies, LATENCY_WINDOW_LEVELS};
+
+/// Draw the latency histograms with their percentiles, for the last block or the rolling window
+pub fn draw_latency_panels<B: Backend>(state: &State, area: Rect, f: &mut Frame<B>) {
+    let [title_chunk, panels_chunk] = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(3)])
+        .split_area(area);
+
+    let dim_style = Style::default()
+        .fg(Color::White)
+        .add_modifier(Modifier::DIM);
+    let normal_style = Style::default().fg(Color::White);
+
+    // the last block is the current head, or the last finished block until the statistics of the head arrive
+    let live = LevelLatencies::collect(state, &state.current_head_header);
+    let last_block = if live.propagation.is_empty()
+        && live.application.is_empty()
+        && live.endorsement.is_empty()
+    {
+        state.latency.window.back().cloned().unwrap_or(live)
+    } else {
+        live
+    };
+
+    let (scope, latencies): (String, Vec<&LevelLatencies>) = if state.latency.rolling {
+        (
+            format!(
+                "last {} of {} levels",
+                state.latency.window.len(),
+                LATENCY_WINDOW_LEVELS
+            ),
+            state.latency.window.iter().collect(),
+        )
+    } else {
+        (format!("level {}", last_block.level), vec![&last_block])
+    };
+
+    let title = Paragraph::new(Spans::from(vec![
+        Span::styled(" LATENCY - ", normal_style),
+        Span::styled(scope, normal_style),
+        Span::styled(
+            if state.latency.rolling {
+                " (w for the last block)"
+            } else {
+                " (w for the rolling window)"
+            },
+            dim_style,
+        ),
+    ]));
+    f.render_widget(title, title_chunk);
+
+    let panel_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 3),
+        ])
+        .split(panels_chunk);
+
+    let panels = [
+        (
+            " HEADER PROPAGATION ",
+            LatencyDistribution::new(
+                latencies
+                    .iter()
+                    .flat_map(|level| level.propagation.iter().copied()),
+            ),
+        ),
+        (
+            " BLOCK APPLICATION ",
+            LatencyDistribution::new(
+                latencies
+                    .iter()
+                    .flat_map(|level| level.application.iter().copied()),
+            ),
+        ),
+        (
+            " ENDORSEMENT DELTA ",
+            LatencyDistribution::new(
+                latencies
+                    .iter()
+                    .flat_map(|level| level.endorsement.iter().copied()),
+            ),
+        ),
+    ];
+
+    for ((title, distribution), chunk) in panels.iter().zip(panel_chunks) {
+        draw_distribution(title, distribution.as_ref(), chunk, f);
+    }
+}
+
+fn draw_distribution<B: Backend>(
+    title: &str,
+    distribution: Option<&LatencyDistribution>,
+    area: Rect,
+    f: &mut Frame<B>,
+) {
+    let dim_style = Style::default()
+        .fg(Color::White)
+        .add_modifier(Modifier::DIM);
+    let normal_style = Style::default().fg(Color::White);
+
+    // the bucket labels share the unit of the largest latency, shown in the title
+    let (factor, unit) = distribution
+        .map(|distribution| time_unit(distribution.max))
+        .unwrap_or((1, "ns"));
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Span::styled(format!("{}({}) ", title, unit), normal_style));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let distribution = match distribution {
+        Some(distribution) => distribution,
+        None => {
+            f.render_widget(Paragraph::new(Span::styled("No data", dim_style)), inner);
+            return;
+        }
+    };
+
+    let [percentiles_chunk, chart_chunk] = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(1)])
+        .split_area(inner);
+
+    let percentile = |label: &'static str, value: u64| {
+        vec![
+            Span::styled(label, dim_style),
+            Span::styled(format_in_unit(value, factor), normal_style),
+        ]
+    };
+    let percentiles = Paragraph::new(Spans::from(
+        [
+            percentile("p50 ", distribution.p50),
+            percentile(" p90 ", distribution.p90),
+            percentile(" p99 ", distribution.p99),
+            vec![Span::styled(
+                format!(" n={}", distribution.count),
+                dim_style,
+            )],
+        ]
+        .concat(),
+    ));
+    f.render_widget(percentiles, percentiles_chunk);
+
+    let labels: Vec<String> = distribution
+        .buckets
+        .iter()
+        .map(|(upper_bound, _)| format_in_unit(*upper_bound, factor))
+        .collect();
+    let data: Vec<(&str, u64)> = labels
+        .iter()
+        .zip(&distribution.buckets)
+        .map(|(label, (_, count))| (label.as_str(), *count))
+        .collect();
+
+    let bucket_count = data.len().max(1) as u16;
+    let bar_width = (chart_chunk.width / bucket_count).saturating_sub(1).max(1);
+    let chart = BarChart::default()
+        .data(&data)
+        .bar_width(bar_width)
+        .bar_gap(1)
+        .bar_style(Style::default().fg(Color::Cyan))
+        .value_style(Style::default().fg(Color::Black).bg(Color::Cyan))
+        .label_style(dim_style);
+    f.render_widget(chart, chart_chunk);
+}
+
+/// The factor and the name of the largest unit not exceeding the nanoseconds
+fn time_unit(nanos: u64) -> (u64, &'static str) {
+    const UNITS: [(u64, &str); 3] = [(1_000_000_000, "s"), (1_000_000, "ms"), (1_000, "μs")];
+
+    UNITS
+        .iter()
+        .copied()
+        .find(|(factor, _)| nanos >= *factor)
+        .unwrap_or((1, "ns"))
+}
+
+/// Short label of the nanoseconds in the unit, to fit under a narrow bar
+fn format_in_unit(nanos: u64, factor: u64) -> String {
+    let value = nanos as f64 / factor as f64;
+    if value >= 10.0 || factor == 1 {
+        format!("{:.0}", value)
+    } else {
+        format!("{:.1}", value)
+    }
+}
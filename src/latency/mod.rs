@@ -0,0 +1,11 @@
+pub mod latency_actions;
+pub use latency_actions::*;
+
+pub mod latency_reducer;
+pub use latency_reducer::*;
+
+pub mod latency_state;
+pub use latency_state::*;
+
+pub mod latency_view;
+pub use latency_view::*;
@@ -1,12 +1,25 @@
+pub mod alerts;
 pub mod automaton;
 pub mod baking;
+pub mod blocks;
 pub mod common;
 pub mod configuration;
+pub mod debugger;
 pub mod endorsements;
+pub mod export;
 pub mod extensions;
+pub mod governance;
+pub mod history;
+pub mod inspector;
+pub mod latency;
+pub mod logs;
+pub mod mock;
 pub mod operations;
+pub mod retention;
+pub mod rights;
 pub mod rpc;
 pub mod services;
 pub mod synchronization;
 pub mod terminal_ui;
+pub mod trends;
 pub mod websocket;
@@ -0,0 +1,85 @@
+use redux_rs::EnablingCondition;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    automaton::State, history::PromptEdit, services::log_service::LogRecord,
+    terminal_ui::ActivePage,
+};
+
+use super::LOG_SEARCH_LENGTH;
+
+/// Take the records logged since the last tick
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsGetAction {}
+
+impl EnablingCondition<State> for LogsGetAction {
+    fn is_enabled(&self, _: &State) -> bool {
+        true
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsReceivedAction {
+    pub records: Vec<LogRecord>,
+}
+
+impl EnablingCondition<State> for LogsReceivedAction {
+    fn is_enabled(&self, _: &State) -> bool {
+        !self.records.is_empty()
+    }
+}
+
+/// Show only the more severe records, from all the records to the critical ones and back
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsLevelFilterAction {}
+
+impl EnablingCondition<State> for LogsLevelFilterAction {
+    fn is_enabled(&self, state: &State) -> bool {
+        state.ui.active_page == ActivePage::Logs
+    }
+}
+
+/// Show only the records of the next module, after the last one all the modules are shown
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsModuleFilterAction {}
+
+impl EnablingCondition<State> for LogsModuleFilterAction {
+    fn is_enabled(&self, state: &State) -> bool {
+        state.ui.active_page == ActivePage::Logs
+    }
+}
+
+/// Open or close the search prompt
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsSearchToggleAction {}
+
+impl EnablingCondition<State> for LogsSearchToggleAction {
+    fn is_enabled(&self, state: &State) -> bool {
+        state.ui.active_page == ActivePage::Logs || state.logs.prompt.is_some()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsSearchEditAction {
+    pub edit: PromptEdit,
+}
+
+impl EnablingCondition<State> for LogsSearchEditAction {
+    fn is_enabled(&self, state: &State) -> bool {
+        match (&state.logs.prompt, &self.edit) {
+            (Some(prompt), PromptEdit::Push(_)) => prompt.chars().count() < LOG_SEARCH_LENGTH,
+            (Some(prompt), PromptEdit::Pop) => !prompt.is_empty(),
+            (None, _) => false,
+        }
+    }
+}
+
+/// Search the text entered in the prompt, an empty prompt clears the search
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogsSearchSubmitAction {}
+
+impl EnablingCondition<State> for LogsSearchSubmitAction {
+    fn is_enabled(&self, state: &State) -> bool {
+        state.logs.prompt.is_some()
+    }
+}
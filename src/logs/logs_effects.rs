@@ -0,0 +1,16 @@
+use crate::{
+    automaton::{Action, ActionWithMeta, Store},
+    services::{log_service::LogService, Service},
+};
+
+use super::LogsReceivedAction;
+
+pub fn logs_effects<S>(store: &mut Store<S>, action: &ActionWithMeta)
+where
+    S: Service,
+{
+    if let Action::LogsGet(_) = &action.action {
+        let records = store.service().logs().take_records();
+        store.dispatch(LogsReceivedAction { records });
+    }
+}
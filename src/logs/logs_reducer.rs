@@ -0,0 +1,39 @@
+use crate::{
+    automaton::{Action, ActionWithMeta, State},
+    history::PromptEdit,
+};
+
+pub fn logs_reducer(state: &mut State, action: &ActionWithMeta) {
+    match &action.action {
+        Action::LogsReceived(action) => {
+            state.logs.push(&action.records);
+        }
+        Action::LogsLevelFilter(_) => {
+            state.logs.next_level();
+        }
+        Action::LogsModuleFilter(_) => {
+            state.logs.next_module();
+        }
+        Action::LogsSearchToggle(_) => {
+            state.logs.prompt = match state.logs.prompt {
+                Some(_) => None,
+                None => Some(state.logs.search.clone().unwrap_or_default()),
+            };
+        }
+        Action::LogsSearchEdit(action) => {
+            if let Some(prompt) = state.logs.prompt.as_mut() {
+                match action.edit {
+                    PromptEdit::Push(character) => prompt.push(character),
+                    PromptEdit::Pop => {
+                        prompt.pop();
+                    }
+                }
+            }
+        }
+        Action::LogsSearchSubmit(_) => {
+            state.logs.search = state.logs.prompt.take().filter(|search| !search.is_empty());
+            state.logs.selected = None;
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,114 @@
+use std::collections::{BTreeSet, VecDeque};
+
+use serde::{Deserialize, Serialize};
+
+use crate::services::log_service::{LogLevel, LogRecord, LOG_RECORDS_CAPACITY};
+
+/// Characters accepted by the search prompt
+pub const LOG_SEARCH_LENGTH: usize = 64;
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LogsState {
+    /// The last records, the oldest first
+    pub records: VecDeque<LogRecord>,
+    /// Only the records of this level or a more severe one are shown
+    pub level: LogLevel,
+    /// Only the records of this module are shown, `None` shows all the modules
+    pub module: Option<String>,
+    /// Only the records containing the text are shown, ignoring the case
+    pub search: Option<String>,
+    /// Text typed in the search prompt, `None` when the prompt is closed
+    pub prompt: Option<String>,
+    /// Index of the selected record in [LogsState::filtered], the newest records are followed when `None`
+    pub selected: Option<usize>,
+}
+
+impl LogsState {
+    /// Add the new records, keeping the selection on the same record while it is kept
+    pub fn push(&mut self, records: &[LogRecord]) {
+        self.records.extend(records.iter().cloned());
+        let overflow = self.records.len().saturating_sub(LOG_RECORDS_CAPACITY);
+        let dropped: Vec<LogRecord> = self.records.drain(..overflow).collect();
+        let dropped_shown = dropped
+            .iter()
+            .filter(|record| self.is_shown(record))
+            .count();
+        self.selected = self
+            .selected
+            .map(|selected| selected.saturating_sub(dropped_shown));
+    }
+
+    pub fn is_shown(&self, record: &LogRecord) -> bool {
+        let module_shown = match &self.module {
+            Some(module) => *module == record.module,
+            None => true,
+        };
+        let search_shown = match &self.search {
+            Some(search) => contains_ignore_case(record, search),
+            None => true,
+        };
+        record.level <= self.level && module_shown && search_shown
+    }
+
+    /// The records passing the filters, the oldest first
+    pub fn filtered(&self) -> Vec<&LogRecord> {
+        self.records
+            .iter()
+            .filter(|record| self.is_shown(record))
+            .collect()
+    }
+
+    /// The modules of the kept records, sorted
+    pub fn modules(&self) -> Vec<&str> {
+        self.records
+            .iter()
+            .map(|record| record.module.as_str())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// Filter the next module, after the last one all the modules are shown again
+    pub fn next_module(&mut self) {
+        let modules = self.modules();
+        let next = match &self.module {
+            None => modules.first(),
+            Some(current) => modules
+                .iter()
+                .skip_while(|module| *module != current)
+                .nth(1),
+        };
+        self.module = next.map(|module| module.to_string());
+        self.selected = None;
+    }
+
+    pub fn next_level(&mut self) {
+        self.level = self.level.next_filter();
+        self.selected = None;
+    }
+
+    /// Move the selection to the older record, the newest one is selected first
+    pub fn select_previous(&mut self) {
+        let count = self.filtered().len();
+        self.selected = match self.selected {
+            _ if count == 0 => None,
+            None => Some(count - 1),
+            Some(selected) => Some(selected.saturating_sub(1).min(count - 1)),
+        };
+    }
+
+    /// Move the selection to the newer record, past the newest one the records are followed again
+    pub fn select_next(&mut self) {
+        let count = self.filtered().len();
+        self.selected = match self.selected {
+            Some(selected) if selected + 1 < count => Some(selected + 1),
+            _ => None,
+        };
+    }
+}
+
+fn contains_ignore_case(record: &LogRecord, search: &str) -> bool {
+    let search = search.to_lowercase();
+    record.message.to_lowercase().contains(&search)
+        || record.module.to_lowercase().contains(&search)
+}
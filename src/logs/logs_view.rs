@@ -0,0 +1,173 @@
+use time::format_description;
+use tui::backend::Backend;
+use tui::layout::{Constraint, Corner, Direction, Layout};
+use tui::style::{Color, Modifier, Style};
+use tui::text::{Span, Spans};
+use tui::widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState};
+use tui::Frame;
+
+use crate::automaton::State;
+use crate::common::{create_header_bar, create_pages_tabs, create_quit, HEADER_HEIGHT};
+use crate::extensions::{CustomSeparator, Renderable, SplitArea};
+use crate::services::log_service::LogLevel;
+
+/// Prefix of the modules of the tui, not shown
+const CRATE_MODULE_PREFIX: &str = "tezedge_tui::";
+
+pub struct LogsScreen {}
+
+impl<B: Backend> Renderable<B> for LogsScreen {
+    fn draw_screen(state: &State, f: &mut Frame<B>) {
+        let size = f.size();
+
+        let background = Block::default().style(Style::default().bg(Color::Rgb(31, 30, 30)));
+        f.render_widget(background, size);
+
+        let [header_chunk, body_chunk, tabs_chunk] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(HEADER_HEIGHT),
+                Constraint::Min(5),
+                Constraint::Length(1),
+            ])
+            .split_area(size);
+
+        let [summary_chunk, records_chunk] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(2), Constraint::Min(3)])
+            .split_area(body_chunk);
+
+        let dimmed_text_style = Style::default()
+            .fg(Color::White)
+            .add_modifier(Modifier::DIM);
+        let normal_style = Style::default().fg(Color::White);
+
+        let logs = &state.logs;
+        let filtered = logs.filtered();
+
+        // ======================== SUMMARY ========================
+        let summary = match &logs.prompt {
+            Some(prompt) => vec![
+                Span::styled(" SEARCH: ", normal_style),
+                Span::styled(format!("{}_", prompt), normal_style),
+                Span::styled(
+                    " - ENTER to search, an empty search shows all, ESC to cancel",
+                    dimmed_text_style,
+                ),
+            ],
+            None => vec![
+                Span::styled(" LOGS - ", normal_style),
+                Span::styled(
+                    format!("{}/{}", filtered.len(), logs.records.len()),
+                    normal_style,
+                ),
+                Span::styled(" records - level ", dimmed_text_style),
+                Span::styled(logs.level.to_string().to_uppercase(), normal_style),
+                Span::styled(" and above (l) - module ", dimmed_text_style),
+                Span::styled(
+                    logs.module
+                        .as_deref()
+                        .map_or("all", short_module)
+                        .to_string(),
+                    normal_style,
+                ),
+                Span::styled(" (o) - search ", dimmed_text_style),
+                Span::styled(logs.search.as_deref().unwrap_or("-"), normal_style),
+                Span::styled(" (/)", dimmed_text_style),
+            ],
+        };
+        let summary = Paragraph::new(Spans::from(summary))
+            .block(Block::default().borders(Borders::TOP | Borders::LEFT | Borders::RIGHT));
+        f.render_widget(summary, summary_chunk);
+
+        // ======================== RECORDS ========================
+        // borders, the header and its margin
+        let visible_rows = records_chunk.height.saturating_sub(4) as usize;
+        // without a selection the newest records are followed
+        let (shown, selected) = match logs.selected {
+            Some(selected) => (&filtered[..], Some(selected)),
+            None => (
+                &filtered[filtered.len().saturating_sub(visible_rows)..],
+                None,
+            ),
+        };
+
+        let format_desc = format_description::parse("[hour]:[minute]:[second]").unwrap_or_default();
+        let rows: Vec<Row> = shown
+            .iter()
+            .map(|record| {
+                Row::new(vec![
+                    Cell::from(record.time.format(&format_desc).unwrap_or_default())
+                        .style(dimmed_text_style),
+                    Cell::from(record.level.as_short_str()).style(level_style(record.level)),
+                    Cell::from(short_module(&record.module).to_string()).style(dimmed_text_style),
+                    Cell::from(record.message.clone()).style(normal_style),
+                ])
+                .height(1)
+            })
+            .collect();
+
+        let header = Row::new(vec!["Time", "Level", "Module", "Message"])
+            .style(normal_style)
+            .height(1)
+            .bottom_margin(1);
+
+        let selected_style = Style::default()
+            .remove_modifier(Modifier::DIM)
+            .bg(Color::Black);
+
+        let widths = [
+            Constraint::Length(8),
+            Constraint::Length(5),
+            Constraint::Length(28),
+            // borders, the highlight symbol, the fixed columns and the column spacing
+            Constraint::Length(records_chunk.width.saturating_sub(47)),
+        ];
+        let table = Table::new(rows)
+            .header(header)
+            .block(Block::default().borders(Borders::ALL))
+            .highlight_style(selected_style)
+            .highlight_symbol("▶")
+            .widths(&widths);
+
+        let mut table_state = TableState::default();
+        table_state.select(selected);
+        f.render_stateful_widget(table, records_chunk, &mut table_state);
+
+        // overlap the block corners with special separators to make flush transition to the table block
+        let vertical_left_separator = CustomSeparator::default()
+            .separator("├")
+            .corner(Corner::TopLeft);
+        f.render_widget(vertical_left_separator, records_chunk);
+
+        let vertical_right_separator = CustomSeparator::default()
+            .separator("┤")
+            .corner(Corner::TopRight);
+        f.render_widget(vertical_right_separator, records_chunk);
+
+        // ======================== HEADER ========================
+        create_header_bar(header_chunk, state, f);
+
+        // ======================== PAGES TABS ========================
+        let tabs = create_pages_tabs(&state.ui);
+        f.render_widget(tabs, tabs_chunk);
+
+        // ======================== Quit ========================
+        create_quit(tabs_chunk, f);
+    }
+}
+
+fn short_module(module: &str) -> &str {
+    module.strip_prefix(CRATE_MODULE_PREFIX).unwrap_or(module)
+}
+
+fn level_style(level: LogLevel) -> Style {
+    match level {
+        LogLevel::Critical | LogLevel::Error => Style::default().fg(Color::Red),
+        LogLevel::Warning => Style::default().fg(Color::Yellow),
+        LogLevel::Info => Style::default().fg(Color::White),
+        LogLevel::Debug | LogLevel::Trace => Style::default()
+            .fg(Color::White)
+            .add_modifier(Modifier::DIM),
+    }
+}
@@ -0,0 +1,14 @@
+pub mod logs_actions;
+pub use logs_actions::*;
+
+pub mod logs_effects;
+pub use logs_effects::*;
+
+pub mod logs_reducer;
+pub use logs_reducer::*;
+
+pub mod logs_state;
+pub use logs_state::*;
+
+pub mod logs_view;
+pub use logs_view::*;
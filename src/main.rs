@@ -1,36 +1,108 @@
 use clap::Parser;
-use std::error::Error;
-use tezedge_tui::{automaton::AutomatonManager, configuration};
+use std::{error::Error, process};
+use tezedge_tui::{
+    automaton::{ActionRecorder, AutomatonManager, NodeConnection},
+    configuration,
+    mock::{MockNode, MockScenario},
+    services::{
+        log_service::LogServiceDefault,
+        traffic_service::{load_traffic, ReplayPace, TrafficRecorder},
+    },
+};
 
 #[tokio::main]
-async fn main() -> Result<(), Box<dyn Error>> {
+async fn main() {
+    // the errors are printed after the terminal is restored, without the debug formatting
+    if let Err(e) = run().await {
+        eprintln!("tezedge-tui: {}", e);
+        process::exit(1);
+    }
+}
+
+async fn run() -> Result<(), Box<dyn Error>> {
     let tui_args = configuration::TuiArgs::parse();
+    let logs = LogServiceDefault::create(&tui_args.log_file, tui_args.log_append).map_err(|e| {
+        format!(
+            "Cannot create the log file {}: {}",
+            tui_args.log_file.display(),
+            e
+        )
+    })?;
+    let log = logs.log.clone();
 
-    let mut automaton_manager = AutomatonManager::new(
-        tui_args.node,
-        tui_args.websocket,
-        tui_args.baker_address,
-        tui_args.record_actions,
-        create_file_logger("tui.log"),
-    );
-    automaton_manager.start().await;
+    let action_recorder = if tui_args.record_actions {
+        Some(ActionRecorder::create(&tui_args.record_actions_path, &log)?)
+    } else {
+        None
+    };
 
-    Ok(())
-}
+    if let Some(recording) = &tui_args.replay {
+        if tui_args.replay_speed.is_nan() || tui_args.replay_speed <= 0.0 {
+            return Err("--replay-speed must be above 0".into());
+        }
+        let pace = if tui_args.replay_step {
+            ReplayPace::Stepped
+        } else {
+            ReplayPace::Speed(tui_args.replay_speed)
+        };
 
-fn create_file_logger(path: &str) -> slog::Logger {
-    use slog::Drain;
+        let mut automaton_manager = AutomatonManager::replay(
+            load_traffic(recording)?,
+            pace,
+            tui_args.baker_address,
+            action_recorder,
+            tui_args.config,
+            tui_args.export_dir,
+            logs,
+        )?;
+        automaton_manager.start().await;
 
-    let file = std::fs::OpenOptions::new()
-        .create(true)
-        .write(true)
-        .truncate(true)
-        .open(path)
-        .expect("Failed to create log file");
+        return Ok(());
+    }
 
-    let decorator = slog_term::PlainDecorator::new(file);
-    let drain = slog_term::FullFormat::new(decorator).build().fuse();
-    let drain = slog_async::Async::new(drain).build().fuse();
+    // the mock node runs until the tui quits
+    let mock_node = if tui_args.mock {
+        let scenario = match &tui_args.mock_scenario {
+            Some(path) => MockScenario::load(path)?,
+            None => MockScenario::default(),
+        };
+        let node = MockNode::start(scenario);
+        node.simulate();
+        Some(node)
+    } else {
+        None
+    };
 
-    slog::Logger::root(drain, slog::o!())
+    let (rpc_url, websocket_url, baker_address) = match &mock_node {
+        Some(mock_node) => (
+            mock_node.rpc_url(),
+            mock_node.websocket_url(),
+            // the first baker of the scenario shows the baking and endorsing screens
+            tui_args
+                .baker_address
+                .or_else(|| mock_node.chain().scenario.bakers.first().cloned()),
+        ),
+        None => (tui_args.node, tui_args.websocket, tui_args.baker_address),
+    };
+
+    let recorder = match &tui_args.record_traffic {
+        Some(path) => Some(TrafficRecorder::create(path, &log)?),
+        None => None,
+    };
+
+    let mut automaton_manager = AutomatonManager::new(
+        NodeConnection {
+            rpc_url,
+            websocket_url,
+            recorder,
+        },
+        baker_address,
+        action_recorder,
+        tui_args.config,
+        tui_args.export_dir,
+        logs,
+    )?;
+    automaton_manager.start().await;
+
+    Ok(())
 }
@@ -0,0 +1,538 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+use serde_json::{json, Value};
+use time::{Duration, OffsetDateTime};
+
+use crate::{
+    baking::{
+        BakingRightsPerLevel, BlockApplicationProtocolStatistics, BlockApplicationStatistics,
+        PerPeerBlockStatistics,
+    },
+    endorsements::{EndorsementRightsWithTimePerLevel, EndorsementStatus},
+    operations::OperationStats,
+    services::{
+        rpc_service_async::{CurrentHeadHeader, RpcCall, RpcTarget},
+        ws_service::WebsocketMessage,
+    },
+    synchronization::{
+        BlockApplicationStatus, BlockInfo, BlockStatus, ChainStatus, Cycle,
+        IncomingTransferMetrics, PeerMetrics,
+    },
+};
+
+use super::MockScenario;
+
+/// The rpc targets with a path of their own, the targets of past levels share the paths
+const SERVED_TARGETS: [RpcTarget; 14] = [
+    RpcTarget::EndorsementRights,
+    RpcTarget::EndersementsStatus,
+    RpcTarget::CurrentHeadHeader,
+    RpcTarget::OperationsStats,
+    RpcTarget::ApplicationStatistics,
+    RpcTarget::PerPeerBlockStatistics,
+    RpcTarget::BakingRights,
+    RpcTarget::EndorsementRightsWithTime,
+    RpcTarget::MempoolEndorsementStats,
+    RpcTarget::NetworkConstants,
+    RpcTarget::CurrentHeadMetadata,
+    RpcTarget::BestRemoteLevel,
+    RpcTarget::Proposals,
+    RpcTarget::PendingOperations,
+];
+
+/// Chain simulated from a scenario, it progresses only when a block is baked or endorsed
+#[derive(Debug, Clone)]
+pub struct MockChain {
+    pub scenario: MockScenario,
+    pub level: i32,
+    pub head_time: OffsetDateTime,
+    /// Delegates that endorsed the head so far
+    pub endorsed: usize,
+}
+
+impl MockChain {
+    pub fn new(scenario: MockScenario) -> Self {
+        Self {
+            level: scenario.start_level,
+            head_time: now_in_seconds(),
+            endorsed: 0,
+            scenario,
+        }
+    }
+
+    /// Make a new head, its endorsements start over
+    pub fn bake_block(&mut self) -> CurrentHeadHeader {
+        self.level += 1;
+        self.head_time = now_in_seconds();
+        self.endorsed = 0;
+        self.header(self.level)
+    }
+
+    /// Endorse the head by the next `delegates`
+    pub fn endorse(&mut self, delegates: usize) {
+        self.endorsed = (self.endorsed + delegates).min(self.scenario.delegates);
+    }
+
+    /// Messages the node sends on the websocket after applying the head, the fixtures of the scenario
+    /// replace the simulated messages of their type
+    pub fn websocket_messages(&self) -> Vec<WebsocketMessage> {
+        let simulated = vec![
+            WebsocketMessage::IncomingTransfer(self.incoming_transfer()),
+            WebsocketMessage::BlockStatus(self.block_statuses()),
+            WebsocketMessage::BlockApplicationStatus(self.application_status()),
+            WebsocketMessage::ChainStatus(ChainStatus {
+                chain: self.cycles(),
+            }),
+            WebsocketMessage::PeersMetrics(self.peers_metrics()),
+        ];
+        simulated
+            .into_iter()
+            .map(|message| {
+                let kind: &'static str = (&message).into();
+                self.scenario
+                    .websocket_messages
+                    .iter()
+                    .find(|fixture| <&'static str>::from(*fixture) == kind)
+                    .cloned()
+                    .unwrap_or(message)
+            })
+            .collect()
+    }
+
+    /// Response to the rpc `path` with the `query`, `None` for the paths the node does not serve
+    pub fn respond(&self, path: &str, query: &HashMap<String, String>) -> Option<Value> {
+        if let Some(response) = self.scenario.responses.get(path) {
+            return Some(response.clone());
+        }
+
+        let target = SERVED_TARGETS
+            .iter()
+            .find(|target| RpcCall::new(**target, None).to_url() == path)?;
+
+        let level = query
+            .get("level")
+            .and_then(|level| level.parse().ok())
+            .unwrap_or(self.level);
+        let cycle = query
+            .get("cycle")
+            .and_then(|cycle| cycle.parse().ok())
+            .unwrap_or_else(|| self.cycle_of(self.level));
+        let delegate = query.get("delegate");
+
+        let response = match target {
+            RpcTarget::EndorsementRights => to_value(self.endorsement_rights()),
+            RpcTarget::EndersementsStatus => to_value(self.endorsement_statuses()),
+            RpcTarget::CurrentHeadHeader => to_value(self.header(self.level)),
+            RpcTarget::OperationsStats => json!({}),
+            RpcTarget::ApplicationStatistics | RpcTarget::LevelApplicationStatistics(_) => {
+                to_value(self.application_statistics(level))
+            }
+            RpcTarget::PerPeerBlockStatistics | RpcTarget::LevelPerPeerBlockStatistics(_) => {
+                to_value(self.per_peer_block_statistics(level))
+            }
+            RpcTarget::BakingRights => to_value(self.baking_rights(delegate, cycle)),
+            RpcTarget::EndorsementRightsWithTime => {
+                to_value(self.endorsement_rights_with_time(delegate, cycle))
+            }
+            RpcTarget::MempoolEndorsementStats => to_value(self.mempool_endorsements()),
+            RpcTarget::NetworkConstants => self.constants(),
+            RpcTarget::CurrentHeadMetadata => self.metadata(),
+            RpcTarget::BestRemoteLevel => json!(self.level),
+            RpcTarget::Proposals => to_value(&self.scenario.proposals),
+            RpcTarget::PendingOperations => self.pending_operations(),
+        };
+
+        Some(response)
+    }
+
+    pub fn delegate(&self, index: usize) -> String {
+        self.scenario
+            .bakers
+            .get(index)
+            .cloned()
+            .unwrap_or_else(|| format!("tz1Mock{:029}", index))
+    }
+
+    pub fn header(&self, level: i32) -> CurrentHeadHeader {
+        CurrentHeadHeader {
+            level,
+            hash: block_hash(level),
+            timestamp: self.level_time(level),
+            predecessor: block_hash(level - 1),
+            protocol: self.scenario.protocol.clone(),
+            priority: 0,
+            ..Default::default()
+        }
+    }
+
+    /// Time the level was baked, or is expected to be baked
+    fn level_time(&self, level: i32) -> OffsetDateTime {
+        self.head_time
+            + Duration::seconds(
+                i64::from(level - self.level) * i64::from(self.scenario.block_delay_seconds),
+            )
+    }
+
+    fn cycle_of(&self, level: i32) -> i32 {
+        (level - 1).div_euclid(self.scenario.blocks_per_cycle.max(1))
+    }
+
+    fn cycle_levels(&self, cycle: i32) -> impl Iterator<Item = i32> {
+        let blocks_per_cycle = self.scenario.blocks_per_cycle.max(1);
+        (cycle * blocks_per_cycle + 1)..=((cycle + 1) * blocks_per_cycle)
+    }
+
+    /// Delegates with the index of the delegate as the first slot, the slots go round robin
+    fn delegate_slots(&self, delegate: usize) -> Vec<u32> {
+        let delegates = self.scenario.delegates.max(1);
+        (0..self.scenario.endorsement_slots)
+            .filter(|slot| *slot as usize % delegates == delegate)
+            .collect()
+    }
+
+    fn delegate_index(&self, address: &str) -> Option<usize> {
+        (0..self.scenario.delegates).find(|index| self.delegate(*index) == address)
+    }
+
+    fn baker_of(&self, level: i32) -> usize {
+        level.rem_euclid(self.scenario.delegates.max(1) as i32) as usize
+    }
+
+    fn endorsement_rights(&self) -> HashMap<String, Vec<u32>> {
+        (0..self.scenario.delegates)
+            .map(|delegate| (self.delegate(delegate), self.delegate_slots(delegate)))
+            .filter(|(_, slots)| !slots.is_empty())
+            .collect()
+    }
+
+    fn endorsement_statuses(&self) -> HashMap<String, EndorsementStatus> {
+        (0..self.endorsed)
+            .filter_map(|delegate| {
+                let slot = *self.delegate_slots(delegate).first()?;
+                let received = 2_000_000 + jitter(self.level + delegate as i32, 30_000_000);
+                // the earlier endorsements had the time to be broadcast
+                let broadcast = delegate < self.endorsed / 2;
+                let status = EndorsementStatus {
+                    received_hash_time: Some(received),
+                    received_contents_time: Some(received + 1_000_000),
+                    decoded_time: Some(received + 1_200_000),
+                    prechecked_time: Some(received + 1_500_000),
+                    applied_time: Some(received + 3_000_000),
+                    broadcast_time: broadcast.then(|| received + 3_400_000),
+                    slot,
+                    state: String::from(if broadcast { "broadcast" } else { "applied" }),
+                    broadcast,
+                };
+                Some((operation_hash(self.level, delegate), status))
+            })
+            .collect()
+    }
+
+    fn mempool_endorsements(&self) -> HashMap<String, OperationStats> {
+        (0..self.endorsed)
+            .map(|delegate| {
+                (
+                    operation_hash(self.level, delegate),
+                    OperationStats::default(),
+                )
+            })
+            .collect()
+    }
+
+    /// The endorsements of the mempool with a varying number of other operations
+    fn pending_operations(&self) -> Value {
+        let endorsements = (0..self.endorsed).map(|delegate| operation_hash(self.level, delegate));
+        let others = (0..jitter(self.level, 40) as usize)
+            .map(|index| format!("o{}_{}", self.level, index));
+        let applied: Vec<Value> = endorsements
+            .chain(others)
+            .map(|hash| json!({ "hash": hash }))
+            .collect();
+        json!({
+            "applied": applied,
+            "refused": [],
+            "outdated": [],
+            "branch_refused": [],
+            "branch_delayed": [],
+            "unprocessed": [],
+        })
+    }
+
+    fn application_statistics(&self, level: i32) -> Vec<BlockApplicationStatistics> {
+        if level > self.level {
+            return Vec::new();
+        }
+
+        let block_time = self.level_time(level).unix_timestamp_nanos();
+        let receive_delay = 150_000_000 + jitter(level, 250_000_000);
+        let apply = 80_000_000 + jitter(level * 7, 400_000_000);
+        // the phases are nanoseconds from receiving the block
+        let phases = [
+            2_000_000,
+            10_000_000,
+            12_000_000,
+            40_000_000,
+            41_000_000,
+            45_000_000,
+            46_000_000,
+            52_000_000,
+            53_000_000,
+            53_000_000 + apply,
+            53_000_000 + apply,
+            60_000_000 + apply,
+            61_000_000 + apply,
+            64_000_000 + apply,
+        ];
+
+        vec![BlockApplicationStatistics {
+            block_hash: block_hash(level),
+            block_timestamp: (block_time / 1_000_000_000) as u64,
+            receive_timestamp: (block_time + i128::from(receive_delay)) as i64,
+            baker: Some(self.delegate(self.baker_of(level))),
+            baker_priority: Some(0),
+            download_block_header_start: Some(0),
+            download_block_header_end: Some(phases[0]),
+            download_block_operations_start: Some(phases[1]),
+            download_block_operations_end: Some(phases[2]),
+            load_data_start: Some(phases[3]),
+            load_data_end: Some(phases[4]),
+            precheck_start: Some(phases[5]),
+            precheck_end: Some(phases[6]),
+            apply_block_start: Some(phases[7]),
+            apply_block_end: Some(phases[9]),
+            store_result_start: Some(phases[10]),
+            store_result_end: Some(phases[11]),
+            send_start: Some(phases[12]),
+            send_end: Some(phases[13]),
+            protocol_times: Some(BlockApplicationProtocolStatistics {
+                apply_start: phases[8],
+                operations_decoding_start: phases[8] + 1_000_000,
+                operations_decoding_end: phases[8] + 5_000_000,
+                operations_metadata_encoding_start: phases[8] + apply - 8_000_000,
+                operations_metadata_encoding_end: phases[8] + apply - 2_000_000,
+                begin_application_start: phases[8] + 5_000_000,
+                begin_application_end: phases[8] + 9_000_000,
+                finalize_block_start: phases[8] + apply - 2_000_000,
+                finalize_block_end: phases[8] + apply - 1_000_000,
+                collect_new_rolls_owner_snapshots_start: phases[8] + apply - 1_000_000,
+                collect_new_rolls_owner_snapshots_end: phases[8] + apply,
+                commit_start: phases[8] + apply,
+                commit_end: phases[8] + apply,
+                apply_end: phases[8] + apply,
+            }),
+            injected: None,
+        }]
+    }
+
+    fn per_peer_block_statistics(&self, level: i32) -> Vec<PerPeerBlockStatistics> {
+        if level > self.level {
+            return Vec::new();
+        }
+
+        (0..self.scenario.peers)
+            .map(|peer| {
+                let seed = level + peer as i32 * 13;
+                let received = 5_000_000 + jitter(seed, 80_000_000);
+                let sent = 70_000_000 + jitter(seed * 3, 120_000_000);
+                PerPeerBlockStatistics {
+                    address: format!("10.0.{}.{}:9732", peer / 250, peer % 250 + 1),
+                    block_hash: block_hash(level),
+                    node_id: format!("idMock{:024}", peer),
+                    received_time: Some(received),
+                    sent_start_time: Some(sent),
+                    sent_end_time: Some(sent + 1_000_000),
+                    sent_time: Some(sent + 1_000_000),
+                    get_operations_recv_start_time: Some(sent + 2_000_000),
+                    get_operations_recv_end_time: Some(sent + 3_000_000),
+                    operations_send_start_time: Some(sent + 4_000_000),
+                    operations_send_end_time: Some(sent + 6_000_000),
+                }
+            })
+            .collect()
+    }
+
+    fn baking_rights(&self, delegate: Option<&String>, cycle: i32) -> Vec<BakingRightsPerLevel> {
+        self.cycle_levels(cycle)
+            .map(|level| (level, self.delegate(self.baker_of(level))))
+            .filter(|(_, baker)| delegate.is_none() || delegate == Some(baker))
+            .map(|(level, baker)| BakingRightsPerLevel {
+                level,
+                priority: 0,
+                round: None,
+                delegate: baker,
+                estimated_time: (level > self.level).then(|| self.level_time(level)),
+            })
+            .collect()
+    }
+
+    fn endorsement_rights_with_time(
+        &self,
+        delegate: Option<&String>,
+        cycle: i32,
+    ) -> Vec<EndorsementRightsWithTimePerLevel> {
+        let delegates: Vec<usize> = match delegate {
+            Some(address) => self.delegate_index(address).into_iter().collect(),
+            None => (0..self.scenario.delegates).collect(),
+        };
+
+        self.cycle_levels(cycle)
+            .flat_map(|level| {
+                delegates
+                    .iter()
+                    .map(move |delegate| EndorsementRightsWithTimePerLevel {
+                        level,
+                        slots: self
+                            .delegate_slots(*delegate)
+                            .into_iter()
+                            .map(|slot| slot as u16)
+                            .collect(),
+                        delegate: self.delegate(*delegate),
+                        estimated_time: (level > self.level).then(|| self.level_time(level)),
+                    })
+            })
+            .filter(|rights| !rights.slots.is_empty())
+            .collect()
+    }
+
+    fn constants(&self) -> Value {
+        let delay = self.scenario.block_delay_seconds.to_string();
+        json!({
+            "minimal_block_delay": delay,
+            "preserved_cycles": 3,
+            "blocks_per_cycle": self.scenario.blocks_per_cycle,
+            "cycles_per_voting_period": self.scenario.cycles_per_voting_period,
+            "endorsers_per_block": self.scenario.endorsement_slots,
+            "delay_per_missed_endorsement": "4",
+            "time_between_blocks": [delay, "10"],
+        })
+    }
+
+    fn metadata(&self) -> Value {
+        let blocks_per_cycle = self.scenario.blocks_per_cycle.max(1);
+        let period_length = blocks_per_cycle * self.scenario.cycles_per_voting_period.max(1);
+        let position = self.level - 1;
+        json!({
+            "protocol": self.scenario.protocol,
+            "next_protocol": self.scenario.protocol,
+            "level_info": {
+                "level": self.level,
+                "level_position": position,
+                "cycle": position.div_euclid(blocks_per_cycle),
+                "cycle_position": position.rem_euclid(blocks_per_cycle),
+                "expected_commitment": false,
+            },
+            "voting_period_info": {
+                "voting_period": {
+                    "index": position.div_euclid(period_length),
+                    "kind": "proposal",
+                    "start_position": position.div_euclid(period_length) * period_length,
+                },
+                "position": position.rem_euclid(period_length),
+                "remaining": period_length - position.rem_euclid(period_length) - 1,
+            },
+        })
+    }
+
+    /// The node is synchronized, it downloads the blocks as they are baked
+    fn incoming_transfer(&self) -> IncomingTransferMetrics {
+        let level = self.level.max(0) as usize;
+        let rate = 1.0 / self.scenario.block_delay_seconds.max(1) as f32;
+        IncomingTransferMetrics {
+            eta: None,
+            current_block_count: level,
+            downloaded_blocks: level,
+            download_rate: rate,
+            average_download_rate: rate,
+            downloaded_headers: level,
+            header_download_rate: rate,
+            header_average_download_rate: rate,
+        }
+    }
+
+    /// Downloaded and applied blocks by cycle, up to the head
+    fn block_statuses(&self) -> Vec<BlockStatus> {
+        self.cycles()
+            .iter()
+            .map(|cycle| BlockStatus {
+                group: cycle.id as i32,
+                numbers_of_blocks: self.scenario.blocks_per_cycle.max(1),
+                finished_blocks: cycle.operations as i32,
+                applied_blocks: cycle.applications as i32,
+                download_duration: cycle.duration,
+            })
+            .collect()
+    }
+
+    fn application_status(&self) -> BlockApplicationStatus {
+        BlockApplicationStatus {
+            current_application_speed: 1.0 + (jitter(self.level, 100) as f32) / 100.0,
+            average_application_speed: 1.5,
+            last_applied_block: Some(BlockInfo {
+                hash: block_hash(self.level),
+                level: self.level,
+            }),
+        }
+    }
+
+    /// Cycles up to the head, all the blocks baked so far are downloaded and applied
+    fn cycles(&self) -> Vec<Cycle> {
+        let head_cycle = self.cycle_of(self.level);
+        (0..=head_cycle.max(0))
+            .map(|cycle| {
+                let baked = self
+                    .cycle_levels(cycle)
+                    .filter(|level| *level <= self.level)
+                    .count();
+                let complete = cycle < head_cycle;
+                Cycle {
+                    id: cycle as usize,
+                    headers: baked,
+                    operations: baked,
+                    applications: baked,
+                    duration: complete.then(|| 60.0 + jitter(cycle, 60) as f32),
+                }
+            })
+            .collect()
+    }
+
+    fn peers_metrics(&self) -> Vec<PeerMetrics> {
+        (0..self.scenario.peers)
+            .map(|peer| {
+                let seed = self.level + peer as i32 * 13;
+                // the blocks with their operations are a few kilobytes
+                let speed = 1_000.0 + jitter(seed, 4_000) as f32;
+                PeerMetrics {
+                    id: format!("idMock{:024}", peer),
+                    ip_address: format!("10.0.{}.{}:9732", peer / 250, peer % 250 + 1),
+                    transferred_bytes: self.level.max(0) as usize * (4_096 + peer * 64),
+                    average_transfer_speed: 2_500.0,
+                    current_transfer_speed: speed,
+                }
+            })
+            .collect()
+    }
+}
+
+pub fn block_hash(level: i32) -> String {
+    format!("BLockMock{:042}", level)
+}
+
+fn operation_hash(level: i32, delegate: usize) -> String {
+    format!("opMock{:020}{:025}", level, delegate)
+}
+
+fn now_in_seconds() -> OffsetDateTime {
+    let now = OffsetDateTime::now_utc();
+    now - Duration::nanoseconds(i64::from(now.nanosecond()))
+}
+
+/// Deterministic variation of the simulated times, below `range`
+fn jitter(seed: i32, range: u64) -> u64 {
+    (seed as u64).wrapping_mul(2_654_435_761) % range.max(1)
+}
+
+fn to_value<T: Serialize>(value: T) -> Value {
+    // the served types serialize into json maps and sequences only
+    serde_json::to_value(value).unwrap_or_default()
+}
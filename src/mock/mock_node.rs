@@ -0,0 +1,180 @@
+use std::{
+    collections::HashMap,
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use futures_util::{SinkExt, StreamExt};
+use tokio::sync::{broadcast, oneshot};
+use url::Url;
+use warp::{
+    http::StatusCode,
+    path::FullPath,
+    ws::{Message, WebSocket, Ws},
+    Filter, Reply,
+};
+
+use crate::services::{rpc_service_async::CurrentHeadHeader, ws_service::WebsocketMessage};
+
+use super::{MockChain, MockScenario};
+
+/// Messages buffered for a slow websocket client before it starts missing them
+const WEBSOCKET_BUFFER: usize = 64;
+
+/// Node serving the rpc and the websocket of a simulated chain on a local port, stopped when dropped
+pub struct MockNode {
+    chain: Arc<Mutex<MockChain>>,
+    websocket: broadcast::Sender<Vec<WebsocketMessage>>,
+    address: SocketAddr,
+    _shutdown: oneshot::Sender<()>,
+}
+
+impl MockNode {
+    /// Start the node on an ephemeral port, must be called from a tokio runtime
+    pub fn start(scenario: MockScenario) -> Self {
+        let chain = Arc::new(Mutex::new(MockChain::new(scenario)));
+        let (websocket, _) = broadcast::channel(WEBSOCKET_BUFFER);
+        let (shutdown, shutdown_signal) = oneshot::channel::<()>();
+
+        let ws_sender = websocket.clone();
+        let websocket_route = warp::path::end().and(warp::ws()).map(move |ws: Ws| {
+            let receiver = ws_sender.subscribe();
+            ws.on_upgrade(move |socket| forward_messages(socket, receiver))
+        });
+
+        let rpc_chain = chain.clone();
+        let rpc_route = warp::get()
+            .and(warp::path::full())
+            .and(warp::query::<HashMap<String, String>>())
+            .map(move |path: FullPath, query: HashMap<String, String>| {
+                let path = path.as_str().trim_start_matches('/');
+                // the lock is only poisoned by a panic in the chain, the test is failing anyway
+                match rpc_chain.lock().unwrap().respond(path, &query) {
+                    Some(response) => warp::reply::json(&response).into_response(),
+                    None => StatusCode::NOT_FOUND.into_response(),
+                }
+            });
+
+        let (address, server) = warp::serve(websocket_route.or(rpc_route))
+            .bind_with_graceful_shutdown(([127, 0, 0, 1], 0), async {
+                // the sender dropped with the node stops the server too
+                let _ = shutdown_signal.await;
+            });
+        tokio::task::spawn(server);
+
+        Self {
+            chain,
+            websocket,
+            address,
+            _shutdown: shutdown,
+        }
+    }
+
+    pub fn rpc_url(&self) -> Url {
+        // the address of a bound socket always makes a valid url
+        Url::parse(&format!("http://{}/", self.address)).unwrap()
+    }
+
+    pub fn websocket_url(&self) -> Url {
+        Url::parse(&format!("ws://{}/", self.address)).unwrap()
+    }
+
+    pub fn chain(&self) -> MockChain {
+        self.chain.lock().unwrap().clone()
+    }
+
+    /// Make a new head and send the messages of its application on the websocket
+    pub fn bake_block(&self) -> CurrentHeadHeader {
+        let mut chain = self.chain.lock().unwrap();
+        let header = chain.bake_block();
+        let messages = chain.websocket_messages();
+        drop(chain);
+        self.send_websocket(messages);
+        header
+    }
+
+    /// Endorse the head by the next `delegates`
+    pub fn endorse(&self, delegates: usize) {
+        self.chain.lock().unwrap().endorse(delegates);
+    }
+
+    /// Send the messages to the connected websocket clients, returns the number of the clients
+    pub fn send_websocket(&self, messages: Vec<WebsocketMessage>) -> usize {
+        self.websocket.send(messages).unwrap_or_default()
+    }
+
+    /// Progress the chain in real time: a block every `block_delay_seconds` and its endorsements in between
+    pub fn simulate(&self) {
+        let chain = self.chain.clone();
+        let websocket = self.websocket.clone();
+        let mut driver = MockNodeDriver {
+            chain,
+            websocket,
+            since_block: 0,
+        };
+
+        tokio::task::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(1));
+            loop {
+                interval.tick().await;
+                if !driver.step() {
+                    break;
+                }
+            }
+        });
+    }
+}
+
+/// The parts of the node the simulation task holds on to, it stops once the node is dropped
+struct MockNodeDriver {
+    chain: Arc<Mutex<MockChain>>,
+    websocket: broadcast::Sender<Vec<WebsocketMessage>>,
+    /// Seconds since the last baked block
+    since_block: u32,
+}
+
+impl MockNodeDriver {
+    /// Progress the chain by a second, `false` once the node is dropped
+    fn step(&mut self) -> bool {
+        // only the driver holds the chain after the node is dropped
+        if Arc::strong_count(&self.chain) == 1 {
+            return false;
+        }
+
+        let mut chain = self.chain.lock().unwrap();
+        let delay = chain.scenario.block_delay_seconds.max(1);
+        self.since_block += 1;
+        if self.since_block >= delay {
+            self.since_block = 0;
+            chain.bake_block();
+            let _ = self.websocket.send(chain.websocket_messages());
+        } else {
+            // most of the delegates endorse within the first half of the block delay
+            let per_second = (chain.scenario.delegates * 2 / delay as usize).max(1);
+            chain.endorse(per_second);
+        }
+        true
+    }
+}
+
+async fn forward_messages(
+    socket: WebSocket,
+    mut receiver: broadcast::Receiver<Vec<WebsocketMessage>>,
+) {
+    let (mut sender, _) = socket.split();
+    loop {
+        let messages = match receiver.recv().await {
+            Ok(messages) => messages,
+            Err(broadcast::error::RecvError::Lagged(_)) => continue,
+            Err(broadcast::error::RecvError::Closed) => break,
+        };
+        let text = match serde_json::to_string(&messages) {
+            Ok(text) => text,
+            Err(_) => continue,
+        };
+        if sender.send(Message::text(text)).await.is_err() {
+            break;
+        }
+    }
+}
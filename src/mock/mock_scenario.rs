@@ -0,0 +1,66 @@
+use std::{collections::BTreeMap, fs::File, io, path::Path};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{governance::Proposal, services::ws_service::WebsocketMessage};
+
+#[derive(Error, Debug)]
+pub enum ScenarioError {
+    #[error("Scenario file io error: {0}")]
+    Io(#[from] io::Error),
+    #[error("Scenario file is not valid json: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// Fixture of the chain simulated by the mock node
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct MockScenario {
+    /// Level of the head when the node starts
+    pub start_level: i32,
+    /// Seconds between the blocks, also the `minimal_block_delay` constant
+    pub block_delay_seconds: u32,
+    pub blocks_per_cycle: i32,
+    pub cycles_per_voting_period: i32,
+    pub endorsement_slots: u32,
+    /// Delegates holding the baking and endorsement rights, the bakers first
+    pub delegates: usize,
+    /// Addresses of the first delegates, usable as `--baker-address`
+    pub bakers: Vec<String>,
+    /// Peers the blocks are propagated to
+    pub peers: usize,
+    pub protocol: String,
+    pub proposals: Vec<Proposal>,
+    /// Fixed responses by the rpc path, served instead of the simulated ones
+    pub responses: BTreeMap<String, serde_json::Value>,
+    /// Fixed websocket messages, sent instead of the simulated messages of the same type
+    pub websocket_messages: Vec<WebsocketMessage>,
+}
+
+impl Default for MockScenario {
+    fn default() -> Self {
+        Self {
+            start_level: 2_000,
+            block_delay_seconds: 15,
+            blocks_per_cycle: 256,
+            cycles_per_voting_period: 5,
+            endorsement_slots: 256,
+            delegates: 32,
+            bakers: vec![String::from("tz1Mkb2MQyHnVybEru6iTgTGQaZikyg4fBhr")],
+            peers: 8,
+            protocol: String::from("PtHangz2aRngywmSRGGvrcTyMbbdpWdpFKuS4uMWxg2RaH9i1qx"),
+            proposals: Vec::new(),
+            responses: BTreeMap::new(),
+            websocket_messages: Vec::new(),
+        }
+    }
+}
+
+impl MockScenario {
+    /// Load the scenario from a json file, the missing fields take the default values
+    pub fn load(path: &Path) -> Result<Self, ScenarioError> {
+        let file = File::open(path)?;
+        Ok(serde_json::from_reader(file)?)
+    }
+}
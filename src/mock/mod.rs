@@ -0,0 +1,8 @@
+pub mod mock_chain;
+pub use mock_chain::*;
+
+pub mod mock_node;
+pub use mock_node::*;
+
+pub mod mock_scenario;
+pub use mock_scenario::*;
@@ -20,11 +20,7 @@ pub fn operations_reducer(state: &mut State, action: &ActionWithMeta) {
             state
                 .operations_statistics
                 .main_operation_statistics_table
-                .content = sortable;
-            state
-                .operations_statistics
-                .main_operation_statistics_table
-                .sort_content(delta_toggle);
+                .set_content(sortable, delta_toggle);
         }
         _ => {}
     }
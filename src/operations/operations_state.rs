@@ -8,7 +8,8 @@ use tui::{
 };
 
 use crate::extensions::{
-    convert_time_to_unit_string, get_time_style, ExtendedTable, SortableByFocus, TuiTableData,
+    convert_time_to_unit_string, get_time_style, ExtendedTable, SortKey, SortKeyByFocus,
+    TableRowKey, TuiTableData,
 };
 
 pub type OperationsStats = BTreeMap<String, OperationStats>;
@@ -206,7 +207,17 @@ pub struct OperationDetailSortable {
     pub sent: usize,
 }
 
+impl TableRowKey for OperationDetailSortable {
+    fn row_key(&self) -> String {
+        self.node_id.clone()
+    }
+}
+
 impl TuiTableData for OperationDetailSortable {
+    fn duration_columns() -> &'static [usize] {
+        &[1, 2, 3]
+    }
+
     fn construct_tui_table_data(&self, _delta_toggle: bool) -> Vec<(String, Style)> {
         let mut final_vec = Vec::with_capacity(7);
         let missing_value = (String::from('-'), Style::default().fg(Color::DarkGray));
@@ -464,7 +475,17 @@ impl OperationStats {
     }
 }
 
+impl TableRowKey for OperationStatsSortable {
+    fn row_key(&self) -> String {
+        self.hash.clone()
+    }
+}
+
 impl TuiTableData for OperationStatsSortable {
+    fn duration_columns() -> &'static [usize] {
+        &[0, 3, 4, 5, 6, 7, 8, 9, 11]
+    }
+
     fn construct_tui_table_data(&self, delta_toggle: bool) -> Vec<(String, Style)> {
         let mut final_vec = Vec::with_capacity(13);
         let missing_value = (String::from('-'), Style::default().fg(Color::DarkGray));
@@ -616,75 +637,46 @@ impl TuiTableData for OperationStatsSortable {
     }
 }
 
-impl SortableByFocus for OperationsStatsSortable {
-    fn sort_by_focus(&mut self, focus_index: usize, delta_toggle: bool) {
+impl SortKeyByFocus for OperationStatsSortable {
+    fn sort_key(&self, focus_index: usize, delta_toggle: bool) -> Option<SortKey> {
+        let pick = |concrete: Option<i64>, delta: Option<i64>| {
+            if delta_toggle {
+                delta
+            } else {
+                concrete
+            }
+        };
+
         match focus_index {
-            0 => self.sort_by_key(|k| k.datetime),
-            1 => self.sort_by_key(|k| k.hash.clone()),
-            2 => self.sort_by_key(|k| k.nodes),
-            3 => self.sort_by_key(|k| k.delta),
-            4 => self.sort_by_key(|k| k.received),
-            5 => self.sort_by_key(|k| {
-                if delta_toggle {
-                    k.content_received_delta
-                } else {
-                    k.content_received
-                }
-            }),
-            6 => self.sort_by_key(|k| {
-                if delta_toggle {
-                    k.validation_started_delta
-                } else {
-                    k.validation_started
-                }
-            }),
-            7 => self.sort_by_key(|k| {
-                if delta_toggle {
-                    k.preapply_started_delta
-                } else {
-                    k.preapply_started
-                }
-            }),
-            8 => self.sort_by_key(|k| {
-                if delta_toggle {
-                    k.preapply_ended_delta
-                } else {
-                    k.preapply_ended
-                }
-            }),
-            9 => self.sort_by_key(|k| {
-                if delta_toggle {
-                    k.validation_finished_delta
-                } else {
-                    k.validation_finished
-                }
-            }),
-            10 => self.sort_by_key(|k| k.validations_length),
-            11 => self.sort_by_key(|k| if delta_toggle { k.sent_delta } else { k.sent }),
-            12 => self.sort_by_key(|k| k.kind),
-            _ => {}
+            0 => Some(self.datetime.into()),
+            1 => Some(self.hash.clone().into()),
+            2 => Some(self.nodes.into()),
+            3 => self.delta.map(SortKey::from),
+            4 => self.received.map(SortKey::from),
+            5 => pick(self.content_received, self.content_received_delta).map(SortKey::from),
+            6 => pick(self.validation_started, self.validation_started_delta).map(SortKey::from),
+            7 => pick(self.preapply_started, self.preapply_started_delta).map(SortKey::from),
+            8 => pick(self.preapply_ended, self.preapply_ended_delta).map(SortKey::from),
+            9 => pick(self.validation_finished, self.validation_finished_delta).map(SortKey::from),
+            10 => Some(self.validations_length.into()),
+            11 => pick(self.sent, self.sent_delta).map(SortKey::from),
+            12 => self.kind.map(|kind| SortKey::Number(kind as i128)),
+            _ => None,
         }
     }
-
-    fn rev(&mut self) {
-        self.reverse()
-    }
 }
 
-impl SortableByFocus for OperationDetailsSortable {
-    fn sort_by_focus(&mut self, focus_index: usize, _delta_toogle: bool) {
+impl SortKeyByFocus for OperationDetailSortable {
+    fn sort_key(&self, focus_index: usize, _delta_toogle: bool) -> Option<SortKey> {
         match focus_index {
-            0 => self.sort_by_key(|k| k.node_id.clone()),
-            1 => self.sort_by_key(|k| k.first_received),
-            2 => self.sort_by_key(|k| k.first_content_received),
-            3 => self.sort_by_key(|k| k.first_sent),
-            4 => self.sort_by_key(|k| k.received),
-            5 => self.sort_by_key(|k| k.content_received),
-            6 => self.sort_by_key(|k| k.sent),
-            _ => {}
+            0 => Some(self.node_id.clone().into()),
+            1 => self.first_received.map(SortKey::from),
+            2 => self.first_content_received.map(SortKey::from),
+            3 => self.first_sent.map(SortKey::from),
+            4 => Some(self.received.into()),
+            5 => Some(self.content_received.into()),
+            6 => Some(self.sent.into()),
+            _ => None,
         }
     }
-    fn rev(&mut self) {
-        self.reverse()
-    }
 }
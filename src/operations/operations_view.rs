@@ -8,11 +8,12 @@ use tui::{
     Frame,
 };
 
-use itertools::Itertools;
-
 use crate::automaton::State;
-use crate::common::{create_header_bar, create_help_bar, create_pages_tabs, create_quit};
-use crate::extensions::{CustomSeparator, Renderable};
+use crate::common::{
+    create_header_bar, create_help_bar, create_pages_tabs, create_quit, draw_column_chooser,
+    HEADER_HEIGHT,
+};
+use crate::extensions::{CustomSeparator, Renderable, SplitArea};
 
 const SIDE_PADDINGS: u16 = 1;
 const SIDE_BY_SIDE_TABLE_THRESHOLD: u16 = 128;
@@ -32,7 +33,7 @@ impl<B: Backend> Renderable<B> for StatisticsScreen {
         let page_chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(2),
+                Constraint::Length(HEADER_HEIGHT),
                 Constraint::Length(2),
                 Constraint::Min(5),
                 Constraint::Length(1),
@@ -65,23 +66,17 @@ impl<B: Backend> Renderable<B> for StatisticsScreen {
         // ======================== HELP BAR ========================
         create_help_bar(page_chunks[1], f, delta_toggle);
 
-        let (main_table_chunk, details_table_chunk) =
+        let [main_table_chunk, details_table_chunk] =
             if f.size().width < SIDE_BY_SIDE_TABLE_THRESHOLD {
                 Layout::default()
                     .direction(Direction::Vertical)
                     .constraints([Constraint::Percentage(75), Constraint::Length(25)])
-                    .split(page_chunks[2])
-                    .into_iter()
-                    .collect_tuple()
-                    .unwrap()
+                    .split_area(page_chunks[2])
             } else {
                 Layout::default()
                     .direction(Direction::Horizontal)
                     .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-                    .split(page_chunks[2])
-                    .into_iter()
-                    .collect_tuple()
-                    .unwrap()
+                    .split_area(page_chunks[2])
             };
 
         // ======================== MAIN STATISTICS TABLE ========================
@@ -91,9 +86,11 @@ impl<B: Backend> Renderable<B> for StatisticsScreen {
         let normal_style = Style::default().fg(Color::White);
 
         let max_size: u16 = if f.size().width < SIDE_BY_SIDE_TABLE_THRESHOLD {
-            f.size().width - SIDE_PADDINGS
+            f.size().width.saturating_sub(SIDE_PADDINGS)
         } else {
-            f.size().width - details_table_chunk.width - SIDE_PADDINGS
+            f.size()
+                .width
+                .saturating_sub(details_table_chunk.width + SIDE_PADDINGS)
         };
 
         let renderable_constraints = state
@@ -208,5 +205,8 @@ impl<B: Backend> Renderable<B> for StatisticsScreen {
             .separator("┤")
             .corner(Corner::TopRight);
         f.render_widget(vertical_right_separator, details_table_chunk);
+
+        // ======================== COLUMN CHOOSER ========================
+        draw_column_chooser(state, f);
     }
 }
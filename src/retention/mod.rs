@@ -0,0 +1,8 @@
+pub mod retention_reducer;
+pub use retention_reducer::*;
+
+pub mod retention_state;
+pub use retention_state::*;
+
+pub mod retention_view;
+pub use retention_view::*;
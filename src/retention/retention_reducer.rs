@@ -0,0 +1,87 @@
+use crate::automaton::{Action, ActionWithMeta, State};
+
+use super::memory_usage;
+
+pub fn retention_reducer(state: &mut State, action: &ActionWithMeta) {
+    match &action.action {
+        Action::ApplicationStatisticsReceived(stats_action) => {
+            let level = stats_action
+                .level
+                .unwrap_or(state.current_head_header.level);
+            for stats in &stats_action.application_statistics {
+                state.retention.record_block(&stats.block_hash, level);
+            }
+        }
+        Action::PerPeerBlockStatisticsReceived(stats_action) => {
+            let level = stats_action
+                .level
+                .unwrap_or(state.current_head_header.level);
+            if let Some(peer_stats) = stats_action.per_peer_block_statistics.first() {
+                state.retention.record_block(&peer_stats.block_hash, level);
+            }
+        }
+        Action::CurrentHeadHeaderChanged(head_action) => {
+            let head_level = head_action.current_head_header.level;
+            state
+                .retention
+                .level_times
+                .entry(head_level)
+                .or_insert_with(|| action.time_as_nanos());
+
+            let cutoff = state.retention.cutoff_level(
+                &state.config.retention,
+                head_level,
+                action.time_as_nanos(),
+            );
+            prune(state, cutoff);
+            if state.ui.memory_panel_open {
+                state.retention.usage = memory_usage(state);
+            }
+        }
+        Action::TuiMemoryPanelKeyPushed(_) => {
+            state.retention.usage = if state.ui.memory_panel_open {
+                memory_usage(state)
+            } else {
+                Vec::new()
+            };
+        }
+        _ => {}
+    }
+}
+
+/// Drop everything collected for the levels below `cutoff`
+fn prune(state: &mut State, cutoff: i32) {
+    let retention = &mut state.retention;
+    retention.level_times = retention.level_times.split_off(&cutoff);
+    retention.block_levels.retain(|_, level| *level >= cutoff);
+    retention.cutoff_level = Some(cutoff);
+
+    let block_levels = &retention.block_levels;
+    let retained = |hash: &String| block_levels.contains_key(hash);
+    state
+        .baking
+        .application_statistics
+        .retain(|hash, _| retained(hash));
+    state
+        .baking
+        .per_peer_block_statistics
+        .retain(|hash, _| retained(hash));
+
+    let endorsements = &mut state.endorsmenents;
+    endorsements.injected_endorsement_stats =
+        endorsements.injected_endorsement_stats.split_off(&cutoff);
+
+    let blocks = &mut state.blocks;
+    blocks.recent.retain(|block| block.level >= cutoff);
+    blocks.selected = match blocks.selected {
+        Some(_) if blocks.recent.is_empty() => None,
+        selected => selected.map(|selected| selected.min(blocks.recent.len() - 1)),
+    };
+
+    state
+        .latency
+        .window
+        .retain(|latencies| latencies.level >= cutoff);
+    state.trends.samples.retain(|sample| sample.level >= cutoff);
+    state.rights.cleanup(cutoff);
+}
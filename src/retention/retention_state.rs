@@ -0,0 +1,157 @@
+use std::{collections::BTreeMap, io};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{automaton::State, configuration::RetentionConfig};
+
+/// The head and the previous head are always kept, the reducers compare them on a new head
+pub const MIN_RETAINED_LEVELS: i32 = 2;
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RetentionState {
+    /// Level of each block with collected statistics, the statistics do not carry the level
+    pub block_levels: BTreeMap<String, i32>,
+    /// Time the level was first seen as the head, in nanoseconds
+    pub level_times: BTreeMap<i32, u64>,
+    /// Levels below this one were dropped by the last pruning
+    pub cutoff_level: Option<i32>,
+    /// Usage shown by the memory panel, measured when the panel opens and on each new head
+    #[serde(skip)]
+    pub usage: Vec<SliceUsage>,
+}
+
+impl RetentionState {
+    /// Remember the level of the block, the first level recorded for the hash stays
+    pub fn record_block(&mut self, hash: &str, level: i32) {
+        if !self.block_levels.contains_key(hash) {
+            self.block_levels.insert(hash.to_string(), level);
+        }
+    }
+
+    /// The lowest level kept by the `policy` with the head at `head_level` at the time `now`
+    pub fn cutoff_level(&self, policy: &RetentionConfig, head_level: i32, now: u64) -> i32 {
+        let mut cutoff = i32::MIN;
+
+        if policy.max_levels > 0 {
+            let max_levels = i32::try_from(policy.max_levels).unwrap_or(i32::MAX);
+            cutoff = head_level.saturating_sub(max_levels).saturating_add(1);
+        }
+
+        if policy.max_age_seconds > 0 {
+            let oldest = now.saturating_sub(policy.max_age_seconds.saturating_mul(1_000_000_000));
+            if let Some((level, _)) = self.level_times.iter().find(|(_, seen)| **seen >= oldest) {
+                cutoff = cutoff.max(*level);
+            }
+        }
+
+        cutoff.min(head_level.saturating_sub(MIN_RETAINED_LEVELS - 1))
+    }
+}
+
+/// Entry count and approximate memory of a state slice growing during the session
+#[derive(Debug, Clone, PartialEq)]
+pub struct SliceUsage {
+    pub name: &'static str,
+    pub entries: usize,
+    pub bytes: usize,
+}
+
+/// Usage of the accumulating state slices, the pruned ones first
+pub fn memory_usage(state: &State) -> Vec<SliceUsage> {
+    let usage = |name, entries, bytes| SliceUsage {
+        name,
+        entries,
+        bytes,
+    };
+
+    vec![
+        usage(
+            "Application statistics",
+            state.baking.application_statistics.len(),
+            approximate_size(&state.baking.application_statistics),
+        ),
+        usage(
+            "Per peer block statistics",
+            state.baking.per_peer_block_statistics.len(),
+            approximate_size(&state.baking.per_peer_block_statistics),
+        ),
+        usage(
+            "Injected endorsement statistics",
+            state.endorsmenents.injected_endorsement_stats.len(),
+            approximate_size(&state.endorsmenents.injected_endorsement_stats),
+        ),
+        usage(
+            "Recent blocks",
+            state.blocks.recent.len(),
+            approximate_size(&state.blocks.recent),
+        ),
+        usage(
+            "Past level statistics",
+            state.history.cache.len(),
+            approximate_size(&state.history.cache),
+        ),
+        usage(
+            "Latency window",
+            state.latency.window.len(),
+            approximate_size(&state.latency.window),
+        ),
+        usage(
+            "Header trends",
+            state.trends.samples.len(),
+            approximate_size(&state.trends.samples),
+        ),
+        usage(
+            "Rights calendar",
+            state.rights.calendar.len(),
+            approximate_size(&state.rights.calendar),
+        ),
+        usage(
+            "Retention index",
+            state.retention.block_levels.len() + state.retention.level_times.len(),
+            approximate_size(&state.retention),
+        ),
+        usage(
+            "Alert log",
+            state.alerts.log.len(),
+            approximate_size(&state.alerts.log),
+        ),
+        usage(
+            "Log records",
+            state.logs.records.len(),
+            approximate_size(&state.logs.records),
+        ),
+        usage(
+            "Inspected rpc calls",
+            state.inspector.calls.len(),
+            approximate_size(&state.inspector.calls),
+        ),
+        usage(
+            "Inspected actions and messages",
+            state.inspector.actions.len() + state.inspector.websocket_messages.len(),
+            approximate_size(&state.inspector.actions)
+                + approximate_size(&state.inspector.websocket_messages),
+        ),
+    ]
+}
+
+/// Approximate memory taken by the value, measured as the size of its json serialization
+pub fn approximate_size<T: Serialize + ?Sized>(value: &T) -> usize {
+    let mut counter = ByteCounter::default();
+    // the counter never fails
+    let _ = serde_json::to_writer(&mut counter, value);
+    counter.0
+}
+
+#[derive(Default)]
+struct ByteCounter(usize);
+
+impl io::Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0 += buf.len();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
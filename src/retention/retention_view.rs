@@ -0,0 +1,132 @@
+use tui::{
+    backend::Backend,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table},
+    Frame,
+};
+
+use crate::automaton::State;
+
+/// Draw the entry counts and the approximate memory of the accumulating state as a popup over the screen
+pub fn draw_memory_panel<B: Backend>(state: &State, f: &mut Frame<B>) {
+    if !state.ui.memory_panel_open {
+        return;
+    }
+
+    let dim_style = Style::default().fg(Color::Gray).add_modifier(Modifier::DIM);
+    let value_style = Style::default().fg(Color::White);
+
+    let usage = &state.retention.usage;
+    let total: usize = usage.iter().map(|slice| slice.bytes).sum();
+    let slice_count = usage.len();
+    let mut rows: Vec<Row> = usage
+        .iter()
+        .enumerate()
+        .map(|(index, slice)| {
+            let row = Row::new(vec![
+                Cell::from(slice.name).style(dim_style),
+                Cell::from(slice.entries.to_string()).style(value_style),
+                Cell::from(format_bytes(slice.bytes)).style(value_style),
+            ]);
+            // space before the total
+            if index + 1 == slice_count {
+                row.bottom_margin(1)
+            } else {
+                row
+            }
+        })
+        .collect();
+    rows.push(Row::new(vec![
+        Cell::from("Total").style(value_style),
+        Cell::from(""),
+        Cell::from(format_bytes(total)).style(value_style),
+    ]));
+
+    let policy = &state.config.retention;
+    let limit = |value: u64, unit: &str| {
+        if value == 0 {
+            String::from("unlimited")
+        } else {
+            format!("{} {}", value, unit)
+        }
+    };
+    let cutoff = state
+        .retention
+        .cutoff_level
+        .map_or_else(|| String::from("-"), |level| level.to_string());
+    let policy_line = Spans::from(vec![
+        Span::styled(" Keeping ", dim_style),
+        Span::styled(limit(policy.max_levels.into(), "levels"), value_style),
+        Span::styled(" / ", dim_style),
+        Span::styled(limit(policy.max_age_seconds, "seconds"), value_style),
+        Span::styled(", oldest level kept ", dim_style),
+        Span::styled(cutoff, value_style),
+    ]);
+
+    let size = f.size();
+    let width = 64.min(size.width);
+    // borders, policy line, header, total and the help line
+    let height = (rows.len() as u16 + 8).min(size.height);
+    let area = Rect::new(
+        (size.width - width) / 2,
+        (size.height - height) / 2,
+        width,
+        height,
+    );
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("Memory")
+        .style(Style::default().bg(Color::Rgb(31, 30, 30)));
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(area);
+
+    f.render_widget(Clear, area);
+
+    let inner = block.inner(chunks[0]);
+    f.render_widget(block, chunks[0]);
+
+    let inner_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Min(1)])
+        .split(inner);
+
+    f.render_widget(Paragraph::new(policy_line), inner_chunks[0]);
+
+    let header = Row::new(vec!["Slice", "Entries", "Approx. size"])
+        .style(value_style)
+        .bottom_margin(1);
+    let usage_table = Table::new(rows).header(header).widths(&[
+        Constraint::Length(32),
+        Constraint::Length(9),
+        Constraint::Length(12),
+    ]);
+    f.render_widget(usage_table, inner_chunks[1]);
+
+    let help = Paragraph::new(Spans::from(vec![Span::styled("m close", dim_style)]))
+        .alignment(Alignment::Center)
+        .style(Style::default().bg(Color::Rgb(31, 30, 30)));
+    f.render_widget(help, chunks[1]);
+}
+
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
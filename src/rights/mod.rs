@@ -0,0 +1,14 @@
+pub mod rights_actions;
+pub use rights_actions::*;
+
+pub mod rights_effects;
+pub use rights_effects::*;
+
+pub mod rights_reducer;
+pub use rights_reducer::*;
+
+pub mod rights_state;
+pub use rights_state::*;
+
+pub mod rights_view;
+pub use rights_view::*;
@@ -0,0 +1,16 @@
+use redux_rs::EnablingCondition;
+use serde::{Deserialize, Serialize};
+
+use crate::automaton::State;
+
+/// Request both baking and endorsing rights of the baker for a whole cycle
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RightsCycleGetAction {
+    pub cycle: i32,
+}
+
+impl EnablingCondition<State> for RightsCycleGetAction {
+    fn is_enabled(&self, state: &State) -> bool {
+        state.baker_address.is_some() && !state.rights.requested_cycles.contains_key(&self.cycle)
+    }
+}
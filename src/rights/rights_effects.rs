@@ -0,0 +1,40 @@
+use crate::{
+    automaton::{Action, ActionWithMeta, Store},
+    baking::BakingRightsGetAction,
+    endorsements::EndorsementsRightsWithTimeGetAction,
+    services::Service,
+};
+
+use super::RightsCycleGetAction;
+
+pub fn rights_effects<S>(store: &mut Store<S>, action: &ActionWithMeta)
+where
+    S: Service,
+{
+    match &action.action {
+        // the constants and the metadata arrive in no particular order, so try on both
+        Action::CurrentHeadMetadataChanged(_) | Action::NetworkConstantsReceived(_) => {
+            let level_info = &store.state().current_head_metadata.level_info;
+            // no metadata yet, the current cycle is unknown
+            if level_info.level == 0 {
+                return;
+            }
+            let current_cycle = level_info.cycle;
+            let preserved_cycles = store.state().network_constants.preserved_cycles;
+
+            // already requested cycles are filtered out by the enabling condition
+            for cycle in current_cycle..=current_cycle + preserved_cycles {
+                store.dispatch(RightsCycleGetAction { cycle });
+            }
+        }
+        Action::RightsCycleGet(action) => {
+            store.dispatch(BakingRightsGetAction {
+                cycle: action.cycle,
+            });
+            store.dispatch(EndorsementsRightsWithTimeGetAction {
+                cycle: action.cycle,
+            });
+        }
+        _ => {}
+    }
+}
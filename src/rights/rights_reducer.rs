@@ -0,0 +1,49 @@
+use crate::automaton::{Action, ActionWithMeta, State};
+
+use super::CycleRightsRequest;
+
+pub fn rights_reducer(state: &mut State, action: &ActionWithMeta) {
+    let now = action.time_as_nanos();
+    match &action.action {
+        Action::BakingRightsReceived(action) => {
+            state.rights.add_baking(&action.rights);
+            if let Some(request) = state.rights.requested_cycles.get_mut(&action.cycle) {
+                request.baking_received = true;
+            }
+        }
+        Action::EndorsementsRightsWithTimeReceived(action) => {
+            state.rights.add_endorsing(&action.rights);
+            if let Some(request) = state.rights.requested_cycles.get_mut(&action.cycle) {
+                request.endorsing_received = true;
+            }
+        }
+        Action::RightsCycleGet(action) => {
+            state.rights.requested_cycles.insert(
+                action.cycle,
+                CycleRightsRequest {
+                    requested: now,
+                    ..Default::default()
+                },
+            );
+        }
+        Action::CurrentHeadMetadataChanged(_) => {
+            // the effects request the cycles again, unless their rights arrived or are still on the way
+            state
+                .rights
+                .requested_cycles
+                .retain(|_, request| !request.is_timed_out(now));
+        }
+        Action::CycleChanged(action) => {
+            // past cycles will not be requested again
+            state
+                .rights
+                .requested_cycles
+                .retain(|cycle, _| *cycle >= action.new_cycle);
+        }
+        Action::CurrentHeadHeaderChanged(action) => {
+            // rights in the past are no longer interesting, keep the current level though
+            state.rights.cleanup(action.current_head_header.level);
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,145 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::{baking::BakingRightsPerLevel, endorsements::EndorsementRightsWithTimePerLevel};
+
+/// Time after which the rights of a cycle that did not arrive are requested again, in nanoseconds
+pub const RIGHTS_REQUEST_TIMEOUT: u64 = 60_000_000_000;
+
+/// Rights of the baker in a single level
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LevelRights {
+    pub baking_priority: Option<u64>,
+    /// Round of the baking right since Tenderbake
+    #[serde(default)]
+    pub baking_round: Option<u64>,
+    pub endorsing_slots: Option<usize>,
+    #[serde(default)]
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub estimated_time: Option<OffsetDateTime>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum RightKind {
+    /// The round is `None` before Tenderbake
+    Baking { priority: u64, round: Option<u64> },
+    Endorsing { slots: usize },
+}
+
+/// One line of the rights calendar
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalendarEntry {
+    pub level: i32,
+    pub kind: RightKind,
+    pub estimated_time: Option<OffsetDateTime>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RightsState {
+    /// Upcoming baking and endorsing rights of the baker keyed by level
+    pub calendar: BTreeMap<i32, LevelRights>,
+    /// Index of the selected entry, see [RightsState::entries]
+    pub selected: Option<usize>,
+    /// Cycles the rights were requested for, a timed out request is dropped to request the rights again
+    #[serde(default)]
+    pub requested_cycles: BTreeMap<i32, CycleRightsRequest>,
+}
+
+/// Request of the baking and endorsing rights of a cycle
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CycleRightsRequest {
+    /// Time of the request in nanoseconds
+    pub requested: u64,
+    pub baking_received: bool,
+    pub endorsing_received: bool,
+}
+
+impl CycleRightsRequest {
+    pub fn is_received(&self) -> bool {
+        self.baking_received && self.endorsing_received
+    }
+
+    /// The rights did not arrive in time, e.g. the rpc failed
+    pub fn is_timed_out(&self, now: u64) -> bool {
+        !self.is_received() && now.saturating_sub(self.requested) >= RIGHTS_REQUEST_TIMEOUT
+    }
+}
+
+impl RightsState {
+    pub fn add_baking(&mut self, raw: &[BakingRightsPerLevel]) {
+        for rights_per_level in raw {
+            let level_rights = self.calendar.entry(rights_per_level.level).or_default();
+            level_rights.baking_priority = Some(
+                level_rights
+                    .baking_priority
+                    .map_or(rights_per_level.priority, |priority| {
+                        priority.min(rights_per_level.priority)
+                    }),
+            );
+            level_rights.baking_round = match (level_rights.baking_round, rights_per_level.round) {
+                (Some(round), Some(other)) => Some(round.min(other)),
+                (round, other) => round.or(other),
+            };
+            level_rights.estimated_time = level_rights
+                .estimated_time
+                .or(rights_per_level.estimated_time);
+        }
+    }
+
+    pub fn add_endorsing(&mut self, raw: &[EndorsementRightsWithTimePerLevel]) {
+        for rights_per_level in raw {
+            let level_rights = self.calendar.entry(rights_per_level.level).or_default();
+            level_rights.endorsing_slots = Some(rights_per_level.slots.len());
+            level_rights.estimated_time = level_rights
+                .estimated_time
+                .or(rights_per_level.estimated_time);
+        }
+    }
+
+    /// Drop the rights below `to_level`, keeping the selection on the same entry
+    pub fn cleanup(&mut self, to_level: i32) {
+        let removed = self
+            .calendar
+            .range(..to_level)
+            .map(|(_, level_rights)| level_rights.entry_count())
+            .sum::<usize>();
+        self.calendar.retain(|level, _| *level >= to_level);
+        self.selected = self
+            .selected
+            .map(|selected| selected.saturating_sub(removed));
+    }
+
+    /// All the rights in level order, baking before endorsing in the same level
+    pub fn entries(&self) -> Vec<CalendarEntry> {
+        self.calendar
+            .iter()
+            .flat_map(|(level, level_rights)| {
+                let baking = level_rights
+                    .baking_priority
+                    .map(|priority| RightKind::Baking {
+                        priority,
+                        round: level_rights.baking_round,
+                    });
+                let endorsing = level_rights
+                    .endorsing_slots
+                    .map(|slots| RightKind::Endorsing { slots });
+                baking
+                    .into_iter()
+                    .chain(endorsing)
+                    .map(move |kind| CalendarEntry {
+                        level: *level,
+                        kind,
+                        estimated_time: level_rights.estimated_time,
+                    })
+            })
+            .collect()
+    }
+}
+
+impl LevelRights {
+    fn entry_count(&self) -> usize {
+        self.baking_priority.iter().count() + self.endorsing_slots.iter().count()
+    }
+}
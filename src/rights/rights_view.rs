@@ -0,0 +1,229 @@
+use time::{format_description, Duration, OffsetDateTime};
+use tui::backend::Backend;
+use tui::layout::{Constraint, Corner, Direction, Layout};
+use tui::style::{Color, Modifier, Style};
+use tui::text::{Span, Spans};
+use tui::widgets::{Block, Borders, Paragraph, Row, Table, TableState};
+use tui::Frame;
+
+use crate::automaton::State;
+use crate::common::{create_header_bar, create_pages_tabs, create_quit, HEADER_HEIGHT};
+use crate::extensions::{CustomSeparator, Renderable, SplitArea};
+
+use super::{CalendarEntry, RightKind};
+
+pub struct RightsScreen {}
+
+impl<B: Backend> Renderable<B> for RightsScreen {
+    fn draw_screen(state: &State, f: &mut Frame<B>) {
+        let size = f.size();
+
+        let background = Block::default().style(Style::default().bg(Color::Rgb(31, 30, 30)));
+        f.render_widget(background, size);
+
+        let page_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(HEADER_HEIGHT),
+                Constraint::Min(5),
+                Constraint::Length(1),
+            ])
+            .split(size);
+
+        let [summary_chunk, calendar_chunk] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(2), Constraint::Min(3)])
+            .split_area(page_chunks[1]);
+
+        let dimmed_text_style = Style::default()
+            .fg(Color::White)
+            .add_modifier(Modifier::DIM);
+        let normal_style = Style::default().fg(Color::White);
+
+        let entries = state.rights.entries();
+
+        // ======================== SUMMARY ========================
+        let summary = if state.baker_address.is_none() {
+            vec![Span::styled(
+                " Start the TUI with --baker-address to see the rights of your baker",
+                dimmed_text_style,
+            )]
+        } else {
+            let (baking_count, endorsing_count) =
+                entries
+                    .iter()
+                    .fold((0, 0), |(baking, endorsing), entry| match entry.kind {
+                        RightKind::Baking { .. } => (baking + 1, endorsing),
+                        RightKind::Endorsing { .. } => (baking, endorsing + 1),
+                    });
+            vec![
+                Span::styled(" RIGHTS CALENDAR - ", normal_style),
+                Span::styled(baking_count.to_string(), normal_style),
+                Span::styled(" baking and ", dimmed_text_style),
+                Span::styled(endorsing_count.to_string(), normal_style),
+                Span::styled(" endorsing rights ahead", dimmed_text_style),
+            ]
+        };
+        let summary_paragraph = Paragraph::new(Spans::from(summary))
+            .block(Block::default().borders(Borders::TOP | Borders::LEFT | Borders::RIGHT));
+        f.render_widget(summary_paragraph, summary_chunk);
+
+        // ======================== CALENDAR ========================
+        let head = &state.current_head_header;
+        let now = OffsetDateTime::from_unix_timestamp_nanos(state.ui.draw_time.into())
+            .unwrap_or(OffsetDateTime::UNIX_EPOCH);
+        let format_desc =
+            format_description::parse("[year]-[month]-[day] [hour]:[minute]:[second]")
+                .unwrap_or_default();
+
+        let cycle_style = Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD);
+        let baking_style = Style::default().fg(Color::Green);
+        let endorsing_style = Style::default().fg(Color::Blue);
+
+        // a cycle title row precedes the rights of each cycle, so the selected entry has to be
+        // shifted by the number of titles above it
+        let mut rows: Vec<Row> = Vec::with_capacity(entries.len());
+        let mut selected_row = None;
+        let mut last_cycle = None;
+        for (index, entry) in entries.iter().enumerate() {
+            let cycle = state
+                .current_head_metadata
+                .level_info
+                .cycle_of(entry.level, state.network_constants.blocks_per_cycle);
+            if rows.is_empty() || cycle != last_cycle {
+                let title = cycle.map_or_else(
+                    || String::from("Cycle -"),
+                    |cycle| format!("Cycle {}", cycle),
+                );
+                rows.push(Row::new(vec![title]).style(cycle_style));
+                last_cycle = cycle;
+            }
+            if state.rights.selected == Some(index) {
+                selected_row = Some(rows.len());
+            }
+
+            let estimated_time = estimated_time(
+                entry,
+                head.level,
+                head.timestamp,
+                state.network_constants.minimal_block_delay,
+            );
+            let (right, value, style) = match entry.kind {
+                RightKind::Baking {
+                    round: Some(round), ..
+                } => ("Baking", format!("round {}", round), baking_style),
+                RightKind::Baking { priority, .. } => {
+                    ("Baking", format!("priority {}", priority), baking_style)
+                }
+                RightKind::Endorsing { slots } => {
+                    ("Endorsing", format!("{} slots", slots), endorsing_style)
+                }
+            };
+
+            rows.push(
+                Row::new(vec![
+                    Span::styled(format!("  {}", entry.level), normal_style),
+                    Span::styled(right, style),
+                    Span::styled(value, dimmed_text_style),
+                    Span::styled(
+                        estimated_time
+                            .and_then(|time| time.format(&format_desc).ok())
+                            .unwrap_or_else(|| String::from("-")),
+                        dimmed_text_style,
+                    ),
+                    Span::styled(
+                        estimated_time
+                            .map(|time| format_countdown(time - now))
+                            .unwrap_or_else(|| String::from("-")),
+                        normal_style,
+                    ),
+                ])
+                .height(1),
+            );
+        }
+
+        let header = Row::new(vec![
+            "Level",
+            "Right",
+            "Priority/Slots",
+            "Estimated Time",
+            "In",
+        ])
+        .style(normal_style)
+        .height(1)
+        .bottom_margin(1);
+
+        let selected_style = Style::default()
+            .remove_modifier(Modifier::DIM)
+            .bg(Color::Black);
+
+        let table = Table::new(rows)
+            .header(header)
+            .block(Block::default().borders(Borders::ALL))
+            .highlight_style(selected_style)
+            .highlight_symbol("▶")
+            .widths(&[
+                Constraint::Length(12),
+                Constraint::Length(10),
+                Constraint::Length(15),
+                Constraint::Length(21),
+                Constraint::Min(12),
+            ]);
+
+        let mut table_state = TableState::default();
+        table_state.select(selected_row);
+        f.render_stateful_widget(table, calendar_chunk, &mut table_state);
+
+        // overlap the block corners with special separators to make flush transition to the table block
+        let vertical_left_separator = CustomSeparator::default()
+            .separator("├")
+            .corner(Corner::TopLeft);
+        f.render_widget(vertical_left_separator, calendar_chunk);
+
+        let vertical_right_separator = CustomSeparator::default()
+            .separator("┤")
+            .corner(Corner::TopRight);
+        f.render_widget(vertical_right_separator, calendar_chunk);
+
+        // ======================== HEADER ========================
+        create_header_bar(page_chunks[0], state, f);
+
+        // ======================== PAGES TABS ========================
+        let tabs = create_pages_tabs(&state.ui);
+        f.render_widget(tabs, page_chunks[2]);
+
+        // ======================== Quit ========================
+        create_quit(page_chunks[2], f);
+    }
+}
+
+/// Time of the right as reported by the node, or extrapolated from the current head
+pub fn estimated_time(
+    entry: &CalendarEntry,
+    head_level: i32,
+    head_timestamp: OffsetDateTime,
+    block_delay: i32,
+) -> Option<OffsetDateTime> {
+    entry.estimated_time.or_else(|| {
+        let level_delta = (entry.level - head_level) as i64;
+        head_timestamp.checked_add(Duration::seconds(level_delta * block_delay as i64))
+    })
+}
+
+/// Countdown string like `2d 03:04:05`, `now` once the time has passed
+pub fn format_countdown(until: Duration) -> String {
+    if !until.is_positive() {
+        return String::from("now");
+    }
+    let days = until.whole_days();
+    let hours = until.whole_hours() % 24;
+    let minutes = until.whole_minutes() % 60;
+    let seconds = until.whole_seconds() % 60;
+    if days > 0 {
+        format!("{}d {:02}:{:02}:{:02}", days, hours, minutes, seconds)
+    } else {
+        format!("{:02}:{:02}:{:02}", hours, minutes, seconds)
+    }
+}
@@ -17,6 +17,22 @@ impl EnablingCondition<State> for RpcRequestAction {
     }
 }
 
+/// The request queue of the rpc service did not accept the call
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcRequestNotSentAction {
+    /// Id of the request action, missing in the recordings of the older versions
+    #[serde(default)]
+    pub id: u64,
+    pub call: RpcCall,
+    pub reason: String,
+}
+
+impl EnablingCondition<State> for RpcRequestNotSentAction {
+    fn is_enabled(&self, _: &State) -> bool {
+        true
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RpcResponseAction {
     pub response: RpcResponse,
@@ -1,16 +1,33 @@
 use crate::{
     automaton::{Action, ActionWithMeta, Store},
-    services::{rpc_service_async::RpcService, Service},
+    services::{
+        rpc_service_async::{RpcRequest, RpcService},
+        Service,
+    },
 };
 
+use super::RpcRequestNotSentAction;
+
 pub fn rpc_effects<S>(store: &mut Store<S>, action: &ActionWithMeta)
 where
     S: Service,
 {
     #[allow(clippy::single_match)]
     match &action.action {
-        Action::RpcRequest(action) => {
-            let _ = store.service().rpc().request_send(action.call.clone());
+        Action::RpcRequest(request) => {
+            // the id of the request action identifies the call in the reports
+            let id = action.id.into();
+            let sent = store.service().rpc().request_send(RpcRequest {
+                id,
+                call: request.call.clone(),
+            });
+            if let Err(e) = sent {
+                store.dispatch(RpcRequestNotSentAction {
+                    id,
+                    call: request.call.clone(),
+                    reason: e.to_string(),
+                });
+            }
         }
         _ => {}
     }
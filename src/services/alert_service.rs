@@ -0,0 +1,238 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    fs::OpenOptions,
+    io::{self, Write},
+    path::PathBuf,
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+use slog::{warn, Logger};
+
+use crate::{
+    alerts::Alert,
+    configuration::{AlertSinkConfig, AlertSinkKind},
+};
+
+pub trait AlertService {
+    /// Hand the fired alert to the alert sinks
+    fn deliver(&mut self, alert: &Alert);
+}
+
+/// Destination of the fired alerts
+pub trait AlertSink {
+    /// Deliver the alert, the delivery can finish in the background
+    fn send(&mut self, alert: &Alert) -> io::Result<()>;
+}
+
+/// POSTs the alert as json, on a background thread so the TUI is not blocked by a slow server
+#[derive(Debug)]
+pub struct WebhookSink {
+    url: String,
+    log: Logger,
+}
+
+impl WebhookSink {
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
+    pub fn new(url: String, log: &Logger) -> Self {
+        Self {
+            url,
+            log: log.clone(),
+        }
+    }
+}
+
+impl AlertSink for WebhookSink {
+    fn send(&mut self, alert: &Alert) -> io::Result<()> {
+        let url = self.url.clone();
+        let alert = alert.clone();
+        let log = self.log.clone();
+        // the blocking client must not run on the tokio runtime
+        thread::Builder::new()
+            .name(String::from("alert-webhook"))
+            .spawn(move || {
+                let result = reqwest::blocking::Client::new()
+                    .post(&url)
+                    .timeout(Self::TIMEOUT)
+                    .json(&alert)
+                    .send()
+                    .and_then(|response| response.error_for_status());
+                if let Err(e) = result {
+                    warn!(log, "Failed to post the alert to {}: {}", url, e);
+                }
+            })?;
+        Ok(())
+    }
+}
+
+/// Runs a shell command with the alert in the `TEZEDGE_ALERT_*` environment variables
+#[derive(Debug)]
+pub struct ExecSink {
+    command: String,
+    log: Logger,
+}
+
+impl ExecSink {
+    pub fn new(command: String, log: &Logger) -> Self {
+        Self {
+            command,
+            log: log.clone(),
+        }
+    }
+}
+
+impl AlertSink for ExecSink {
+    fn send(&mut self, alert: &Alert) -> io::Result<()> {
+        let mut child = Command::new("sh")
+            .arg("-c")
+            .arg(&self.command)
+            .env("TEZEDGE_ALERT_KEY", &alert.key)
+            .env("TEZEDGE_ALERT_RULE", &alert.rule)
+            .env("TEZEDGE_ALERT_MESSAGE", &alert.message)
+            .env("TEZEDGE_ALERT_LEVEL", alert.level.to_string())
+            .env("TEZEDGE_ALERT_FIRED", alert.fired.to_string())
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()?;
+
+        // reap the child without waiting for it
+        let log = self.log.clone();
+        thread::Builder::new()
+            .name(String::from("alert-exec"))
+            .spawn(move || match child.wait() {
+                Ok(status) if !status.success() => {
+                    warn!(log, "Alert command exited with {}", status);
+                }
+                Err(e) => warn!(log, "Failed to wait for the alert command: {}", e),
+                _ => {}
+            })?;
+        Ok(())
+    }
+}
+
+/// Appends the alerts to a file, one json object per line
+#[derive(Debug)]
+pub struct FileSink {
+    path: PathBuf,
+}
+
+impl FileSink {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+}
+
+impl AlertSink for FileSink {
+    fn send(&mut self, alert: &Alert) -> io::Result<()> {
+        let mut line = serde_json::to_vec(alert)?;
+        line.push(b'\n');
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        file.write_all(&line)
+    }
+}
+
+/// Rate limiting and de-duplication of the alerts of a single sink
+#[derive(Debug)]
+pub struct AlertThrottle {
+    max_per_minute: usize,
+    dedup_window: Duration,
+    /// Delivery times within the last minute
+    sent: VecDeque<Instant>,
+    /// Last delivery time of each alert key
+    last_sent: HashMap<String, Instant>,
+}
+
+impl AlertThrottle {
+    const RATE_WINDOW: Duration = Duration::from_secs(60);
+
+    /// Zero `max_per_minute` or `dedup_seconds` turns the respective check off
+    pub fn new(max_per_minute: usize, dedup_seconds: u64) -> Self {
+        Self {
+            max_per_minute,
+            dedup_window: Duration::from_secs(dedup_seconds),
+            sent: VecDeque::new(),
+            last_sent: HashMap::new(),
+        }
+    }
+
+    /// Whether the alert should be delivered at `now`, records the delivery if so
+    pub fn allow(&mut self, alert: &Alert, now: Instant) -> bool {
+        let dedup_window = self.dedup_window;
+        self.last_sent
+            .retain(|_, sent| now.saturating_duration_since(*sent) < dedup_window);
+        if self.last_sent.contains_key(&alert.key) {
+            return false;
+        }
+
+        while let Some(sent) = self.sent.front() {
+            if now.saturating_duration_since(*sent) < Self::RATE_WINDOW {
+                break;
+            }
+            self.sent.pop_front();
+        }
+        if self.max_per_minute > 0 && self.sent.len() >= self.max_per_minute {
+            return false;
+        }
+
+        self.sent.push_back(now);
+        if !dedup_window.is_zero() {
+            self.last_sent.insert(alert.key.clone(), now);
+        }
+        true
+    }
+}
+
+/// Delivers the alerts to the configured sinks
+pub struct AlertServiceDefault {
+    sinks: Vec<(Box<dyn AlertSink>, AlertThrottle)>,
+    log: Logger,
+}
+
+impl AlertServiceDefault {
+    pub fn new(config: &[AlertSinkConfig], log: &Logger) -> Self {
+        let sinks = config
+            .iter()
+            .map(|sink_config| {
+                let sink: Box<dyn AlertSink> = match &sink_config.kind {
+                    AlertSinkKind::Webhook { url } => Box::new(WebhookSink::new(url.clone(), log)),
+                    AlertSinkKind::Exec { command } => {
+                        Box::new(ExecSink::new(command.clone(), log))
+                    }
+                    AlertSinkKind::File { path } => Box::new(FileSink::new(path.clone())),
+                };
+                let throttle =
+                    AlertThrottle::new(sink_config.max_per_minute, sink_config.dedup_seconds);
+                (sink, throttle)
+            })
+            .collect();
+
+        Self {
+            sinks,
+            log: log.clone(),
+        }
+    }
+}
+
+impl AlertService for AlertServiceDefault {
+    fn deliver(&mut self, alert: &Alert) {
+        let now = Instant::now();
+        for (sink, throttle) in &mut self.sinks {
+            if !throttle.allow(alert, now) {
+                continue;
+            }
+            if let Err(e) = sink.send(alert) {
+                warn!(
+                    self.log,
+                    "Failed to deliver alert \"{}\": {}", alert.message, e
+                );
+            }
+        }
+    }
+}
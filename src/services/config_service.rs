@@ -0,0 +1,77 @@
+use std::{fs::File, io, path::PathBuf};
+
+use slog::{warn, Logger};
+use thiserror::Error;
+
+use crate::configuration::TuiConfig;
+
+pub trait ConfigService {
+    fn save(&mut self, config: &TuiConfig);
+}
+
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    #[error("Config file io error: {0}")]
+    Io(#[from] io::Error),
+    #[error("Config file is not valid json: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// Persists the TUI settings as a json file
+#[derive(Debug)]
+pub struct ConfigServiceDefault {
+    path: PathBuf,
+    log: Logger,
+}
+
+impl ConfigServiceDefault {
+    pub fn new(path: PathBuf, log: &Logger) -> Self {
+        Self {
+            path,
+            log: log.clone(),
+        }
+    }
+
+    /// Load the persisted settings, falls back to the defaults when the file is missing or invalid
+    pub fn load(&self) -> TuiConfig {
+        if !self.path.exists() {
+            return TuiConfig::default();
+        }
+
+        match self.read() {
+            Ok(config) => config,
+            Err(e) => {
+                warn!(
+                    self.log,
+                    "Failed to load config {}: {}, using defaults",
+                    self.path.display(),
+                    e
+                );
+                TuiConfig::default()
+            }
+        }
+    }
+
+    fn read(&self) -> Result<TuiConfig, ConfigError> {
+        let file = File::open(&self.path)?;
+        Ok(serde_json::from_reader(file)?)
+    }
+
+    fn write(&self, config: &TuiConfig) -> Result<(), ConfigError> {
+        let file = File::create(&self.path)?;
+        Ok(serde_json::to_writer_pretty(file, config)?)
+    }
+}
+
+impl ConfigService for ConfigServiceDefault {
+    fn save(&mut self, config: &TuiConfig) {
+        if let Err(e) = self.write(config) {
+            warn!(
+                self.log,
+                "Failed to save config {}: {}",
+                self.path.display(),
+                e
+            );
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use std::{
+    fs, io,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+pub trait ExportService {
+    /// Write the exported content into a new file, returns the path of the file
+    fn export(&mut self, name: &str, extension: &str, content: &str) -> io::Result<PathBuf>;
+}
+
+/// Writes the exports into timestamped files in the export directory
+#[derive(Debug)]
+pub struct ExportServiceDefault {
+    dir: PathBuf,
+}
+
+impl ExportServiceDefault {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+}
+
+impl ExportService for ExportServiceDefault {
+    fn export(&mut self, name: &str, extension: &str, content: &str) -> io::Result<PathBuf> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+
+        fs::create_dir_all(&self.dir)?;
+        let path = self
+            .dir
+            .join(format!("tezedge_{}_{}.{}", name, timestamp, extension));
+        fs::write(&path, content)?;
+
+        Ok(path)
+    }
+}
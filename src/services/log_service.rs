@@ -0,0 +1,180 @@
+use std::{
+    collections::VecDeque,
+    fmt::{self, Write},
+    fs::OpenOptions,
+    io,
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+use serde::{Deserialize, Serialize};
+use slog::{Drain, Level, Logger, Never, OwnedKVList, Record, KV};
+use strum_macros::Display;
+use time::OffsetDateTime;
+
+/// Records kept by the buffer and by the log screen, the oldest are dropped first
+pub const LOG_RECORDS_CAPACITY: usize = 1000;
+
+/// Severity of a log record, the most severe first
+#[derive(Debug, Clone, Copy, Display, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Critical,
+    Error,
+    Warning,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    pub fn as_short_str(&self) -> &'static str {
+        Level::from(*self).as_short_str()
+    }
+
+    /// The next level of the level filter, from showing everything to only the critical records
+    pub fn next_filter(&self) -> Self {
+        match self {
+            LogLevel::Trace => LogLevel::Debug,
+            LogLevel::Debug => LogLevel::Info,
+            LogLevel::Info => LogLevel::Warning,
+            LogLevel::Warning => LogLevel::Error,
+            LogLevel::Error => LogLevel::Critical,
+            LogLevel::Critical => LogLevel::Trace,
+        }
+    }
+}
+
+impl Default for LogLevel {
+    fn default() -> Self {
+        LogLevel::Trace
+    }
+}
+
+impl From<Level> for LogLevel {
+    fn from(level: Level) -> Self {
+        match level {
+            Level::Critical => LogLevel::Critical,
+            Level::Error => LogLevel::Error,
+            Level::Warning => LogLevel::Warning,
+            Level::Info => LogLevel::Info,
+            Level::Debug => LogLevel::Debug,
+            Level::Trace => LogLevel::Trace,
+        }
+    }
+}
+
+impl From<LogLevel> for Level {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Critical => Level::Critical,
+            LogLevel::Error => Level::Error,
+            LogLevel::Warning => Level::Warning,
+            LogLevel::Info => Level::Info,
+            LogLevel::Debug => Level::Debug,
+            LogLevel::Trace => Level::Trace,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LogRecord {
+    #[serde(with = "time::serde::rfc3339")]
+    pub time: OffsetDateTime,
+    pub level: LogLevel,
+    /// Module the record was logged from
+    pub module: String,
+    /// The message followed by the key-value pairs of the record
+    pub message: String,
+}
+
+/// Drain keeping the last records in memory for the log screen
+#[derive(Debug, Clone, Default)]
+pub struct LogBuffer {
+    records: Arc<Mutex<VecDeque<LogRecord>>>,
+}
+
+impl LogBuffer {
+    /// Take the records logged since the last call, the oldest first
+    pub fn take(&self) -> Vec<LogRecord> {
+        match self.records.lock() {
+            Ok(mut records) => records.drain(..).collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+}
+
+impl Drain for LogBuffer {
+    type Ok = ();
+    type Err = Never;
+
+    fn log(&self, record: &Record, values: &OwnedKVList) -> Result<(), Never> {
+        let mut message = KvFormatter(record.msg().to_string());
+        // formatting to a string does not fail
+        let _ = record.kv().serialize(record, &mut message);
+        let _ = values.serialize(record, &mut message);
+
+        let record = LogRecord {
+            time: OffsetDateTime::now_utc(),
+            level: record.level().into(),
+            module: record.module().to_string(),
+            message: message.0,
+        };
+        if let Ok(mut records) = self.records.lock() {
+            if records.len() == LOG_RECORDS_CAPACITY {
+                records.pop_front();
+            }
+            records.push_back(record);
+        }
+        Ok(())
+    }
+}
+
+/// Appends the key-value pairs to the message
+struct KvFormatter(String);
+
+impl slog::Serializer for KvFormatter {
+    fn emit_arguments(&mut self, key: slog::Key, val: &fmt::Arguments) -> slog::Result {
+        let _ = write!(self.0, ", {}: {}", key, val);
+        Ok(())
+    }
+}
+
+pub trait LogService {
+    /// Take the records logged since the last call, the oldest first
+    fn take_records(&mut self) -> Vec<LogRecord>;
+}
+
+pub struct LogServiceDefault {
+    /// Logs to the log file and to the buffer of the log screen
+    pub log: Logger,
+    buffer: LogBuffer,
+}
+
+impl LogServiceDefault {
+    /// Create the logger writing to the file, the file is truncated unless `append` is set
+    pub fn create(path: &Path, append: bool) -> io::Result<Self> {
+        let file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(append)
+            .truncate(!append)
+            .open(path)?;
+
+        let decorator = slog_term::PlainDecorator::new(file);
+        let file_drain = slog_term::FullFormat::new(decorator).build().fuse();
+        let buffer = LogBuffer::default();
+        let drain = slog::Duplicate::new(file_drain, buffer.clone()).fuse();
+        let drain = slog_async::Async::new(drain).build().fuse();
+
+        Ok(Self {
+            log: Logger::root(drain, slog::o!()),
+            buffer,
+        })
+    }
+}
+
+impl LogService for LogServiceDefault {
+    fn take_records(&mut self) -> Vec<LogRecord> {
+        self.buffer.take()
+    }
+}
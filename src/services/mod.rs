@@ -10,11 +10,27 @@ pub mod ws_service;
 
 pub mod tui_service;
 
+pub mod config_service;
+
+pub mod export_service;
+
+pub mod alert_service;
+
+pub mod traffic_service;
+
+pub mod log_service;
+
 pub mod service_async_channel;
 pub use service_async_channel::*;
 use tui::backend::{Backend, CrosstermBackend};
 
+use crate::automaton::ActionRecorder;
+
 use self::{
+    alert_service::{AlertService, AlertServiceDefault},
+    config_service::{ConfigService, ConfigServiceDefault},
+    export_service::{ExportService, ExportServiceDefault},
+    log_service::{LogService, LogServiceDefault},
     // rpc_service::{RpcService, RpcServiceDefault},
     rpc_service_async::{RpcService, RpcServiceDefault},
     tui_service::{TuiService, TuiServiceDefault},
@@ -26,25 +42,45 @@ pub trait Service: TimeService {
     type Rpc: RpcService;
     type Tui: TuiService;
     type Ws: WebsocketService;
+    type Config: ConfigService;
+    type Export: ExportService;
+    type Alert: AlertService;
+    type Logs: LogService;
 
     fn rpc(&mut self) -> &mut Self::Rpc;
     fn tui(&mut self) -> &mut Self::Tui;
     fn ws(&mut self) -> &mut Self::Ws;
+    fn config(&mut self) -> &mut Self::Config;
+    fn export(&mut self) -> &mut Self::Export;
+    fn alert(&mut self) -> &mut Self::Alert;
+    fn logs(&mut self) -> &mut Self::Logs;
+    /// Records the dispatched actions, `None` unless recording
+    fn action_recorder(&mut self) -> Option<&ActionRecorder>;
 }
 
-pub struct ServiceDefault {
-    pub rpc: RpcServiceDefault,
+/// The services of the tui, the node data comes from the live node by default or from a traffic replay
+pub struct ServiceDefault<Rpc = RpcServiceDefault, Ws = WebsocketServiceDefault> {
+    pub rpc: Rpc,
     pub tui: TuiServiceDefault,
-    pub ws: WebsocketServiceDefault,
+    pub ws: Ws,
+    pub config: ConfigServiceDefault,
+    pub export: ExportServiceDefault,
+    pub alert: AlertServiceDefault,
+    pub logs: LogServiceDefault,
+    pub action_recorder: Option<ActionRecorder>,
 }
 
-impl TimeService for ServiceDefault {}
+impl<Rpc, Ws> TimeService for ServiceDefault<Rpc, Ws> {}
 
-impl Service for ServiceDefault {
+impl<Rpc: RpcService, Ws: WebsocketService> Service for ServiceDefault<Rpc, Ws> {
     type Be = CrosstermBackend<Stdout>;
-    type Rpc = RpcServiceDefault;
+    type Rpc = Rpc;
     type Tui = TuiServiceDefault;
-    type Ws = WebsocketServiceDefault;
+    type Ws = Ws;
+    type Config = ConfigServiceDefault;
+    type Export = ExportServiceDefault;
+    type Alert = AlertServiceDefault;
+    type Logs = LogServiceDefault;
 
     fn rpc(&mut self) -> &mut Self::Rpc {
         &mut self.rpc
@@ -55,4 +91,19 @@ impl Service for ServiceDefault {
     fn ws(&mut self) -> &mut Self::Ws {
         &mut self.ws
     }
+    fn config(&mut self) -> &mut Self::Config {
+        &mut self.config
+    }
+    fn export(&mut self) -> &mut Self::Export {
+        &mut self.export
+    }
+    fn alert(&mut self) -> &mut Self::Alert {
+        &mut self.alert
+    }
+    fn logs(&mut self) -> &mut Self::Logs {
+        &mut self.logs
+    }
+    fn action_recorder(&mut self) -> Option<&ActionRecorder> {
+        self.action_recorder.as_ref()
+    }
 }
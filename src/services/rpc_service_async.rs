@@ -1,4 +1,4 @@
-use std::fmt::Display;
+use std::{collections::VecDeque, fmt::Display, time::Instant};
 
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
@@ -14,173 +14,293 @@ use crate::{
         EndorsementRights, EndorsementRightsWithTimePerLevel, EndorsementStatuses,
         MempoolEndorsementStats,
     },
+    governance::Proposal,
     operations::OperationsStats,
+    trends::{mempool_size, PendingOperations},
 };
 
-use super::RequestTrySendError;
+use super::{
+    traffic_service::{TrafficEvent, TrafficRecorder},
+    RequestTrySendError,
+};
 
 pub type RpcRecvError = mpsc::error::TryRecvError;
 
+/// Bytes of the response body kept in a [RpcCallReport]
+pub const RPC_PREVIEW_LENGTH: usize = 1024;
+
+/// Replayed calls waiting for a recorded response, the oldest are dropped beyond this
+const REPLAY_PENDING_CAPACITY: usize = 256;
+
 #[async_trait]
 pub trait RpcService {
-    fn request_send(&mut self, req: RpcCall) -> Result<(), RequestTrySendError<RpcCall>>;
+    fn request_send(&mut self, req: RpcRequest) -> Result<(), RequestTrySendError<RpcRequest>>;
     async fn response_recv(&mut self) -> Option<RpcResponse>;
+    /// Take the reports of the calls finished since the last call, the oldest first
+    fn take_reports(&mut self) -> Vec<RpcCallReport>;
 }
 
 #[derive(Debug)]
 pub struct RpcServiceDefault {
-    sender: mpsc::Sender<RpcCall>,
+    sender: mpsc::Sender<RpcRequest>,
     receiver: mpsc::Receiver<RpcResponse>,
+    report_receiver: mpsc::UnboundedReceiver<RpcCallReport>,
     _url: Url,
+    recorder: Option<TrafficRecorder>,
 }
 
 impl RpcServiceDefault {
     pub fn new(bound: usize, url: Url, log: &Logger) -> Self {
         let (call_tx, call_rx) = mpsc::channel(bound);
         let (response_tx, response_rx) = mpsc::channel(bound);
+        // the reports are taken on every tick, they do not pile up
+        let (report_tx, report_rx) = mpsc::unbounded_channel();
 
         let t_url = url.clone();
         let t_log = log.clone();
 
-        tokio::task::spawn(
-            async move { Self::run_worker(call_rx, response_tx, &t_url, &t_log).await },
-        );
+        tokio::task::spawn(async move {
+            Self::run_worker(call_rx, response_tx, report_tx, &t_url, &t_log).await
+        });
 
         Self {
             sender: call_tx,
             receiver: response_rx,
+            report_receiver: report_rx,
             _url: url,
+            recorder: None,
         }
     }
+
+    /// Record the received responses
+    pub fn with_recorder(mut self, recorder: TrafficRecorder) -> Self {
+        self.recorder = Some(recorder);
+        self
+    }
 }
 
 #[async_trait]
 impl RpcService for RpcServiceDefault {
-    fn request_send(&mut self, req: RpcCall) -> Result<(), RequestTrySendError<RpcCall>> {
+    fn request_send(&mut self, req: RpcRequest) -> Result<(), RequestTrySendError<RpcRequest>> {
         self.sender.try_send(req)
     }
 
     async fn response_recv(&mut self) -> Option<RpcResponse> {
-        self.receiver.recv().await
+        let response = self.receiver.recv().await;
+        if let (Some(recorder), Some(response)) = (&self.recorder, &response) {
+            recorder.record(TrafficEvent::Rpc(response.clone()));
+        }
+        response
+    }
+
+    fn take_reports(&mut self) -> Vec<RpcCallReport> {
+        let mut reports = Vec::new();
+        while let Ok(report) = self.report_receiver.try_recv() {
+            reports.push(report);
+        }
+        reports
+    }
+}
+
+/// Serves the responses of a traffic recording, the requests are only kept for the reports
+#[derive(Debug)]
+pub struct RpcServiceReplay {
+    receiver: mpsc::Receiver<RpcResponse>,
+    /// Requests waiting for a recorded response, the oldest first
+    pending: VecDeque<(RpcRequest, Instant)>,
+    reports: Vec<RpcCallReport>,
+}
+
+impl RpcServiceReplay {
+    pub fn new(receiver: mpsc::Receiver<RpcResponse>) -> Self {
+        Self {
+            receiver,
+            pending: VecDeque::new(),
+            reports: Vec::new(),
+        }
+    }
+
+    /// Report the response as the answer to the oldest request of its target
+    fn report(&mut self, response: &RpcResponse) {
+        let target = response.target();
+        let position = self
+            .pending
+            .iter()
+            .position(|(request, _)| request.call.target == target);
+        if let Some((request, sent)) = position.and_then(|position| self.pending.remove(position))
+        {
+            // the recording keeps the parsed response, not the body sent by the node
+            let body = serde_json::to_vec(response).unwrap_or_default();
+            self.reports.push(RpcCallReport {
+                id: request.id,
+                call: request.call,
+                latency: sent.elapsed().as_nanos() as u64,
+                status: Some(200),
+                size: body.len(),
+                preview: String::from_utf8_lossy(&body[..body.len().min(RPC_PREVIEW_LENGTH)])
+                    .into_owned(),
+                error: None,
+            });
+        }
+    }
+}
+
+#[async_trait]
+impl RpcService for RpcServiceReplay {
+    fn request_send(&mut self, req: RpcRequest) -> Result<(), RequestTrySendError<RpcRequest>> {
+        self.pending.push_back((req, Instant::now()));
+        if self.pending.len() > REPLAY_PENDING_CAPACITY {
+            self.pending.pop_front();
+        }
+        Ok(())
+    }
+
+    async fn response_recv(&mut self) -> Option<RpcResponse> {
+        match self.receiver.recv().await {
+            Some(response) => {
+                self.report(&response);
+                Some(response)
+            }
+            // the recording is over, nothing more will arrive
+            None => std::future::pending().await,
+        }
+    }
+
+    fn take_reports(&mut self) -> Vec<RpcCallReport> {
+        std::mem::take(&mut self.reports)
     }
 }
 
 impl RpcServiceDefault {
     async fn run_worker(
-        mut call_receiver: mpsc::Receiver<RpcCall>,
+        mut call_receiver: mpsc::Receiver<RpcRequest>,
         response_sender: mpsc::Sender<RpcResponse>,
+        report_sender: mpsc::UnboundedSender<RpcCallReport>,
         url: &Url,
         log: &Logger,
     ) {
         info!(log, "Rpc service started. Rpc url: {}", url);
-        while let Some(req) = call_receiver.recv().await {
-            match Self::call_rpc(req, url).await {
+        while let Some(RpcRequest { id, call }) = call_receiver.recv().await {
+            let started = Instant::now();
+            let fetched = Self::fetch(&call, url).await;
+            let mut report = RpcCallReport {
+                id,
+                call: call.clone(),
+                latency: started.elapsed().as_nanos() as u64,
+                status: None,
+                size: 0,
+                preview: String::new(),
+                error: None,
+            };
+
+            let response = fetched.and_then(|(status, body)| {
+                report.status = Some(status);
+                report.size = body.len();
+                report.preview =
+                    String::from_utf8_lossy(&body[..body.len().min(RPC_PREVIEW_LENGTH)])
+                        .into_owned();
+                Self::parse(&call, &body)
+            });
+            match response {
                 Ok(response) => {
                     let _ = response_sender.send(response).await;
                 }
                 Err(e) => {
-                    warn!(log, "Rpc failed: {}", e)
+                    warn!(log, "Rpc failed: {}", e);
+                    report.error = Some(e.to_string());
                 }
             };
+            // only fails when the service is dropped
+            let _ = report_sender.send(report);
         }
     }
 
-    async fn call_rpc(request: RpcCall, url: &Url) -> Result<RpcResponse, RpcError> {
-        let mut url = url.join(request.to_url()).unwrap();
-        if let Some(query) = request.query_arg.clone() {
-            url = url.join(&query).unwrap();
+    /// Call the rpc, returning the http status and the body of the response
+    async fn fetch(call: &RpcCall, url: &Url) -> Result<(u16, Vec<u8>), RpcError> {
+        let mut url = url.join(call.to_url())?;
+        if let Some(query) = &call.query_arg {
+            url = url.join(query)?;
         }
 
         let response = reqwest::get(url)
             .await
-            .map_err(|e| RpcError::RequestErrorDetailed(request.clone(), e))?;
+            .map_err(|e| RpcError::RequestErrorDetailed(call.clone(), e))?;
+        let status = response.status().as_u16();
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| RpcError::RequestErrorDetailed(call.clone(), e))?;
+
+        Ok((status, body.to_vec()))
+    }
 
-        match request.target {
+    fn parse(call: &RpcCall, body: &[u8]) -> Result<RpcResponse, RpcError> {
+        let parsed = match call.target {
             RpcTarget::EndorsementRights => {
-                let rights: EndorsementRights = response
-                    .json()
-                    .await
-                    .map_err(|e| RpcError::RequestErrorDetailed(request, e))?;
-                Ok(RpcResponse::EndorsementRights(rights))
+                serde_json::from_slice(body).map(RpcResponse::EndorsementRights)
             }
             RpcTarget::CurrentHeadHeader => {
-                let header: CurrentHeadHeader = response
-                    .json()
-                    .await
-                    .map_err(|e| RpcError::RequestErrorDetailed(request, e))?;
-                Ok(RpcResponse::CurrentHeadHeader(header))
+                serde_json::from_slice(body).map(RpcResponse::CurrentHeadHeader)
             }
             RpcTarget::EndersementsStatus => {
-                let statuses: EndorsementStatuses = response
-                    .json()
-                    .await
-                    .map_err(|e| RpcError::RequestErrorDetailed(request, e))?;
-                Ok(RpcResponse::EndorsementsStatus(statuses))
+                serde_json::from_slice(body).map(RpcResponse::EndorsementsStatus)
             }
             RpcTarget::OperationsStats => {
-                let stats: OperationsStats = response
-                    .json()
-                    .await
-                    .map_err(|e| RpcError::RequestErrorDetailed(request, e))?;
-                Ok(RpcResponse::OperationsStats(stats))
+                serde_json::from_slice(body).map(RpcResponse::OperationsStats)
             }
             RpcTarget::ApplicationStatistics => {
-                let stats: Vec<BlockApplicationStatistics> = response
-                    .json()
-                    .await
-                    .map_err(|e| RpcError::RequestErrorDetailed(request, e))?;
-                Ok(RpcResponse::ApplicationStatistics(stats))
+                let level = Self::query_value(call, "level")?;
+                serde_json::from_slice(body)
+                    .map(|stats| RpcResponse::ApplicationStatistics(level, stats))
             }
             RpcTarget::PerPeerBlockStatistics => {
-                let stats: PerPeerBlockStatisticsVector = response
-                    .json()
-                    .await
-                    .map_err(|e| RpcError::RequestErrorDetailed(request, e))?;
-                Ok(RpcResponse::PerPeerBlockStatistics(stats))
+                let level = Self::query_value(call, "level")?;
+                serde_json::from_slice(body)
+                    .map(|stats| RpcResponse::PerPeerBlockStatistics(level, stats))
             }
+            RpcTarget::LevelApplicationStatistics(level) => serde_json::from_slice(body)
+                .map(|stats| RpcResponse::LevelApplicationStatistics(level, stats)),
+            RpcTarget::LevelPerPeerBlockStatistics(level) => serde_json::from_slice(body)
+                .map(|stats| RpcResponse::LevelPerPeerBlockStatistics(level, stats)),
             RpcTarget::BakingRights => {
-                let rights: Vec<BakingRightsPerLevel> = response
-                    .json()
-                    .await
-                    .map_err(|e| RpcError::RequestErrorDetailed(request, e))?;
-                Ok(RpcResponse::BakingRights(rights))
+                let cycle = Self::query_value(call, "cycle")?;
+                serde_json::from_slice(body).map(|rights| RpcResponse::BakingRights(cycle, rights))
             }
             RpcTarget::EndorsementRightsWithTime => {
-                let rights: Vec<EndorsementRightsWithTimePerLevel> = response
-                    .json()
-                    .await
-                    .map_err(|e| RpcError::RequestErrorDetailed(request, e))?;
-                Ok(RpcResponse::EndorsementRightsWithTime(rights))
+                let cycle = Self::query_value(call, "cycle")?;
+                serde_json::from_slice(body)
+                    .map(|rights| RpcResponse::EndorsementRightsWithTime(cycle, rights))
             }
             RpcTarget::MempoolEndorsementStats => {
-                let stats: MempoolEndorsementStats = response
-                    .json()
-                    .await
-                    .map_err(|e| RpcError::RequestErrorDetailed(request, e))?;
-                Ok(RpcResponse::MempoolEndorsementStats(stats))
+                serde_json::from_slice(body).map(RpcResponse::MempoolEndorsementStats)
             }
             RpcTarget::NetworkConstants => {
-                let constants: NetworkConstants = response
-                    .json()
-                    .await
-                    .map_err(|e| RpcError::RequestErrorDetailed(request, e))?;
-                Ok(RpcResponse::NetworkConstants(constants))
+                serde_json::from_slice(body).map(RpcResponse::NetworkConstants)
             }
             RpcTarget::CurrentHeadMetadata => {
-                let metadata: CurrentHeadMetadata = response
-                    .json()
-                    .await
-                    .map_err(|e| RpcError::RequestErrorDetailed(request, e))?;
-                Ok(RpcResponse::CurrentHeadMetadata(metadata))
+                serde_json::from_slice(body).map(RpcResponse::CurrentHeadMetadata)
             }
             RpcTarget::BestRemoteLevel => {
-                let level: Option<i32> = response
-                    .json()
-                    .await
-                    .map_err(|e| RpcError::RequestErrorDetailed(request, e))?;
-                Ok(RpcResponse::BestRemoteLevel(level))
+                serde_json::from_slice(body).map(RpcResponse::BestRemoteLevel)
             }
-        }
+            RpcTarget::Proposals => serde_json::from_slice(body).map(RpcResponse::Proposals),
+            RpcTarget::PendingOperations => serde_json::from_slice::<PendingOperations>(body)
+                .map(|pending| RpcResponse::PendingOperations(mempool_size(&pending))),
+        };
+        parsed.map_err(|e| RpcError::DeserializationError(call.clone(), e))
+    }
+
+    /// Cycle or level the call was made for, the responses carry it
+    fn query_value(call: &RpcCall, name: &'static str) -> Result<i32, RpcError> {
+        call.query_arg()
+            .and_then(|query| {
+                query.trim_start_matches('?').split('&').find_map(|pair| {
+                    pair.strip_prefix(name)
+                        .and_then(|value| value.strip_prefix('='))
+                })
+            })
+            .and_then(|value| value.parse().ok())
+            .ok_or_else(|| RpcError::MissingQueryArgument(call.clone(), name))
     }
 }
 
@@ -190,11 +310,38 @@ pub enum RpcError {
     UrlParseError(#[from] url::ParseError),
     #[error("Error while calling RPC {0}: {1}")]
     RequestErrorDetailed(RpcCall, reqwest::Error),
-    #[error("Error while desierializing RPC response: {0}")]
-    DeserializationError(#[from] serde_json::Error),
+    #[error("Error while desierializing RPC {0} response: {1}")]
+    DeserializationError(RpcCall, serde_json::Error),
+    #[error("RPC {0} is missing the {1} query argument")]
+    MissingQueryArgument(RpcCall, &'static str),
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+/// Outcome of a rpc call, failed calls are reported too
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct RpcCallReport {
+    /// Id of the request, missing in the recordings of the older versions
+    #[serde(default)]
+    pub id: u64,
+    pub call: RpcCall,
+    /// Time from sending the request to reading the whole body, in nanoseconds
+    pub latency: u64,
+    /// Http status, `None` when no response was received
+    pub status: Option<u16>,
+    /// Size of the body in bytes
+    pub size: usize,
+    /// Beginning of the body
+    pub preview: String,
+    pub error: Option<String>,
+}
+
+/// A call tagged with the id of its request action, the report of the call carries the id
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct RpcRequest {
+    pub id: u64,
+    pub call: RpcCall,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct RpcCall {
     pub target: RpcTarget,
     query_arg: Option<String>,
@@ -204,9 +351,13 @@ impl RpcCall {
     pub fn new(target: RpcTarget, query_arg: Option<String>) -> Self {
         Self { target, query_arg }
     }
+
+    pub fn query_arg(&self) -> Option<&str> {
+        self.query_arg.as_deref()
+    }
 }
 
-#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub enum RpcTarget {
     EndorsementRights,
     EndersementsStatus,
@@ -214,12 +365,17 @@ pub enum RpcTarget {
     OperationsStats,
     ApplicationStatistics,
     PerPeerBlockStatistics,
+    /// Statistics of a past level, the responses carry the level
+    LevelApplicationStatistics(i32),
+    LevelPerPeerBlockStatistics(i32),
     BakingRights,
     EndorsementRightsWithTime,
     MempoolEndorsementStats,
     NetworkConstants,
     CurrentHeadMetadata,
     BestRemoteLevel,
+    Proposals,
+    PendingOperations,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -229,14 +385,49 @@ pub enum RpcResponse {
     EndorsementsStatus(EndorsementStatuses),
     CurrentHeadHeader(CurrentHeadHeader),
     OperationsStats(OperationsStats),
-    ApplicationStatistics(Vec<BlockApplicationStatistics>),
-    PerPeerBlockStatistics(PerPeerBlockStatisticsVector),
-    BakingRights(Vec<BakingRightsPerLevel>),
-    EndorsementRightsWithTime(Vec<EndorsementRightsWithTimePerLevel>),
+    /// Statistics of the head, the responses carry the requested level
+    ApplicationStatistics(i32, Vec<BlockApplicationStatistics>),
+    PerPeerBlockStatistics(i32, PerPeerBlockStatisticsVector),
+    LevelApplicationStatistics(i32, Vec<BlockApplicationStatistics>),
+    LevelPerPeerBlockStatistics(i32, PerPeerBlockStatisticsVector),
+    /// Rights of a cycle, the responses carry the cycle
+    BakingRights(i32, Vec<BakingRightsPerLevel>),
+    EndorsementRightsWithTime(i32, Vec<EndorsementRightsWithTimePerLevel>),
     MempoolEndorsementStats(MempoolEndorsementStats),
     NetworkConstants(NetworkConstants),
     CurrentHeadMetadata(CurrentHeadMetadata),
     BestRemoteLevel(Option<i32>),
+    Proposals(Vec<Proposal>),
+    /// Number of the operations waiting in the mempool, only the number is kept from the response
+    PendingOperations(usize),
+}
+
+impl RpcResponse {
+    /// Target of the call answered by the response
+    pub fn target(&self) -> RpcTarget {
+        match self {
+            RpcResponse::EndorsementRights(_) => RpcTarget::EndorsementRights,
+            RpcResponse::EndorsementsStatus(_) => RpcTarget::EndersementsStatus,
+            RpcResponse::CurrentHeadHeader(_) => RpcTarget::CurrentHeadHeader,
+            RpcResponse::OperationsStats(_) => RpcTarget::OperationsStats,
+            RpcResponse::ApplicationStatistics(..) => RpcTarget::ApplicationStatistics,
+            RpcResponse::PerPeerBlockStatistics(..) => RpcTarget::PerPeerBlockStatistics,
+            RpcResponse::LevelApplicationStatistics(level, _) => {
+                RpcTarget::LevelApplicationStatistics(*level)
+            }
+            RpcResponse::LevelPerPeerBlockStatistics(level, _) => {
+                RpcTarget::LevelPerPeerBlockStatistics(*level)
+            }
+            RpcResponse::BakingRights(..) => RpcTarget::BakingRights,
+            RpcResponse::EndorsementRightsWithTime(..) => RpcTarget::EndorsementRightsWithTime,
+            RpcResponse::MempoolEndorsementStats(_) => RpcTarget::MempoolEndorsementStats,
+            RpcResponse::NetworkConstants(_) => RpcTarget::NetworkConstants,
+            RpcResponse::CurrentHeadMetadata(_) => RpcTarget::CurrentHeadMetadata,
+            RpcResponse::BestRemoteLevel(_) => RpcTarget::BestRemoteLevel,
+            RpcResponse::Proposals(_) => RpcTarget::Proposals,
+            RpcResponse::PendingOperations(_) => RpcTarget::PendingOperations,
+        }
+    }
 }
 
 impl Display for RpcCall {
@@ -268,6 +459,20 @@ impl Display for RpcCall {
                     self.query_arg
                 )
             }
+            RpcTarget::LevelApplicationStatistics(level) => {
+                write!(
+                    f,
+                    "LevelApplicationStatistics {} - Query args: {:?}",
+                    level, self.query_arg
+                )
+            }
+            RpcTarget::LevelPerPeerBlockStatistics(level) => {
+                write!(
+                    f,
+                    "LevelPerPeerBlockStatistics {} - Query args: {:?}",
+                    level, self.query_arg
+                )
+            }
             RpcTarget::BakingRights => {
                 write!(f, "BakingRights - Query args: {:?}", self.query_arg)
             }
@@ -294,6 +499,12 @@ impl Display for RpcCall {
             RpcTarget::BestRemoteLevel => {
                 write!(f, "BestRemoteLevel - Query args: {:?}", self.query_arg)
             }
+            RpcTarget::Proposals => {
+                write!(f, "Proposals - Query args: {:?}", self.query_arg)
+            }
+            RpcTarget::PendingOperations => {
+                write!(f, "PendingOperations - Query args: {:?}", self.query_arg)
+            }
         }
     }
 }
@@ -305,10 +516,12 @@ impl RpcCall {
             RpcTarget::EndersementsStatus => "dev/shell/automaton/endorsements_status",
             RpcTarget::CurrentHeadHeader => "chains/main/blocks/head/header",
             RpcTarget::OperationsStats => "dev/shell/automaton/mempool/operation_stats",
-            RpcTarget::ApplicationStatistics => {
+            RpcTarget::ApplicationStatistics | RpcTarget::LevelApplicationStatistics(_) => {
                 "dev/shell/automaton/stats/current_head/application"
             }
-            RpcTarget::PerPeerBlockStatistics => "dev/shell/automaton/stats/current_head/peers",
+            RpcTarget::PerPeerBlockStatistics | RpcTarget::LevelPerPeerBlockStatistics(_) => {
+                "dev/shell/automaton/stats/current_head/peers"
+            }
             RpcTarget::BakingRights => "chains/main/blocks/head/helpers/baking_rights",
             RpcTarget::EndorsementRightsWithTime => {
                 "chains/main/blocks/head/helpers/endorsing_rights"
@@ -317,6 +530,8 @@ impl RpcCall {
             RpcTarget::NetworkConstants => "chains/main/blocks/head/context/constants",
             RpcTarget::CurrentHeadMetadata => "chains/main/blocks/head/metadata",
             RpcTarget::BestRemoteLevel => "dev/peers/best_remote_level",
+            RpcTarget::Proposals => "chains/main/blocks/head/votes/proposals",
+            RpcTarget::PendingOperations => "chains/main/mempool/pending_operations",
         }
     }
 }
@@ -362,24 +577,109 @@ impl Default for CurrentHeadHeader {
     }
 }
 
+/// Protocol constants, the ones missing in the running protocol are left empty
 #[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
 pub struct NetworkConstants {
-    // we only need this one for now
     #[serde(deserialize_with = "serde_aux::prelude::deserialize_number_from_string")]
     pub minimal_block_delay: i32,
     pub preserved_cycles: i32,
+    #[serde(default)]
+    pub blocks_per_cycle: i32,
+    #[serde(default)]
+    pub blocks_per_voting_period: Option<i32>,
+    #[serde(default)]
+    pub cycles_per_voting_period: Option<i32>,
+    /// Endorsement slots per block before Tenderbake
+    #[serde(default)]
+    pub endorsers_per_block: Option<u32>,
+    /// Endorsement slots per block since Tenderbake
+    #[serde(default)]
+    pub consensus_committee_size: Option<u32>,
+    #[serde(default)]
+    #[serde(deserialize_with = "serde_aux::prelude::deserialize_option_number_from_string")]
+    pub delay_per_missed_endorsement: Option<i64>,
+    #[serde(default)]
+    #[serde(deserialize_with = "deserialize_numbers_from_strings")]
+    pub time_between_blocks: Vec<i64>,
+}
+
+impl NetworkConstants {
+    /// Endorsement slots per block regardless of the protocol
+    pub fn endorsement_slots(&self) -> Option<u32> {
+        self.consensus_committee_size.or(self.endorsers_per_block)
+    }
+
+    /// Length of the voting period in blocks regardless of the protocol
+    pub fn voting_period_length(&self) -> Option<i32> {
+        self.blocks_per_voting_period.or_else(|| {
+            self.cycles_per_voting_period
+                .map(|cycles| cycles * self.blocks_per_cycle)
+        })
+    }
+}
+
+fn deserialize_numbers_from_strings<'de, D>(deserializer: D) -> Result<Vec<i64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    // the node sends strings, a traffic recording stores the parsed numbers
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum NumberOrString {
+        Number(i64),
+        String(String),
+    }
+
+    let raw: Vec<NumberOrString> = Deserialize::deserialize(deserializer)?;
+    raw.into_iter()
+        .map(|number| match number {
+            NumberOrString::Number(number) => Ok(number),
+            NumberOrString::String(number) => number.parse().map_err(serde::de::Error::custom),
+        })
+        .collect()
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
 pub struct CurrentHeadMetadata {
+    #[serde(default)]
+    pub protocol: String,
+    #[serde(default)]
+    pub next_protocol: String,
     pub level_info: LevelInfo,
+    #[serde(default)]
+    pub voting_period_info: Option<VotingPeriodInfo>,
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
 pub struct LevelInfo {
     pub cycle: i32,
-    cycle_position: i32,
+    pub cycle_position: i32,
     expected_commitment: bool,
     pub level: i32,
     level_position: i32,
 }
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct VotingPeriodInfo {
+    pub voting_period: VotingPeriod,
+    pub position: i32,
+    pub remaining: i32,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct VotingPeriod {
+    pub index: i32,
+    pub kind: String,
+    pub start_position: i32,
+}
+
+impl LevelInfo {
+    /// Cycle the `level` belongs to, `None` until the cycle length is known
+    pub fn cycle_of(&self, level: i32, blocks_per_cycle: i32) -> Option<i32> {
+        if blocks_per_cycle <= 0 {
+            return None;
+        }
+        let position = self.cycle_position + (level - self.level);
+        Some(self.cycle + position.div_euclid(blocks_per_cycle))
+    }
+}
@@ -0,0 +1,201 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    path::Path,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+use slog::{info, warn, Logger};
+use thiserror::Error;
+use tokio::sync::mpsc;
+
+use super::{
+    rpc_service_async::{RpcResponse, RpcServiceReplay},
+    ws_service::{WebsocketMessage, WebsocketServiceReplay},
+};
+
+#[derive(Error, Debug)]
+pub enum TrafficError {
+    #[error("Traffic recording io error: {0}")]
+    Io(#[from] io::Error),
+    #[error("Traffic record is not valid json: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("Invalid traffic record on line {line}: {source}")]
+    Record {
+        line: usize,
+        source: serde_json::Error,
+    },
+}
+
+/// Response or message received from the node
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(clippy::large_enum_variant)]
+pub enum TrafficEvent {
+    Rpc(RpcResponse),
+    Websocket(Vec<WebsocketMessage>),
+}
+
+/// A line of the traffic recording
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrafficRecord {
+    /// Time the event was received, in nanoseconds since the unix epoch
+    pub time: u64,
+    pub event: TrafficEvent,
+}
+
+/// Writes the received traffic to a file, a json record per line, shared by the rpc and the websocket services
+#[derive(Debug, Clone)]
+pub struct TrafficRecorder {
+    writer: Arc<Mutex<BufWriter<File>>>,
+    log: Logger,
+}
+
+impl TrafficRecorder {
+    pub fn create(path: &Path, log: &Logger) -> Result<Self, TrafficError> {
+        let file = File::create(path)?;
+        info!(log, "Recording the traffic to {}", path.display());
+
+        Ok(Self {
+            writer: Arc::new(Mutex::new(BufWriter::new(file))),
+            log: log.clone(),
+        })
+    }
+
+    pub fn record(&self, event: TrafficEvent) {
+        let time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|since_epoch| since_epoch.as_nanos() as u64)
+            .unwrap_or_default();
+
+        if let Err(e) = self.write(&TrafficRecord { time, event }) {
+            warn!(self.log, "Failed to record the traffic: {}", e);
+        }
+    }
+
+    fn write(&self, record: &TrafficRecord) -> Result<(), TrafficError> {
+        // the lock is only poisoned by a panic while writing, the recording is lost anyway
+        let mut writer = self.writer.lock().unwrap();
+        serde_json::to_writer(&mut *writer, record)?;
+        writer.write_all(b"\n")?;
+        // flushed on every record, so the recording survives a crash of the tui
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Read a traffic recording, the records are in the order they were received
+pub fn load_traffic(path: &Path) -> Result<Vec<TrafficRecord>, TrafficError> {
+    let reader = BufReader::new(File::open(path)?);
+
+    reader
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !matches!(line, Ok(line) if line.trim().is_empty()))
+        .map(|(index, line)| {
+            serde_json::from_str(&line?).map_err(|source| TrafficError::Record {
+                line: index + 1,
+                source,
+            })
+        })
+        .collect()
+}
+
+/// How fast the recorded traffic is fed back
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReplayPace {
+    /// Multiple of the recorded speed, 1.0 replays in real time
+    Speed(f64),
+    /// Waits for a step before the traffic of each next block
+    Stepped,
+}
+
+/// Steps the replay in the stepped pace
+#[derive(Debug, Clone)]
+pub struct ReplayControl {
+    step_sender: mpsc::UnboundedSender<()>,
+}
+
+impl ReplayControl {
+    /// Release the traffic of the next block
+    pub fn step(&self) {
+        let _ = self.step_sender.send(());
+    }
+}
+
+/// Feed the recorded traffic to the returned services at the pace, must be called from a tokio runtime
+pub fn start_replay(
+    records: Vec<TrafficRecord>,
+    pace: ReplayPace,
+    bound: usize,
+    log: &Logger,
+) -> (RpcServiceReplay, WebsocketServiceReplay, ReplayControl) {
+    let (rpc_sender, rpc_receiver) = mpsc::channel(bound);
+    let (ws_sender, ws_receiver) = mpsc::channel(bound);
+    let (step_sender, step_receiver) = mpsc::unbounded_channel();
+
+    let t_log = log.clone();
+    tokio::task::spawn(async move {
+        run_player(records, pace, rpc_sender, ws_sender, step_receiver, t_log).await
+    });
+
+    (
+        RpcServiceReplay::new(rpc_receiver),
+        WebsocketServiceReplay::new(ws_receiver),
+        ReplayControl { step_sender },
+    )
+}
+
+async fn run_player(
+    records: Vec<TrafficRecord>,
+    pace: ReplayPace,
+    rpc_sender: mpsc::Sender<RpcResponse>,
+    ws_sender: mpsc::Sender<Vec<WebsocketMessage>>,
+    mut step_receiver: mpsc::UnboundedReceiver<()>,
+    log: Logger,
+) {
+    info!(
+        log,
+        "Replaying {} traffic records, pace: {:?}",
+        records.len(),
+        pace
+    );
+
+    let mut previous_time: Option<u64> = None;
+    let mut level: Option<i32> = None;
+
+    for record in records {
+        match pace {
+            ReplayPace::Speed(speed) => {
+                if let Some(previous_time) = previous_time {
+                    let recorded_gap = record.time.saturating_sub(previous_time) as f64;
+                    tokio::time::sleep(Duration::from_nanos((recorded_gap / speed) as u64)).await;
+                }
+            }
+            ReplayPace::Stepped => {
+                // the first block is released right away, so the screens are not empty
+                if let TrafficEvent::Rpc(RpcResponse::CurrentHeadHeader(header)) = &record.event {
+                    if level.is_some()
+                        && level != Some(header.level)
+                        && step_receiver.recv().await.is_none()
+                    {
+                        break;
+                    }
+                    level = Some(header.level);
+                }
+            }
+        }
+        previous_time = Some(record.time);
+
+        let sent = match record.event {
+            TrafficEvent::Rpc(response) => rpc_sender.send(response).await.is_ok(),
+            TrafficEvent::Websocket(messages) => ws_sender.send(messages).await.is_ok(),
+        };
+        if !sent {
+            break;
+        }
+    }
+
+    info!(log, "Replay finished");
+}
@@ -1,11 +1,13 @@
-use std::io;
+use std::{io, panic};
 use std::{io::Stdout, time::Duration};
 
 use crossterm::event::{self, Event, KeyCode};
-use crossterm::execute;
 use crossterm::terminal::{
     disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
 };
+use crossterm::{cursor, execute};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
 use tokio::sync::mpsc;
 use tui::backend::Backend;
 use tui::{backend::CrosstermBackend, Terminal};
@@ -16,6 +18,16 @@ pub struct TuiServiceDefault {
     pub terminal: Terminal<CrosstermBackend<Stdout>>,
 }
 
+#[derive(Error, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TuiError {
+    #[error("Failed to set up the terminal: {0}")]
+    Setup(String),
+    #[error("Failed to restore the terminal: {0}")]
+    Restore(String),
+    #[error("Failed to draw the screen: {0}")]
+    Draw(String),
+}
+
 pub trait TuiService {
     type Be: Backend;
     fn terminal(&mut self) -> &mut Terminal<Self::Be>;
@@ -30,25 +42,49 @@ impl TuiService for TuiServiceDefault {
 
     /// Restore terminal to its state before the app has launched
     fn restore_terminal(&mut self) {
-        execute!(self.terminal.backend_mut(), LeaveAlternateScreen)
-            .expect("Error occured while restoring terminal. Please restart your session.");
-        disable_raw_mode().expect("Error while dissabling raw mode. Please restart your session");
-        self.terminal
-            .show_cursor()
-            .expect("Error while restoring cursor. Please restart your session");
+        if let Err(e) = reset_terminal() {
+            eprintln!("{}. Please restart your session", e);
+        }
     }
 }
 
-impl TuiServiceDefault {
-    pub fn new() -> Self {
-        enable_raw_mode().expect("Failed to enable raw mode");
-        let mut stdout = io::stdout();
-        execute!(stdout, EnterAlternateScreen).expect("Failed to execute EnterAlternateScreen");
-        let backend = CrosstermBackend::new(stdout);
+/// Leave the alternate screen, disable the raw mode and show the cursor.
+/// Every step is tried, the first failure is returned
+pub fn reset_terminal() -> Result<(), TuiError> {
+    let screen = execute!(io::stdout(), LeaveAlternateScreen, cursor::Show);
+    let raw_mode = disable_raw_mode();
+    screen
+        .and(raw_mode)
+        .map_err(|e| TuiError::Restore(e.to_string()))
+}
 
-        let terminal = Terminal::new(backend).expect("Error initializing terminal");
+/// Restore the terminal before a panic is reported, a panic would leave it in the raw mode
+pub fn install_panic_hook() {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        let _ = reset_terminal();
+        default_hook(info);
+    }));
+}
 
-        TuiServiceDefault { terminal }
+impl TuiServiceDefault {
+    pub fn new() -> Result<Self, TuiError> {
+        install_panic_hook();
+
+        enable_raw_mode().map_err(|e| TuiError::Setup(e.to_string()))?;
+        let mut stdout = io::stdout();
+        let terminal = execute!(stdout, EnterAlternateScreen)
+            .and_then(|_| Terminal::new(CrosstermBackend::new(stdout)))
+            .map_err(|e| TuiError::Setup(e.to_string()));
+
+        match terminal {
+            Ok(terminal) => Ok(TuiServiceDefault { terminal }),
+            Err(e) => {
+                // the raw mode is already enabled
+                let _ = reset_terminal();
+                Err(e)
+            }
+        }
     }
 
     pub fn start(tick_rate: Duration) -> mpsc::Receiver<TuiEvent> {
@@ -107,9 +143,3 @@ impl TuiServiceDefault {
         }
     }
 }
-
-impl Default for TuiServiceDefault {
-    fn default() -> Self {
-        Self::new()
-    }
-}
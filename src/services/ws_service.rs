@@ -10,6 +10,8 @@ use crate::synchronization::{
     BlockApplicationStatus, BlockStatus, ChainStatus, IncomingTransferMetrics, PeerMetrics,
 };
 
+use super::traffic_service::{TrafficEvent, TrafficRecorder};
+
 pub type WsRecvError = mpsc::error::TryRecvError;
 
 pub type WsReceiver = mpsc::Receiver<Vec<WebsocketMessage>>;
@@ -21,13 +23,18 @@ pub trait WebsocketService {
 
 impl WebsocketService for WebsocketServiceDefault {
     fn message_try_recv(&mut self) -> Result<Vec<WebsocketMessage>, WsRecvError> {
-        self.receiver.try_recv()
+        let messages = self.receiver.try_recv();
+        if let (Some(recorder), Ok(messages)) = (&self.recorder, &messages) {
+            recorder.record(TrafficEvent::Websocket(messages.clone()));
+        }
+        messages
     }
 }
 
 #[derive(Debug)]
 pub struct WebsocketServiceDefault {
     receiver: WsReceiver,
+    recorder: Option<TrafficRecorder>,
 }
 
 impl WebsocketServiceDefault {
@@ -37,7 +44,16 @@ impl WebsocketServiceDefault {
         let t_log = log.clone();
         tokio::task::spawn(async move { Self::run_worker(websocket_url, tx, t_log).await });
 
-        Self { receiver: rx }
+        Self {
+            receiver: rx,
+            recorder: None,
+        }
+    }
+
+    /// Record the received messages
+    pub fn with_recorder(mut self, recorder: TrafficRecorder) -> Self {
+        self.recorder = Some(recorder);
+        self
     }
     async fn run_worker(websocket_url: Url, sender: WsSender, log: Logger) {
         let (ws_stream, _) = match connect_async(websocket_url.clone()).await {
@@ -81,7 +97,25 @@ impl WebsocketServiceDefault {
     }
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+/// Serves the messages of a traffic recording
+#[derive(Debug)]
+pub struct WebsocketServiceReplay {
+    receiver: WsReceiver,
+}
+
+impl WebsocketServiceReplay {
+    pub fn new(receiver: WsReceiver) -> Self {
+        Self { receiver }
+    }
+}
+
+impl WebsocketService for WebsocketServiceReplay {
+    fn message_try_recv(&mut self) -> Result<Vec<WebsocketMessage>, WsRecvError> {
+        self.receiver.try_recv()
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, strum_macros::IntoStaticStr)]
 #[serde(rename_all = "camelCase")]
 #[serde(tag = "type", content = "payload")]
 pub enum WebsocketMessage {
@@ -95,13 +95,12 @@ pub struct BlockInfo {
 
 #[derive(Clone, Deserialize, Debug, Default, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
-#[allow(dead_code)]
 pub struct PeerMetrics {
-    id: String,
-    ip_address: String,
-    transferred_bytes: usize,
-    average_transfer_speed: f32,
-    current_transfer_speed: f32,
+    pub id: String,
+    pub ip_address: String,
+    pub transferred_bytes: usize,
+    pub average_transfer_speed: f32,
+    pub current_transfer_speed: f32,
 }
 
 impl PeerMetrics {
@@ -158,7 +157,7 @@ impl Cycle {
     }
 }
 
-#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct ChainStatus {
     pub chain: Vec<Cycle>,
@@ -5,7 +5,7 @@ use tui::style::Modifier;
 use tui::{
     layout::{Alignment, Constraint, Direction, Layout},
     style::{Color, Style},
-    text::Spans,
+    text::{Span, Spans},
     widgets::{Block, BorderType, Borders, Cell, Paragraph, Row, Table},
     Frame,
 };
@@ -234,12 +234,12 @@ impl<B: Backend> Renderable<B> for SynchronizationScreen {
                 .split(periods_container);
 
             for (period_index, period) in periods.into_iter().enumerate() {
-                // only render periods that are present on the netrwork
-                if (container_index + state.synchronization.period_info_state.offset())
+                let period_data_index = (container_index
+                    + state.synchronization.period_info_state.offset())
                     * period_count_per_page_on_width as usize
-                    + period_index
-                    > period_count
-                {
+                    + period_index;
+                // only render periods that are present on the netrwork
+                if period_data_index > period_count {
                     break;
                 }
                 let period_chunks = Layout::default()
@@ -248,7 +248,28 @@ impl<B: Backend> Renderable<B> for SynchronizationScreen {
                     .constraints([Constraint::Length(1), Constraint::Length(3)])
                     .split(period);
 
-                let period_name = Paragraph::new(Spans::from(" Proposal"))
+                // the kind is only known for the running period
+                let first_cycle = (period_data_index * cycle_per_period) as i32;
+                let current_cycle = state.current_head_metadata.level_info.cycle;
+                let period_name = match &state.current_head_metadata.voting_period_info {
+                    Some(info)
+                        if (first_cycle..first_cycle + cycle_per_period as i32)
+                            .contains(&current_cycle) =>
+                    {
+                        Spans::from(vec![
+                            Span::styled(
+                                format!(" {}", capitalize(&info.voting_period.kind)),
+                                Style::default().add_modifier(Modifier::BOLD),
+                            ),
+                            Span::styled(
+                                format!(" {} blocks remaining", info.remaining),
+                                Style::default().add_modifier(Modifier::DIM),
+                            ),
+                        ])
+                    }
+                    _ => Spans::from(format!(" Period {}", period_data_index)),
+                };
+                let period_name = Paragraph::new(period_name)
                     .alignment(Alignment::Left)
                     .block(Block::default());
                 f.render_widget(period_name, period_chunks[0]);
@@ -405,3 +426,13 @@ fn calculate_percentage(total: usize, current: usize) -> f32 {
 fn _divide_round_up(dividend: usize, divisor: usize) -> usize {
     (dividend + (divisor - 1)) / divisor
 }
+
+/// Voting period kinds come in snake case, e.g. `testing_vote`
+fn capitalize(kind: &str) -> String {
+    let words = kind.replace('_', " ");
+    let mut chars = words.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
@@ -4,7 +4,11 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     automaton::State,
-    services::rpc_service_async::{CurrentHeadHeader, CurrentHeadMetadata, NetworkConstants},
+    extensions::ColumnEdit,
+    services::{
+        rpc_service_async::{CurrentHeadHeader, CurrentHeadMetadata, NetworkConstants},
+        tui_service::TuiError,
+    },
 };
 
 use super::ActivePage;
@@ -31,8 +35,7 @@ impl EnablingCondition<State> for DrawScreenSuccessAction {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DrawScreenFailiureAction {
-    // TODO: return proper error variants
-    _error: String,
+    pub error: TuiError,
 }
 
 impl EnablingCondition<State> for DrawScreenFailiureAction {
@@ -99,6 +102,62 @@ impl EnablingCondition<State> for TuiSortKeyPushedAction {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TuiFollowKeyPushedAction {}
+
+impl EnablingCondition<State> for TuiFollowKeyPushedAction {
+    fn is_enabled(&self, _: &State) -> bool {
+        true
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TuiColumnChooserKeyPushedAction {}
+
+impl EnablingCondition<State> for TuiColumnChooserKeyPushedAction {
+    fn is_enabled(&self, _: &State) -> bool {
+        true
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TuiChainInfoKeyPushedAction {}
+
+impl EnablingCondition<State> for TuiChainInfoKeyPushedAction {
+    fn is_enabled(&self, _: &State) -> bool {
+        true
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TuiAlertLogKeyPushedAction {}
+
+impl EnablingCondition<State> for TuiAlertLogKeyPushedAction {
+    fn is_enabled(&self, _: &State) -> bool {
+        true
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TuiMemoryPanelKeyPushedAction {}
+
+impl EnablingCondition<State> for TuiMemoryPanelKeyPushedAction {
+    fn is_enabled(&self, _: &State) -> bool {
+        true
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TuiColumnEditKeyPushedAction {
+    pub edit: ColumnEdit,
+}
+
+impl EnablingCondition<State> for TuiColumnEditKeyPushedAction {
+    fn is_enabled(&self, state: &State) -> bool {
+        state.ui.column_chooser.is_some()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TuiDeltaToggleKeyPushedAction {}
 
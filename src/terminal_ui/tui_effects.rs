@@ -1,13 +1,27 @@
+use slog::warn;
+use tui::{backend::Backend, Frame};
+
 use crate::{
-    automaton::{Action, ActionWithMeta, Store},
+    alerts::{draw_alert_banner, draw_alert_log},
+    automaton::{Action, ActionWithMeta, State, Store},
     baking::BakingScreen,
+    blocks::BlocksScreen,
+    common::draw_chain_info,
     endorsements::EndorsementsScreen,
+    export::draw_export_menu,
     extensions::Renderable,
+    governance::draw_protocol_banner,
+    history::{draw_level_history, draw_level_prompt},
+    inspector::InspectorScreen,
+    logs::LogsScreen,
     operations::StatisticsScreen,
+    retention::draw_memory_panel,
+    rights::RightsScreen,
     rpc::RpcRequestAction,
     services::{
+        config_service::ConfigService,
         rpc_service_async::{RpcCall, RpcTarget},
-        tui_service::TuiService,
+        tui_service::{TuiError, TuiService},
         Service,
     },
     synchronization::SynchronizationScreen,
@@ -15,7 +29,8 @@ use crate::{
 
 use super::{
     ActivePage, BestRemoteLevelChangedAction, CurrentHeadHeaderChangedAction,
-    CurrentHeadMetadataChangedAction, CycleChangedAction, DrawScreenSuccessAction,
+    CurrentHeadMetadataChangedAction, CycleChangedAction, DrawScreenFailiureAction,
+    DrawScreenSuccessAction,
 };
 
 pub fn tui_effects<S>(store: &mut Store<S>, action: &ActionWithMeta)
@@ -24,51 +39,28 @@ where
 {
     match &action.action {
         Action::DrawScreen(_) => {
-            let res = match store.state().ui.active_page {
-                ActivePage::Synchronization => {
-                    let state = store.state().clone();
-                    store
-                        .service()
-                        .tui()
-                        .terminal()
-                        .draw(|f| SynchronizationScreen::draw_screen(&state, f))
-                }
-                ActivePage::Endorsements => {
-                    let state = store.state().clone();
-                    // TODO: error handling
-                    store
-                        .service()
-                        .tui()
-                        .terminal()
-                        .draw(|f| EndorsementsScreen::draw_screen(&state, f))
-                }
-                ActivePage::Statistics => {
-                    let state = store.state().clone();
-                    store
-                        .service()
-                        .tui()
-                        .terminal()
-                        .draw(|f| StatisticsScreen::draw_screen(&state, f))
-                }
-                ActivePage::Baking => {
-                    let state = store.state().clone();
-                    store
-                        .service()
-                        .tui()
-                        .terminal()
-                        .draw(|f| BakingScreen::draw_screen(&state, f))
-                }
-            };
+            let state = store.state().clone();
+            // the completed frame covers the whole terminal
+            let res = store
+                .service()
+                .tui()
+                .terminal()
+                .draw(|f| draw_active_page(&state, f))
+                .map(|frame| frame.area.width)
+                .map_err(|e| TuiError::Draw(e.to_string()));
             match res {
-                Ok(_) => {
-                    let width = store.service().tui().terminal().size().unwrap().width;
-                    store.dispatch(DrawScreenSuccessAction {
-                        screen_width: width,
-                    });
+                Ok(screen_width) => {
+                    store.dispatch(DrawScreenSuccessAction { screen_width });
+                }
+                Err(error) => {
+                    store.dispatch(DrawScreenFailiureAction { error });
                 }
-                Err(_) => todo!(),
             }
         }
+        Action::DrawScreenFailiure(action) => {
+            // the next draw is tried on the next tick or input
+            warn!(store.state().log, "{}", action.error);
+        }
         Action::CurrentHeadHeaderReceived(action) => {
             if store.state().current_head_header.level < action.current_head_header.level {
                 store.dispatch(CurrentHeadHeaderChangedAction {
@@ -129,7 +121,34 @@ where
                 }
             }
         }
+        Action::TuiSortKeyPushed(_) | Action::TuiColumnEditKeyPushed(_) => {
+            let config = store.state().config.clone();
+            store.service().config().save(&config);
+        }
         Action::Shutdown(_) => {}
         _ => {}
     }
 }
+
+/// Draw the active page with the popups opened over it
+pub fn draw_active_page<B: Backend>(state: &State, f: &mut Frame<B>) {
+    match state.ui.active_page {
+        ActivePage::Synchronization => SynchronizationScreen::draw_screen(state, f),
+        ActivePage::Endorsements => EndorsementsScreen::draw_screen(state, f),
+        ActivePage::Statistics => StatisticsScreen::draw_screen(state, f),
+        ActivePage::Baking => BakingScreen::draw_screen(state, f),
+        ActivePage::Rights => RightsScreen::draw_screen(state, f),
+        ActivePage::Blocks => BlocksScreen::draw_screen(state, f),
+        ActivePage::Logs => LogsScreen::draw_screen(state, f),
+        ActivePage::Inspector => InspectorScreen::draw_screen(state, f),
+    }
+
+    draw_protocol_banner(state, f);
+    draw_alert_banner(state, f);
+    draw_chain_info(state, f);
+    draw_alert_log(state, f);
+    draw_level_history(state, f);
+    draw_level_prompt(state, f);
+    draw_memory_panel(state, f);
+    draw_export_menu(state, f);
+}
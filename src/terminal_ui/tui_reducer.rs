@@ -1,35 +1,41 @@
-use slog::info;
+use crossterm::event::KeyModifiers;
 
 use crate::{
     automaton::{Action, ActionWithMeta, State},
     baking::{BakingSummary, BlockApplicationSummary},
+    extensions::ColumnLayout,
 };
 
-use super::{ActivePage, ActiveWidget};
+use super::{ActivePage, ActiveWidget, ColumnChooserState};
 
 pub fn tui_reducer(state: &mut State, action: &ActionWithMeta) {
+    let now = action.time_as_nanos();
     match &action.action {
-        Action::DrawScreen(_) => match state.ui.active_page {
-            ActivePage::Endorsements => {
-                state.endorsmenents.endorsement_table.highlight_sorting();
-            }
-            ActivePage::Statistics => {
-                state
-                    .operations_statistics
-                    .main_operation_statistics_table
-                    .highlight_sorting();
-                state
-                    .operations_statistics
-                    .details_operation_statistics_table
-                    .highlight_sorting();
-            }
-            ActivePage::Baking => {
-                state.baking.baking_table.highlight_sorting();
+        Action::DrawScreen(_) => {
+            state.ui.draw_time = now;
+            match state.ui.active_page {
+                ActivePage::Endorsements => {
+                    state.endorsmenents.endorsement_table.highlight_sorting();
+                }
+                ActivePage::Statistics => {
+                    state
+                        .operations_statistics
+                        .main_operation_statistics_table
+                        .highlight_sorting();
+                    state
+                        .operations_statistics
+                        .details_operation_statistics_table
+                        .highlight_sorting();
+                }
+                ActivePage::Baking => {
+                    state.baking.baking_table.highlight_sorting();
+                }
+                _ => { /* No sorting highlights requeired on other screens */ }
             }
-            _ => { /* No sorting highlights requeired on other screens */ }
-        },
+        }
         Action::ChangeScreen(action) => {
             state.ui.active_page = action.screen.clone();
+            state.ui.column_chooser = None;
 
             // after we change the screen, we need to set the active widget
             match action.screen {
@@ -39,6 +45,10 @@ pub fn tui_reducer(state: &mut State, action: &ActionWithMeta) {
                     state.ui.active_widget = ActiveWidget::StatisticsMainTable
                 }
                 ActivePage::Baking => state.ui.active_widget = ActiveWidget::BakingTable,
+                ActivePage::Rights => state.ui.active_widget = ActiveWidget::RightsTable,
+                ActivePage::Blocks => state.ui.active_widget = ActiveWidget::BlocksTable,
+                ActivePage::Logs => state.ui.active_widget = ActiveWidget::LogTable,
+                ActivePage::Inspector => state.ui.active_widget = ActiveWidget::InspectorTable,
             }
         }
         Action::DrawScreenSuccess(action) => {
@@ -108,25 +118,31 @@ pub fn tui_reducer(state: &mut State, action: &ActionWithMeta) {
             ActiveWidget::BakingTable => state.baking.baking_table.previous(),
             _ => {}
         },
+        Action::TuiDownKeyPushedAction(_) if state.ui.column_chooser.is_some() => {
+            let column_count = state
+                .active_table()
+                .map(|table| table.columns().len())
+                .unwrap_or(0);
+            if let Some(chooser) = state.ui.column_chooser.as_mut() {
+                if chooser.cursor + 1 < column_count {
+                    chooser.cursor += 1;
+                }
+            }
+        }
         Action::TuiDownKeyPushedAction(_) => match state.ui.active_widget {
             ActiveWidget::PeriodInfo => {}
             ActiveWidget::PeerTable => state.synchronization.peer_table_state.select(next_item(
                 state.synchronization.peer_metrics.len(),
                 state.synchronization.peer_table_state.selected(),
             )),
-            ActiveWidget::EndorserTable => state
-                .endorsmenents
-                .endorsement_table
-                .table_state
-                .select(next_item(
-                    state.endorsmenents.endorsement_table.content.len(),
-                    state.endorsmenents.endorsement_table.table_state.selected(),
-                )),
+            ActiveWidget::EndorserTable => state.endorsmenents.endorsement_table.select(next_item(
+                state.endorsmenents.endorsement_table.content.len(),
+                state.endorsmenents.endorsement_table.table_state.selected(),
+            )),
             ActiveWidget::StatisticsMainTable => {
                 state
                     .operations_statistics
                     .main_operation_statistics_table
-                    .table_state
                     .select(next_item(
                         state
                             .operations_statistics
@@ -159,14 +175,13 @@ pub fn tui_reducer(state: &mut State, action: &ActionWithMeta) {
                         state
                             .operations_statistics
                             .details_operation_statistics_table
-                            .content = stats.to_operations_details();
+                            .set_content(stats.to_operations_details(), state.delta_toggle);
                     }
                 }
             }
             ActiveWidget::StatisticsDetailsTable => state
                 .operations_statistics
                 .details_operation_statistics_table
-                .table_state
                 .select(next_item(
                     state
                         .operations_statistics
@@ -179,11 +194,25 @@ pub fn tui_reducer(state: &mut State, action: &ActionWithMeta) {
                         .table_state
                         .selected(),
                 )),
-            ActiveWidget::BakingTable => state.baking.baking_table.table_state.select(next_item(
+            ActiveWidget::BakingTable => state.baking.baking_table.select(next_item(
                 state.baking.baking_table.content.len(),
                 state.baking.baking_table.table_state.selected(),
             )),
+            ActiveWidget::RightsTable => {
+                state.rights.selected =
+                    next_item(state.rights.entries().len(), state.rights.selected)
+            }
+            ActiveWidget::BlocksTable => {
+                state.blocks.selected = next_item(state.blocks.recent.len(), state.blocks.selected)
+            }
+            ActiveWidget::LogTable => state.logs.select_next(),
+            ActiveWidget::InspectorTable => state.inspector.select_next(),
         },
+        Action::TuiUpKeyPushedAction(_) if state.ui.column_chooser.is_some() => {
+            if let Some(chooser) = state.ui.column_chooser.as_mut() {
+                chooser.cursor = chooser.cursor.saturating_sub(1);
+            }
+        }
         Action::TuiUpKeyPushedAction(_) => match state.ui.active_widget {
             ActiveWidget::PeriodInfo => {}
             ActiveWidget::PeerTable => {
@@ -192,19 +221,16 @@ pub fn tui_reducer(state: &mut State, action: &ActionWithMeta) {
                     state.synchronization.peer_table_state.selected(),
                 ))
             }
-            ActiveWidget::EndorserTable => state
-                .endorsmenents
-                .endorsement_table
-                .table_state
-                .select(previous_item(
+            ActiveWidget::EndorserTable => {
+                state.endorsmenents.endorsement_table.select(previous_item(
                     state.endorsmenents.endorsement_table.content.len(),
                     state.endorsmenents.endorsement_table.table_state.selected(),
-                )),
+                ))
+            }
             ActiveWidget::StatisticsMainTable => {
                 state
                     .operations_statistics
                     .main_operation_statistics_table
-                    .table_state
                     .select(previous_item(
                         state
                             .operations_statistics
@@ -237,14 +263,13 @@ pub fn tui_reducer(state: &mut State, action: &ActionWithMeta) {
                         state
                             .operations_statistics
                             .details_operation_statistics_table
-                            .content = stats.to_operations_details();
+                            .set_content(stats.to_operations_details(), state.delta_toggle);
                     }
                 }
             }
             ActiveWidget::StatisticsDetailsTable => state
                 .operations_statistics
                 .details_operation_statistics_table
-                .table_state
                 .select(previous_item(
                     state
                         .operations_statistics
@@ -257,120 +282,124 @@ pub fn tui_reducer(state: &mut State, action: &ActionWithMeta) {
                         .table_state
                         .selected(),
                 )),
-            ActiveWidget::BakingTable => {
-                state.baking.baking_table.table_state.select(previous_item(
-                    state.baking.baking_table.content.len(),
-                    state.baking.baking_table.table_state.selected(),
-                ))
-            }
-        },
-        Action::TuiSortKeyPushed(_) => match state.ui.active_widget {
-            ActiveWidget::EndorserTable => {
-                let selected = state.endorsmenents.endorsement_table.selected();
-                let sort_order = state.endorsmenents.endorsement_table.sort_order().switch();
-                state
-                    .endorsmenents
-                    .endorsement_table
-                    .set_sort_order(sort_order);
-
-                state
-                    .endorsmenents
-                    .endorsement_table
-                    .set_sorted_by(selected);
-                state
-                    .endorsmenents
-                    .endorsement_table
-                    .sort_content(state.delta_toggle);
+            ActiveWidget::BakingTable => state.baking.baking_table.select(previous_item(
+                state.baking.baking_table.content.len(),
+                state.baking.baking_table.table_state.selected(),
+            )),
+            ActiveWidget::RightsTable => {
+                state.rights.selected =
+                    previous_item(state.rights.entries().len(), state.rights.selected)
             }
-            ActiveWidget::StatisticsMainTable => {
-                let seleceted = state
-                    .operations_statistics
-                    .main_operation_statistics_table
-                    .selected();
-                let sort_order = state
-                    .operations_statistics
-                    .main_operation_statistics_table
-                    .sort_order()
-                    .switch();
-                state
-                    .operations_statistics
-                    .main_operation_statistics_table
-                    .set_sort_order(sort_order);
-
-                state
-                    .operations_statistics
-                    .main_operation_statistics_table
-                    .set_sorted_by(seleceted);
-
-                state
-                    .operations_statistics
-                    .main_operation_statistics_table
-                    .sort_content(state.delta_toggle);
+            ActiveWidget::BlocksTable => {
+                state.blocks.selected =
+                    previous_item(state.blocks.recent.len(), state.blocks.selected)
             }
-            ActiveWidget::StatisticsDetailsTable => {
-                if !state
-                    .operations_statistics
-                    .details_operation_statistics_table
-                    .content
-                    .is_empty()
-                {
-                    let selected = state
-                        .operations_statistics
-                        .details_operation_statistics_table
-                        .selected();
-                    let sort_order = state
-                        .operations_statistics
-                        .details_operation_statistics_table
-                        .sort_order()
-                        .switch();
-                    state
-                        .operations_statistics
-                        .details_operation_statistics_table
-                        .set_sort_order(sort_order);
-
-                    state
-                        .operations_statistics
-                        .details_operation_statistics_table
-                        .set_sorted_by(selected);
-
-                    state
+            ActiveWidget::LogTable => state.logs.select_previous(),
+            ActiveWidget::InspectorTable => state.inspector.select_previous(),
+        },
+        Action::TuiSortKeyPushed(action) => {
+            let append = action.modifier.contains(KeyModifiers::SHIFT);
+            match state.ui.active_widget {
+                ActiveWidget::EndorserTable => {
+                    let table = &mut state.endorsmenents.endorsement_table;
+                    table.sort_by_selected(append);
+                    table.sort_content(state.delta_toggle);
+                    state.config.tables.endorsements = table.table_config();
+                }
+                ActiveWidget::StatisticsMainTable => {
+                    let table = &mut state.operations_statistics.main_operation_statistics_table;
+                    table.sort_by_selected(append);
+                    table.sort_content(state.delta_toggle);
+                    state.config.tables.operations = table.table_config();
+                }
+                ActiveWidget::StatisticsDetailsTable => {
+                    let table = &mut state
                         .operations_statistics
-                        .details_operation_statistics_table
-                        .sort_content(state.delta_toggle);
+                        .details_operation_statistics_table;
+                    if !table.content.is_empty() {
+                        table.sort_by_selected(append);
+                        table.sort_content(state.delta_toggle);
+                        state.config.tables.operation_details = table.table_config();
+                    }
                 }
+                ActiveWidget::BakingTable => {
+                    let table = &mut state.baking.baking_table;
+                    table.sort_by_selected(append);
+                    table.sort_content(state.delta_toggle);
+                    state.config.tables.baking = table.table_config();
+                }
+                _ => {}
             }
-            ActiveWidget::BakingTable => {
-                let seleceted = state.baking.baking_table.selected();
-                let sort_order = state.baking.baking_table.sort_order().switch();
-
-                state.baking.baking_table.set_sort_order(sort_order);
-
-                state.baking.baking_table.set_sorted_by(seleceted);
-
-                state.baking.baking_table.sort_content(state.delta_toggle);
-            }
+        }
+        Action::TuiFollowKeyPushed(_) => match state.ui.active_widget {
+            ActiveWidget::EndorserTable => state.endorsmenents.endorsement_table.toggle_follow(),
+            ActiveWidget::StatisticsMainTable => state
+                .operations_statistics
+                .main_operation_statistics_table
+                .toggle_follow(),
+            ActiveWidget::StatisticsDetailsTable => state
+                .operations_statistics
+                .details_operation_statistics_table
+                .toggle_follow(),
+            ActiveWidget::BakingTable => state.baking.baking_table.toggle_follow(),
             _ => {}
         },
+        Action::TuiColumnChooserKeyPushed(_) => {
+            if state.ui.column_chooser.is_some() {
+                state.ui.column_chooser = None;
+            } else if state.active_table().is_some() {
+                state.ui.column_chooser = Some(ColumnChooserState::default());
+            }
+        }
+        Action::TuiColumnEditKeyPushed(action) => {
+            let cursor = state
+                .ui
+                .column_chooser
+                .as_ref()
+                .map(|chooser| chooser.cursor)
+                .unwrap_or_default();
+            if let Some((table, config)) = state.active_table_mut() {
+                let cursor = table.edit_column(cursor, &action.edit);
+                *config = table.table_config();
+                state.ui.column_chooser = Some(ColumnChooserState { cursor });
+            }
+        }
+        Action::TuiChainInfoKeyPushed(_) => {
+            state.ui.chain_info_open = !state.ui.chain_info_open;
+        }
+        Action::TuiAlertLogKeyPushed(_) => {
+            state.ui.alert_log_open = !state.ui.alert_log_open;
+        }
+        Action::TuiMemoryPanelKeyPushed(_) => {
+            state.ui.memory_panel_open = !state.ui.memory_panel_open;
+        }
         Action::TuiDeltaToggleKeyPushed(_) => {
             state.delta_toggle = !state.delta_toggle;
         }
-        Action::TuiWidgetSelectionKeyPushed(_) => match state.ui.active_page {
-            ActivePage::Synchronization => match state.ui.active_widget {
-                ActiveWidget::PeriodInfo => state.ui.active_widget = ActiveWidget::PeerTable,
-                _ => state.ui.active_widget = ActiveWidget::PeriodInfo,
-            },
-            ActivePage::Endorsements => state.ui.active_widget = ActiveWidget::EndorserTable,
-            ActivePage::Statistics => match state.ui.active_widget {
-                ActiveWidget::StatisticsMainTable => {
-                    state.ui.active_widget = ActiveWidget::StatisticsDetailsTable
-                }
-                ActiveWidget::StatisticsDetailsTable => {
-                    state.ui.active_widget = ActiveWidget::StatisticsMainTable
-                }
-                _ => state.ui.active_widget = ActiveWidget::StatisticsMainTable,
-            },
-            ActivePage::Baking => state.ui.active_widget = ActiveWidget::BakingTable,
-        },
+        Action::TuiWidgetSelectionKeyPushed(_) => {
+            state.ui.column_chooser = None;
+            match state.ui.active_page {
+                ActivePage::Synchronization => match state.ui.active_widget {
+                    ActiveWidget::PeriodInfo => state.ui.active_widget = ActiveWidget::PeerTable,
+                    _ => state.ui.active_widget = ActiveWidget::PeriodInfo,
+                },
+                ActivePage::Endorsements => state.ui.active_widget = ActiveWidget::EndorserTable,
+                ActivePage::Statistics => match state.ui.active_widget {
+                    ActiveWidget::StatisticsMainTable => {
+                        state.ui.active_widget = ActiveWidget::StatisticsDetailsTable
+                    }
+                    ActiveWidget::StatisticsDetailsTable => {
+                        state.ui.active_widget = ActiveWidget::StatisticsMainTable
+                    }
+                    _ => state.ui.active_widget = ActiveWidget::StatisticsMainTable,
+                },
+                ActivePage::Baking => state.ui.active_widget = ActiveWidget::BakingTable,
+                ActivePage::Rights => state.ui.active_widget = ActiveWidget::RightsTable,
+                ActivePage::Blocks => state.ui.active_widget = ActiveWidget::BlocksTable,
+                ActivePage::Logs => state.ui.active_widget = ActiveWidget::LogTable,
+                ActivePage::Inspector => state.ui.active_widget = ActiveWidget::InspectorTable,
+            }
+        }
         Action::CurrentHeadHeaderChanged(action) => {
             // in this context the state.current_head_header is the previous, and state.previous_head_header is the previous of the previous
             // we need to store the last baking data that needs all the updated stats until a new block
@@ -379,6 +408,7 @@ pub fn tui_reducer(state: &mut State, action: &ActionWithMeta) {
                 state.current_head_header.level,
                 &state.current_head_header.timestamp,
                 state.network_constants.minimal_block_delay,
+                (now / 1_000_000_000) as i64,
             ) {
                 if baking_level == state.current_head_header.level {
                     state.baking.last_baked_block_level = Some(state.current_head_header.level);
@@ -426,14 +456,6 @@ pub fn tui_reducer(state: &mut State, action: &ActionWithMeta) {
         Action::CurrentHeadMetadataChanged(action) => {
             state.current_head_metadata = action.new_metadata.clone();
         }
-        Action::CycleChanged(action) => {
-            info!(
-                state.log,
-                "Cleanning up baking rights up until level: {}", action.at_level
-            );
-            state.baking.baking_rights.cleanup(&action.at_level);
-            // TODO: also cleanup endorsement rights
-        }
         Action::BestRemoteLevelChanged(action) => {
             state.best_remote_level = action.level;
         }
@@ -441,35 +463,22 @@ pub fn tui_reducer(state: &mut State, action: &ActionWithMeta) {
     }
 }
 
+/// Index of the next row, wrapping around to the first one, `None` for an empty table
 pub fn next_item(total: usize, selection_index: Option<usize>) -> Option<usize> {
     match selection_index {
-        Some(selection_index) => {
-            if total != 0 {
-                let next_index = selection_index + 1;
-                if next_index > total - 1 {
-                    return Some(0);
-                } else {
-                    return Some(next_index);
-                }
-            }
-            Some(0)
-        }
-        None => Some(0),
+        _ if total == 0 => None,
+        Some(selection_index) if selection_index + 1 < total => Some(selection_index + 1),
+        _ => Some(0),
     }
 }
 
+/// Index of the previous row, wrapping around to the last one, `None` for an empty table.
+/// A selection left past the end by a shrunk content moves to the last row
 pub fn previous_item(total: usize, selection_index: Option<usize>) -> Option<usize> {
     match selection_index {
-        Some(selection_index) => {
-            if total != 0 {
-                if selection_index > 0 {
-                    return Some(selection_index - 1);
-                } else {
-                    return Some(total - 1);
-                }
-            }
-            Some(0)
-        }
+        _ if total == 0 => None,
         None => Some(0),
+        Some(0) => Some(total - 1),
+        Some(selection_index) => Some((selection_index - 1).min(total - 1)),
     }
 }
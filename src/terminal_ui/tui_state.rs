@@ -15,6 +15,21 @@ pub struct UiState {
     pub active_widget: ActiveWidget,
     pub current_details_length: usize,
     pub screen_width: u16,
+    /// Column chooser of the focused table, `None` when closed
+    #[serde(default)]
+    pub column_chooser: Option<ColumnChooserState>,
+    /// Chain info panel shown over the screen
+    #[serde(default)]
+    pub chain_info_open: bool,
+    /// Alert log shown over the screen
+    #[serde(default)]
+    pub alert_log_open: bool,
+    /// Memory usage of the accumulating state shown over the screen
+    #[serde(default)]
+    pub memory_panel_open: bool,
+    /// Time of the screen being drawn in nanoseconds, the countdowns are computed from it
+    #[serde(default)]
+    pub draw_time: u64,
 }
 
 impl PartialEq for UiState {
@@ -24,9 +39,27 @@ impl PartialEq for UiState {
             && self.active_widget == other.active_widget
             && self.current_details_length == other.current_details_length
             && self.screen_width == other.screen_width
+            && self.column_chooser == other.column_chooser
+            && self.chain_info_open == other.chain_info_open
+            && self.alert_log_open == other.alert_log_open
+            && self.memory_panel_open == other.memory_panel_open
+            && self.draw_time == other.draw_time
     }
 }
 
+impl UiState {
+    /// Unix timestamp of the screen being drawn
+    pub fn draw_timestamp(&self) -> i64 {
+        (self.draw_time / 1_000_000_000) as i64
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ColumnChooserState {
+    /// Index of the highlighted column in the column layout
+    pub cursor: usize,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ActiveWidget {
     PeriodInfo,
@@ -35,6 +68,10 @@ pub enum ActiveWidget {
     StatisticsMainTable,
     StatisticsDetailsTable,
     BakingTable,
+    RightsTable,
+    BlocksTable,
+    LogTable,
+    InspectorTable,
 }
 
 // TODO: make enum contain the screen struct?
@@ -42,6 +79,10 @@ pub enum ActiveWidget {
 pub enum ActivePage {
     Endorsements,
     Baking,
+    Rights,
+    Blocks,
+    Logs,
+    Inspector,
     Synchronization,
     Statistics,
 }
@@ -51,16 +92,24 @@ impl ActivePage {
         match self {
             ActivePage::Endorsements => 0,
             ActivePage::Baking => 1,
-            ActivePage::Statistics => 2,
-            ActivePage::Synchronization => 3,
+            ActivePage::Rights => 2,
+            ActivePage::Blocks => 3,
+            ActivePage::Logs => 4,
+            ActivePage::Inspector => 5,
+            ActivePage::Statistics => 6,
+            ActivePage::Synchronization => 7,
         }
     }
     pub fn hotkey(&self) -> String {
         match self {
             ActivePage::Endorsements => String::from("F1"),
             ActivePage::Baking => String::from("F2"),
-            ActivePage::Statistics => String::from("F3"),
-            ActivePage::Synchronization => String::from("F4"),
+            ActivePage::Rights => String::from("F3"),
+            ActivePage::Blocks => String::from("F4"),
+            ActivePage::Logs => String::from("F5"),
+            ActivePage::Inspector => String::from("F6"),
+            ActivePage::Statistics => String::from("F7"),
+            ActivePage::Synchronization => String::from("F8"),
         }
     }
 }
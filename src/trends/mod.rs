@@ -0,0 +1,14 @@
+pub mod trends_actions;
+pub use trends_actions::*;
+
+pub mod trends_effects;
+pub use trends_effects::*;
+
+pub mod trends_reducer;
+pub use trends_reducer::*;
+
+pub mod trends_state;
+pub use trends_state::*;
+
+pub mod trends_view;
+pub use trends_view::*;
@@ -0,0 +1,24 @@
+use redux_rs::EnablingCondition;
+use serde::{Deserialize, Serialize};
+
+use crate::automaton::State;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MempoolSizeGetAction {}
+
+impl EnablingCondition<State> for MempoolSizeGetAction {
+    fn is_enabled(&self, _: &State) -> bool {
+        true
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MempoolSizeReceivedAction {
+    pub size: usize,
+}
+
+impl EnablingCondition<State> for MempoolSizeReceivedAction {
+    fn is_enabled(&self, _: &State) -> bool {
+        true
+    }
+}
@@ -0,0 +1,19 @@
+use crate::{
+    automaton::{Action, ActionWithMeta, Store},
+    rpc::RpcRequestAction,
+    services::{
+        rpc_service_async::{RpcCall, RpcTarget},
+        Service,
+    },
+};
+
+pub fn trends_effects<S>(store: &mut Store<S>, action: &ActionWithMeta)
+where
+    S: Service,
+{
+    if let Action::MempoolSizeGet(_) = &action.action {
+        store.dispatch(RpcRequestAction {
+            call: RpcCall::new(RpcTarget::PendingOperations, None),
+        });
+    }
+}
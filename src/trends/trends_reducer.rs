@@ -0,0 +1,23 @@
+use crate::automaton::{Action, ActionWithMeta, State};
+
+use super::TrendSample;
+
+pub fn trends_reducer(state: &mut State, action: &ActionWithMeta) {
+    match &action.action {
+        Action::MempoolSizeReceived(action) => {
+            state.trends.mempool_size = action.size;
+        }
+        Action::MempoolEndorsementStatsReceived(action) => {
+            state.trends.mempool_endorsements = action.stats.len();
+        }
+        Action::CurrentHeadHeaderChanged(_) => {
+            // the tui reducer already moved the finished block to the previous head
+            let finished = &state.previous_head_header;
+            if !finished.hash.is_empty() {
+                let sample = TrendSample::collect(state, finished, &state.current_head_header);
+                state.trends.push(sample);
+            }
+        }
+        _ => {}
+    }
+}
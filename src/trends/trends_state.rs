@@ -0,0 +1,98 @@
+use std::collections::{BTreeMap, VecDeque};
+
+use serde::{de::IgnoredAny, Deserialize, Serialize};
+
+use crate::{
+    automaton::State, endorsements::EndorsementState,
+    services::rpc_service_async::CurrentHeadHeader,
+};
+
+/// Number of the last finished levels kept for the header sparklines
+pub const TREND_LEVELS: usize = 120;
+
+/// Operations of the mempool by their classification, only their number is read
+pub type PendingOperations = BTreeMap<String, Vec<IgnoredAny>>;
+
+/// Classifications of the operations the mempool rejected, they are not waiting for a block
+const REJECTED_CLASSIFICATIONS: [&str; 3] = ["refused", "branch_refused", "outdated"];
+
+/// Number of the operations waiting for a block
+pub fn mempool_size(pending: &PendingOperations) -> usize {
+    pending
+        .iter()
+        .filter(|(classification, _)| !REJECTED_CLASSIFICATIONS.contains(&classification.as_str()))
+        .map(|(_, operations)| operations.len())
+        .sum()
+}
+
+/// Health metrics of a finished block
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TrendSample {
+    pub level: i32,
+    /// Seconds the block was the head, until the next block arrived
+    pub block_time: Option<u64>,
+    /// Total application time of the block, in nanoseconds
+    pub application_time: Option<u64>,
+    /// Endorsement slots in the Applied or Broadcast state
+    pub endorsements: usize,
+    /// Operations waiting in the mempool
+    pub mempool_size: usize,
+    /// Endorsement operations in the mempool
+    #[serde(default)]
+    pub mempool_endorsements: usize,
+}
+
+impl TrendSample {
+    /// Collect the metrics of the `finished` block when the `next` block becomes the head
+    pub fn collect(state: &State, finished: &CurrentHeadHeader, next: &CurrentHeadHeader) -> Self {
+        let block_time = u64::try_from((next.timestamp - finished.timestamp).whole_seconds()).ok();
+
+        let application_time = state
+            .baking
+            .application_statistics
+            .get(&finished.hash)
+            .and_then(|statistics| statistics.total_application_time());
+
+        let endorsements = state
+            .endorsmenents
+            .endoresement_status_summary
+            .iter()
+            .filter(|(endorsement_state, _)| {
+                matches!(
+                    endorsement_state,
+                    EndorsementState::Applied | EndorsementState::Broadcast
+                )
+            })
+            .map(|(_, slots)| slots)
+            .sum();
+
+        Self {
+            level: finished.level,
+            block_time,
+            application_time,
+            endorsements,
+            mempool_size: state.trends.mempool_size,
+            mempool_endorsements: state.trends.mempool_endorsements,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TrendsState {
+    /// Metrics of the last finished levels, the newest last
+    pub samples: VecDeque<TrendSample>,
+    /// Operations waiting in the last received mempool
+    pub mempool_size: usize,
+    /// Endorsement operations in the last received mempool statistics
+    #[serde(default)]
+    pub mempool_endorsements: usize,
+}
+
+impl TrendsState {
+    pub fn push(&mut self, sample: TrendSample) {
+        self.samples.push_back(sample);
+        while self.samples.len() > TREND_LEVELS {
+            self.samples.pop_front();
+        }
+    }
+}
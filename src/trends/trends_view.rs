@@ -0,0 +1,105 @@
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Paragraph, Sparkline},
+    Frame,
+};
+
+use crate::{automaton::State, extensions::convert_time_to_unit_string};
+
+use super::TrendSample;
+
+/// Label of the metric, its value in the sample and the formatting of the last value
+type Trend = (&'static str, fn(&TrendSample) -> u64, fn(u64) -> String);
+
+/// Columns left for the sparkline, the label is dropped when the trend does not fit
+const MIN_SPARKLINE_WIDTH: usize = 4;
+
+/// Draw a sparkline of the last blocks for each health metric, in a single row
+pub fn draw_header_trends<B: Backend>(state: &State, area: Rect, f: &mut Frame<B>) {
+    let trends: [Trend; 5] = [
+        (
+            "Block time",
+            |sample| sample.block_time.unwrap_or_default(),
+            |value| format!("{}s", value),
+        ),
+        (
+            "Application",
+            |sample| sample.application_time.unwrap_or_default(),
+            convert_time_to_unit_string::<u64>,
+        ),
+        (
+            "Endorsements",
+            |sample| sample.endorsements as u64,
+            |value| value.to_string(),
+        ),
+        (
+            "Mempool",
+            |sample| sample.mempool_size as u64,
+            |value| value.to_string(),
+        ),
+        (
+            "Mempool endorsements",
+            |sample| sample.mempool_endorsements as u64,
+            |value| value.to_string(),
+        ),
+    ];
+
+    let trend_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Ratio(1, 5),
+            Constraint::Ratio(1, 5),
+            Constraint::Ratio(1, 5),
+            Constraint::Ratio(1, 5),
+            Constraint::Ratio(1, 5),
+        ])
+        .split(area);
+
+    for ((label, metric, format), chunk) in trends.iter().zip(trend_chunks) {
+        let values: Vec<u64> = state.trends.samples.iter().map(metric).collect();
+        let last = values
+            .last()
+            .map(|value| format!(" {} ", format(*value)))
+            .unwrap_or_else(|| String::from(" - "));
+        draw_trend(label, &values, &last, chunk, f);
+    }
+}
+
+fn draw_trend<B: Backend>(label: &str, values: &[u64], last: &str, area: Rect, f: &mut Frame<B>) {
+    // the spaces separate the label from the value of the previous trend
+    let mut label = format!(" {} ", label);
+    if label.len() + last.chars().count() + MIN_SPARKLINE_WIDTH > area.width as usize {
+        label = String::from(" ");
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Length(label.len() as u16),
+            Constraint::Min(1),
+            Constraint::Length(last.chars().count() as u16),
+        ])
+        .split(area);
+
+    let label = Paragraph::new(Span::styled(
+        label,
+        Style::default().fg(Color::Gray).add_modifier(Modifier::DIM),
+    ));
+    f.render_widget(label, chunks[0]);
+
+    // the sparkline fills from the left, show only the newest values that fit
+    let shown = values.len().min(chunks[1].width as usize);
+    let sparkline = Sparkline::default()
+        .data(&values[values.len() - shown..])
+        .style(Style::default().fg(Color::Cyan));
+    f.render_widget(sparkline, chunks[1]);
+
+    let last = Paragraph::new(Spans::from(vec![Span::styled(
+        last,
+        Style::default().fg(Color::White),
+    )]));
+    f.render_widget(last, chunks[2]);
+}
@@ -0,0 +1,261 @@
+use pretty_assertions::assert_eq;
+use serde::Serialize;
+use serde_json::json;
+use std::{fs, io::Write, path::PathBuf, time::SystemTime};
+
+use services_mocked::ServiceMocked;
+use tezedge_tui::{
+    automaton::{
+        effects, reducer, Action, ActionRecorder, ActionRecording, ActionWithMeta, Automaton,
+        State, Store, ACTION_CHECKPOINT_INTERVAL,
+    },
+    extensions::{SortOrder, SortSpec},
+    services::rpc_service_async::CurrentHeadHeader,
+    terminal_ui::{CurrentHeadHeaderChangedAction, TuiDeltaToggleKeyPushedAction},
+};
+
+mod services_mocked;
+
+/// Initial state serialized by the older versions
+const LEGACY_STATE: &str = include_str!("fixtures/legacy_state.json");
+
+/// The state as the older versions serialized it, without the parts added since and with a single sort column
+fn legacy_state(state: &State) -> serde_json::Value {
+    let legacy: serde_json::Value = serde_json::from_str(LEGACY_STATE).unwrap();
+    let mut value = serde_json::to_value(state).unwrap();
+    value
+        .as_object_mut()
+        .unwrap()
+        .retain(|key, _| legacy.get(key).is_some());
+    for table in ["/baking/baking_table", "/endorsmenents/endorsement_table"] {
+        let table = value.pointer_mut(table).unwrap().as_object_mut().unwrap();
+        for added in ["columns", "sort_specs", "followed"] {
+            table.remove(added);
+        }
+        table.insert("sorted_by".to_string(), json!(0));
+        table.insert("sort_order".to_string(), json!("Ascending"));
+    }
+    value
+}
+
+fn service() -> ServiceMocked {
+    ServiceMocked::new()
+}
+
+fn recording_store(name: &str) -> (PathBuf, Store<ServiceMocked>) {
+    let path = std::env::temp_dir().join(format!(
+        "tezedge_tui_{}_{}.ndjson",
+        name,
+        std::process::id()
+    ));
+    let log = slog::Logger::root(slog::Discard, slog::o!());
+    let state = State::new(None, log.clone());
+    let recorder = ActionRecorder::create(&path, &log).unwrap();
+    recorder.checkpoint(&state);
+    let service = ServiceMocked {
+        action_recorder: Some(recorder),
+        ..service()
+    };
+
+    let store = Store::new(reducer, effects, service, SystemTime::now(), state);
+    (path, store)
+}
+
+fn finish(store: &mut Store<ServiceMocked>) {
+    if let Some(recorder) = store.service().action_recorder.clone() {
+        recorder.finish(store.state());
+    }
+}
+
+#[test]
+pub fn recording_survives_a_crash() {
+    let (path, mut store) = recording_store("crashed");
+    store.dispatch(CurrentHeadHeaderChangedAction {
+        current_head_header: CurrentHeadHeader {
+            level: 200,
+            ..Default::default()
+        },
+    });
+
+    // no end state and a record cut short by the crash
+    let mut file = fs::OpenOptions::new().append(true).open(&path).unwrap();
+    file.write_all(b"{\"Action\":{\"id\":").unwrap();
+
+    let recording = ActionRecording::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(recording.checkpoints.len(), 1);
+    assert_eq!(recording.checkpoints[0].state.current_head_header.level, 0);
+    assert!(matches!(
+        recording.actions[0].action,
+        Action::CurrentHeadHeaderChanged(_)
+    ));
+    // the actions dispatched by the effects are recorded too
+    assert!(recording.actions.len() > 1);
+    assert!(recording.actions[1..].iter().all(|action| action.depth > 0));
+}
+
+#[test]
+pub fn replay_resumes_from_a_checkpoint() {
+    let (path, mut store) = recording_store("checkpoints");
+    for _ in 0..=ACTION_CHECKPOINT_INTERVAL {
+        store.dispatch(TuiDeltaToggleKeyPushedAction {});
+    }
+    finish(&mut store);
+
+    let recording = ActionRecording::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    // the initial state, the periodic checkpoint and the end state
+    let positions: Vec<_> = recording
+        .checkpoints
+        .iter()
+        .map(|checkpoint| checkpoint.position)
+        .collect();
+    assert_eq!(
+        positions,
+        vec![
+            0,
+            ACTION_CHECKPOINT_INTERVAL,
+            ACTION_CHECKPOINT_INTERVAL + 1
+        ]
+    );
+
+    let (state, actions) = recording.resume(1).unwrap();
+    assert_eq!(actions.len(), 1);
+    let mut store = Store::new(
+        reducer,
+        effects,
+        service(),
+        SystemTime::now(),
+        state.clone(),
+    );
+    for action in actions {
+        store.dispatch(action.action.clone());
+    }
+    assert_eq!(
+        store.state().delta_toggle,
+        recording.end_state().unwrap().delta_toggle
+    );
+    assert!(recording.resume(3).is_none());
+}
+
+#[test]
+pub fn replay_reports_the_divergent_action() {
+    let (path, mut store) = recording_store("replayed");
+    store.dispatch(TuiDeltaToggleKeyPushedAction {});
+    store.dispatch(CurrentHeadHeaderChangedAction {
+        current_head_header: CurrentHeadHeader {
+            level: 200,
+            ..Default::default()
+        },
+    });
+    store.dispatch(TuiDeltaToggleKeyPushedAction {});
+    finish(&mut store);
+
+    let recording = ActionRecording::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    // the level is retained with the time of the replayed action
+    let (_, report) = Automaton::replay_actions(&recording, 0, service(), &["alerts"]).unwrap();
+    assert_eq!(report.replayed, 3);
+    assert!(!report.matches());
+    let divergence = report.divergence.unwrap();
+    assert_eq!(divergence.position, 1);
+    assert!(matches!(
+        divergence.action.action,
+        Action::CurrentHeadHeaderChanged(_)
+    ));
+    assert!(divergence
+        .changes
+        .iter()
+        .any(|change| change.path == "retention.level_times.200"));
+
+    let (automaton, report) =
+        Automaton::replay_actions(&recording, 0, service(), &["alerts", "retention"]).unwrap();
+    assert!(report.matches(), "{}", report);
+    assert!(report.divergence.is_none());
+    assert_eq!(automaton.state().current_head_header.level, 200);
+}
+
+#[test]
+pub fn legacy_dump_is_loaded() {
+    let (path, mut store) = recording_store("legacy");
+    let init_state = store.state().clone();
+    store.dispatch(TuiDeltaToggleKeyPushedAction {});
+    store.dispatch(CurrentHeadHeaderChangedAction {
+        current_head_header: CurrentHeadHeader {
+            level: 200,
+            ..Default::default()
+        },
+    });
+    finish(&mut store);
+    let recording = ActionRecording::load(&path).unwrap();
+
+    // the whole run in a single json object
+    #[derive(Serialize)]
+    struct AutomatonDump<'a> {
+        init_state: serde_json::Value,
+        end_state: serde_json::Value,
+        actions: &'a [ActionWithMeta],
+    }
+    let dump = AutomatonDump {
+        init_state: legacy_state(&init_state),
+        end_state: legacy_state(store.state()),
+        actions: &recording.actions,
+    };
+    fs::write(&path, serde_json::to_vec(&dump).unwrap()).unwrap();
+    let mut legacy = ActionRecording::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(legacy.actions.len(), recording.actions.len());
+    let positions: Vec<_> = legacy
+        .checkpoints
+        .iter()
+        .map(|checkpoint| checkpoint.position)
+        .collect();
+    assert_eq!(positions, vec![0, recording.actions.len()]);
+    assert_eq!(
+        legacy.end_state().unwrap().delta_toggle,
+        store.state().delta_toggle
+    );
+
+    // the parts added since are missing in the recorded end state, only the older parts are compared
+    let (_, report) =
+        Automaton::replay_actions(&legacy, 0, service(), &["alerts", "retention"]).unwrap();
+    assert!(report.matches(), "{}", report);
+    legacy.compared = None;
+    let (_, report) =
+        Automaton::replay_actions(&legacy, 0, service(), &["alerts", "retention"]).unwrap();
+    assert!(report
+        .changes
+        .iter()
+        .any(|change| change.path.starts_with("blocks")));
+}
+
+#[test]
+pub fn legacy_state_is_loaded() {
+    let mut legacy: serde_json::Value = serde_json::from_str(LEGACY_STATE).unwrap();
+    let state: State = serde_json::from_value(legacy.clone()).unwrap();
+    // every column is shown in the order of the headers
+    let table = &state.baking.baking_table;
+    assert_eq!(table.visible_columns(), (0..6).collect::<Vec<_>>());
+    assert_eq!(table.sort_specs(), [SortSpec::new(0, SortOrder::Ascending)]);
+    let endorsement_headers = legacy["endorsmenents"]["endorsement_table"]["headers"]
+        .as_array()
+        .unwrap()
+        .len();
+    assert_eq!(
+        state.endorsmenents.endorsement_table.visible_columns(),
+        (0..endorsement_headers).collect::<Vec<_>>()
+    );
+
+    // the single sort column becomes the primary one
+    legacy["baking"]["baking_table"]["sorted_by"] = json!(2);
+    legacy["baking"]["baking_table"]["sort_order"] = json!("Descending");
+    let state: State = serde_json::from_value(legacy).unwrap();
+    assert_eq!(
+        state.baking.baking_table.sort_specs(),
+        [SortSpec::new(2, SortOrder::Descending)]
+    );
+}
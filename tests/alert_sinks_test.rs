@@ -0,0 +1,138 @@
+use pretty_assertions::assert_eq;
+use std::{
+    fs,
+    sync::{mpsc, Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+use tezedge_tui::{
+    alerts::Alert,
+    configuration::{AlertSinkConfig, AlertSinkKind},
+    services::alert_service::{
+        AlertService, AlertServiceDefault, AlertSink, AlertThrottle, ExecSink, FileSink,
+    },
+};
+use warp::Filter;
+
+fn alert(key: &str) -> Alert {
+    Alert {
+        key: key.to_string(),
+        rule: String::from("behind_remote"),
+        message: format!("Alert {}", key),
+        level: 100,
+        fired: 1_000,
+        resolved: None,
+    }
+}
+
+fn log() -> slog::Logger {
+    slog::Logger::root(slog::Discard, slog::o!())
+}
+
+#[test]
+pub fn webhook_sink_posts_throttled_alerts() {
+    // stand-in for the relay, forwards the posted alerts to the test
+    let (sender, receiver) = mpsc::channel();
+    let sender = Arc::new(Mutex::new(sender));
+    let route = warp::post()
+        .and(warp::path("alerts"))
+        .and(warp::body::json())
+        .map(move |alert: Alert| {
+            let _ = sender.lock().unwrap().send(alert);
+            warp::reply()
+        });
+
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let (address, server) = {
+        let _guard = runtime.enter();
+        warp::serve(route).bind_ephemeral(([127, 0, 0, 1], 0))
+    };
+    runtime.spawn(server);
+
+    let mut service = AlertServiceDefault::new(
+        &[AlertSinkConfig {
+            kind: AlertSinkKind::Webhook {
+                url: format!("http://{}/alerts", address),
+            },
+            max_per_minute: 2,
+            dedup_seconds: 60,
+        }],
+        &log(),
+    );
+    // the repeated alert and the alert over the rate limit are dropped
+    for key in ["a", "a", "b", "c"] {
+        service.deliver(&alert(key));
+    }
+
+    let timeout = Duration::from_secs(10);
+    let mut received: Vec<String> = (0..2)
+        .map(|_| receiver.recv_timeout(timeout).unwrap().key)
+        .collect();
+    received.sort();
+    assert_eq!(received, vec!["a", "b"]);
+    assert!(receiver.recv_timeout(Duration::from_millis(500)).is_err());
+}
+
+#[test]
+pub fn throttle_limits_rate_and_repeats() {
+    let start = Instant::now();
+    let mut throttle = AlertThrottle::new(2, 120);
+
+    assert!(throttle.allow(&alert("a"), start));
+    assert!(!throttle.allow(&alert("a"), start));
+    assert!(throttle.allow(&alert("b"), start));
+    assert!(!throttle.allow(&alert("c"), start));
+
+    // the rate limit is over, but "a" is still a repeat
+    let later = start + Duration::from_secs(61);
+    assert!(!throttle.allow(&alert("a"), later));
+    assert!(throttle.allow(&alert("c"), later));
+
+    let much_later = start + Duration::from_secs(121);
+    assert!(throttle.allow(&alert("a"), much_later));
+
+    // zero turns the checks off
+    let mut unlimited = AlertThrottle::new(0, 0);
+    assert!((0..10).all(|_| unlimited.allow(&alert("a"), start)));
+}
+
+#[test]
+pub fn file_and_exec_sinks_write_the_alerts() {
+    let dir = std::env::temp_dir().join(format!("tezedge_tui_sinks_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+
+    let file_path = dir.join("alerts.ndjson");
+    let mut file_sink = FileSink::new(file_path.clone());
+    file_sink.send(&alert("a")).unwrap();
+    file_sink.send(&alert("b")).unwrap();
+    let logged: Vec<Alert> = fs::read_to_string(&file_path)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(logged, vec![alert("a"), alert("b")]);
+
+    let exec_path = dir.join("exec.txt");
+    let mut exec_sink = ExecSink::new(
+        format!(
+            "echo \"$TEZEDGE_ALERT_RULE $TEZEDGE_ALERT_LEVEL $TEZEDGE_ALERT_MESSAGE\" > {}",
+            exec_path.display()
+        ),
+        &log(),
+    );
+    exec_sink.send(&alert("a")).unwrap();
+
+    // the command runs in the background
+    let deadline = Instant::now() + Duration::from_secs(10);
+    let output = loop {
+        match fs::read_to_string(&exec_path) {
+            Ok(output) if output.ends_with('\n') => break output,
+            _ if Instant::now() > deadline => panic!("the alert command did not run"),
+            _ => thread::sleep(Duration::from_millis(20)),
+        }
+    };
+    assert_eq!(output, "behind_remote 100 Alert a\n");
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,89 @@
+use pretty_assertions::assert_eq;
+use std::time::SystemTime;
+
+use services_mocked::ServiceMocked;
+use tezedge_tui::{
+    alerts::{AlertDismissAction, AlertRule, ApplicationPhase},
+    automaton::{effects, reducer, State, Store},
+    configuration::TuiConfig,
+    services::rpc_service_async::CurrentHeadHeader,
+    terminal_ui::{BestRemoteLevelChangedAction, CurrentHeadHeaderChangedAction},
+};
+
+mod services_mocked;
+
+#[test]
+pub fn alert_fires_once_and_resolves() {
+    let service = ServiceMocked::new();
+    let log = slog::Logger::root(slog::Discard, slog::o!());
+    let state = State::new(None, log);
+    let mut store = Store::new(reducer, effects, service, SystemTime::now(), state);
+
+    let head = |level: i32| CurrentHeadHeaderChangedAction {
+        current_head_header: CurrentHeadHeader {
+            level,
+            ..Default::default()
+        },
+    };
+
+    store.dispatch(head(100));
+    store.dispatch(BestRemoteLevelChangedAction { level: Some(105) });
+    // the condition still holds, so the alert does not fire again
+    store.dispatch(head(101));
+
+    let alerts = store.state().alerts.clone();
+    assert_eq!(alerts.log.len(), 1);
+    assert_eq!(alerts.log[0].rule, "behind_remote");
+    assert_eq!(alerts.log[0].level, 100);
+    assert!(alerts.outbox.is_empty());
+    assert_eq!(store.service().alert.delivered, alerts.log);
+
+    store.dispatch(head(104));
+    let alerts = &store.state().alerts;
+    assert!(alerts.active.is_empty());
+    assert!(alerts.log[0].resolved.is_some());
+
+    assert!(store.dispatch(AlertDismissAction {}));
+    assert_eq!(store.state().alerts.banner, None);
+    assert!(!store.dispatch(AlertDismissAction {}));
+}
+
+#[test]
+pub fn rules_are_configurable() {
+    let config: TuiConfig = serde_json::from_str(
+        r#"{
+            "alerts": {
+                "rules": [
+                    { "rule": "connection_lost", "seconds": 60 },
+                    { "rule": "slow_application", "phase": "load_data", "millis": 500 },
+                    { "rule": "endorsement_not_injected" }
+                ]
+            }
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(
+        config.alerts.rules,
+        vec![
+            AlertRule::ConnectionLost { seconds: 60 },
+            AlertRule::SlowApplication {
+                phase: ApplicationPhase::LoadData,
+                millis: 500
+            },
+            AlertRule::EndorsementNotInjected,
+        ]
+    );
+    assert!(config.alerts.sinks.is_empty());
+
+    // the default rules apply when none are configured
+    let config: TuiConfig = serde_json::from_str("{}").unwrap();
+    assert_eq!(config.alerts.rules, AlertRule::defaults());
+
+    let log = slog::Logger::root(slog::Discard, slog::o!());
+    let mut state = State::new(None, log);
+    state.alerts.last_head_received = Some(0);
+    let connection_lost = AlertRule::ConnectionLost { seconds: 60 };
+    assert_eq!(connection_lost.evaluate(&state, 60_000_000_000), None);
+    let condition = connection_lost.evaluate(&state, 61_000_000_000).unwrap();
+    assert_eq!(condition.key, "connection_lost");
+}
@@ -0,0 +1,66 @@
+use pretty_assertions::assert_eq;
+use time::OffsetDateTime;
+
+use tezedge_tui::{
+    baking::BlockApplicationStatistics,
+    blocks::{BlocksState, RecentBlock, RECENT_BLOCKS_CAPACITY},
+};
+
+fn block(level: i32) -> RecentBlock {
+    RecentBlock {
+        level,
+        hash: format!("hash{}", level),
+        priority: 0,
+        timestamp: OffsetDateTime::UNIX_EPOCH,
+    }
+}
+
+#[test]
+pub fn new_heads_keep_the_selected_block() {
+    let mut blocks = BlocksState::default();
+    blocks.push(block(1));
+    blocks.push(block(2));
+    blocks.selected = Some(1);
+    assert_eq!(blocks.selected_block(), Some(&block(1)));
+
+    blocks.push(block(3));
+    // the same head received again is not duplicated
+    blocks.push(block(3));
+    assert_eq!(blocks.recent.len(), 3);
+    assert_eq!(blocks.selected_block(), Some(&block(1)));
+
+    for level in 4..200 {
+        blocks.push(block(level));
+    }
+    assert_eq!(blocks.recent.len(), RECENT_BLOCKS_CAPACITY);
+    assert_eq!(blocks.recent[0].level, 199);
+    // the selected block dropped out of the list, the selection stays on the oldest block
+    assert_eq!(blocks.selected, Some(RECENT_BLOCKS_CAPACITY - 1));
+}
+
+#[test]
+pub fn application_and_propagation_times() {
+    let statistics = BlockApplicationStatistics {
+        receive_timestamp: 1_500_000_000,
+        download_block_header_start: Some(2_000),
+        load_data_start: Some(5_000),
+        apply_block_end: Some(9_000),
+        store_result_end: Some(12_000),
+        ..Default::default()
+    };
+    assert_eq!(statistics.total_application_time(), Some(10_000));
+
+    let block_timestamp = OffsetDateTime::from_unix_timestamp(1).unwrap();
+    assert_eq!(
+        statistics.received_delay(block_timestamp),
+        Some(500_000_000)
+    );
+
+    // a block timestamp from the future does not give a delay
+    let block_timestamp = OffsetDateTime::from_unix_timestamp(2).unwrap();
+    assert_eq!(statistics.received_delay(block_timestamp), None);
+    assert_eq!(
+        BlockApplicationStatistics::default().total_application_time(),
+        None
+    );
+}
@@ -0,0 +1,72 @@
+use pretty_assertions::assert_eq;
+
+use tezedge_tui::{
+    automaton::State, common::draw_chain_info, services::rpc_service_async::NetworkConstants,
+};
+use tui::{backend::TestBackend, Terminal};
+
+#[test]
+pub fn constants_of_different_protocols_are_parsed() {
+    let granada: NetworkConstants = serde_json::from_str(
+        r#"{
+            "preserved_cycles": 5,
+            "blocks_per_cycle": 8192,
+            "blocks_per_voting_period": 40960,
+            "time_between_blocks": ["60", "40"],
+            "endorsers_per_block": 256,
+            "minimal_block_delay": "30",
+            "delay_per_missed_endorsement": "4"
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(granada.time_between_blocks, vec![60, 40]);
+    assert_eq!(granada.delay_per_missed_endorsement, Some(4));
+    assert_eq!(granada.endorsement_slots(), Some(256));
+    assert_eq!(granada.voting_period_length(), Some(40960));
+
+    let tenderbake: NetworkConstants = serde_json::from_str(
+        r#"{
+            "preserved_cycles": 5,
+            "blocks_per_cycle": 8192,
+            "cycles_per_voting_period": 5,
+            "consensus_committee_size": 7000,
+            "minimal_block_delay": "30"
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(tenderbake.time_between_blocks, Vec::<i64>::new());
+    assert_eq!(tenderbake.delay_per_missed_endorsement, None);
+    assert_eq!(tenderbake.endorsement_slots(), Some(7000));
+    assert_eq!(tenderbake.voting_period_length(), Some(40960));
+}
+
+#[test]
+pub fn chain_info_panel_shows_the_constants() {
+    let log = slog::Logger::root(slog::Discard, slog::o!());
+    let mut state = State::new(None, log);
+    state.current_head_header.chain_id = String::from("NetXdQprcVkpaWU");
+    state.network_constants.minimal_block_delay = 30;
+    state.network_constants.blocks_per_cycle = 8192;
+    state.network_constants.consensus_committee_size = Some(7000);
+
+    let mut terminal = Terminal::new(TestBackend::new(100, 20)).unwrap();
+
+    // closed by default
+    terminal.draw(|f| draw_chain_info(&state, f)).unwrap();
+    let empty = terminal.backend().buffer().clone();
+    assert!(empty.content.iter().all(|cell| cell.symbol == " "));
+
+    state.ui.chain_info_open = true;
+    terminal.draw(|f| draw_chain_info(&state, f)).unwrap();
+    let text: String = terminal
+        .backend()
+        .buffer()
+        .content
+        .iter()
+        .map(|cell| cell.symbol.as_str())
+        .collect();
+    assert!(text.contains("NetXdQprcVkpaWU"));
+    assert!(text.contains("Endorsement slots"));
+    assert!(text.contains("7000"));
+    assert!(text.contains("0 (0/8192)"));
+}
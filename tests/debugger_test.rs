@@ -0,0 +1,103 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use std::{fs, time::SystemTime};
+
+use services_mocked::ServiceMocked;
+use tezedge_tui::{
+    automaton::{effects, reducer, ActionRecorder, ActionRecording, State, Store},
+    debugger::{diff_states, diff_values, StateChange, Timeline},
+    history::{HistoryPromptEditAction, HistoryPromptToggleAction, PromptEdit},
+    services::rpc_service_async::CurrentHeadHeader,
+    terminal_ui::CurrentHeadHeaderChangedAction,
+};
+
+mod services_mocked;
+
+fn recorded_actions() -> ActionRecording {
+    let log = slog::Logger::root(slog::Discard, slog::o!());
+    let path = std::env::temp_dir().join(format!(
+        "tezedge_tui_debugger_{}.ndjson",
+        std::process::id()
+    ));
+    let state = State::new(None, log.clone());
+    let recorder = ActionRecorder::create(&path, &log).unwrap();
+    recorder.checkpoint(&state);
+    let service = ServiceMocked {
+        action_recorder: Some(recorder.clone()),
+        ..ServiceMocked::new()
+    };
+    let mut store = Store::new(reducer, effects, service, SystemTime::now(), state);
+
+    store.dispatch(CurrentHeadHeaderChangedAction {
+        current_head_header: CurrentHeadHeader {
+            level: 200,
+            ..Default::default()
+        },
+    });
+    store.dispatch(HistoryPromptToggleAction {});
+    store.dispatch(HistoryPromptEditAction {
+        edit: PromptEdit::Push('7'),
+    });
+
+    recorder.finish(store.state());
+
+    let recording = ActionRecording::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    recording
+}
+
+#[test]
+pub fn timeline_reproduces_the_recorded_states() {
+    let recording = recorded_actions();
+    // the effects of the new head dispatched nested actions
+    assert!(recording.actions.iter().any(|action| action.depth > 0));
+
+    let mut timeline = Timeline::new(recording);
+    // compared to the end state
+    assert_eq!(timeline.verified_checkpoints(), 1);
+    assert_eq!(timeline.diverged_at(), None);
+    assert_eq!(timeline.state_before().current_head_header.level, 0);
+    assert_eq!(timeline.state_after().current_head_header.level, 200);
+
+    timeline.next_top_level();
+    assert_eq!(timeline.state_before().history.prompt, None);
+    assert_eq!(timeline.state_after().history.prompt.as_deref(), Some(""));
+    assert_eq!(
+        diff_states(timeline.state_before(), timeline.state_after()),
+        vec![StateChange {
+            path: "history.prompt".to_string(),
+            before: Some(json!(null)),
+            after: Some(json!("")),
+        }]
+    );
+
+    timeline.seek(usize::MAX);
+    assert_eq!(timeline.position(), timeline.len() - 1);
+    assert_eq!(timeline.state_after().history.prompt.as_deref(), Some("7"));
+
+    // stepping back replays from the checkpoint
+    timeline.previous_top_level();
+    assert_eq!(timeline.state_after().history.prompt.as_deref(), Some(""));
+    timeline.step_forward();
+    assert_eq!(timeline.state_after().history.prompt.as_deref(), Some("7"));
+}
+
+#[test]
+pub fn diff_lists_the_changed_leaves() {
+    let before = json!({"level": 1, "heads": [1, 2], "removed": true});
+    let after = json!({"level": 2, "heads": [1, 2, 3], "added": {"x": 1}});
+
+    let paths: Vec<_> = diff_values(&before, &after)
+        .into_iter()
+        .map(|change| (change.path, change.before, change.after))
+        .collect();
+    assert_eq!(
+        paths,
+        vec![
+            ("heads[2]".to_string(), None, Some(json!(3))),
+            ("level".to_string(), Some(json!(1)), Some(json!(2))),
+            ("removed".to_string(), Some(json!(true)), None),
+            ("added".to_string(), None, Some(json!({"x": 1}))),
+        ]
+    );
+}
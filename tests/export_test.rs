@@ -0,0 +1,101 @@
+use pretty_assertions::assert_eq;
+use std::time::SystemTime;
+
+use services_mocked::ServiceMocked;
+use tezedge_tui::{
+    automaton::{effects, reducer, State, Store},
+    endorsements::{EndorsementStatusSortable, EndrosementsState},
+    export::{ExportMenuToggleAction, ExportScreenAction, ExportTableAction},
+    extensions::{ColumnEdit, ColumnLayout, ExportFormat},
+};
+
+mod services_mocked;
+
+/// Endorsements table showing only the baker and the delta columns
+fn endorsements() -> EndrosementsState {
+    let mut state = EndrosementsState::default();
+    let mut rows = vec![
+        EndorsementStatusSortable::new(String::from("tz1a"), 0),
+        EndorsementStatusSortable::new(String::from("tz1b"), 1),
+    ];
+    rows[0].delta = Some(1_500_000);
+
+    let table = &mut state.endorsement_table;
+    table.set_content(rows, true);
+    for index in [0, 2, 4, 5, 6, 7, 8, 9] {
+        table.edit_column(index, &ColumnEdit::ToggleVisible);
+    }
+    state
+}
+
+#[test]
+pub fn table_is_exported_with_rendered_values() {
+    let table = endorsements().endorsement_table;
+
+    assert_eq!(
+        table.export(ExportFormat::Csv, false, true),
+        "Baker,Delta\ntz1a,1.50ms\ntz1b,-\n"
+    );
+    assert_eq!(
+        table.export(ExportFormat::Markdown, false, true),
+        "| Baker | Delta |\n| --- | --- |\n| tz1a | 1.50ms |\n| tz1b | - |\n"
+    );
+}
+
+#[test]
+pub fn table_is_exported_with_raw_nanoseconds() {
+    let table = endorsements().endorsement_table;
+
+    assert_eq!(
+        table.export(ExportFormat::Csv, true, true),
+        "Baker,Delta\ntz1a,1500000\ntz1b,\n"
+    );
+
+    let json: serde_json::Value =
+        serde_json::from_str(&table.export(ExportFormat::Json, true, true)).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!([
+            { "Baker": "tz1a", "Delta": 1500000 },
+            { "Baker": "tz1b", "Delta": null },
+        ])
+    );
+}
+
+#[test]
+pub fn exports_are_written_through_the_export_service() {
+    let service = ServiceMocked::new();
+    let mut state = State::new(None, slog::Logger::root(slog::Discard, slog::o!()));
+    state.endorsmenents = endorsements();
+    let mut store = Store::new(reducer, effects, service, SystemTime::now(), state);
+
+    // nothing is exported while the menu is closed
+    assert!(!store.dispatch(ExportTableAction {
+        format: ExportFormat::Csv
+    }));
+
+    store.dispatch(ExportMenuToggleAction {});
+    store.dispatch(ExportTableAction {
+        format: ExportFormat::Csv,
+    });
+    store.dispatch(ExportScreenAction {});
+
+    assert_eq!(
+        store.state().export.last_result,
+        Some(Ok(String::from("screen.txt")))
+    );
+
+    let exports = &store.service().export.exports;
+    assert_eq!(exports.len(), 2);
+    assert_eq!(
+        exports[0],
+        (
+            String::from("endorsements.csv"),
+            String::from("Baker,Delta\ntz1a,1.50ms\ntz1b,-\n")
+        )
+    );
+    // the screen is dumped without the export menu
+    assert_eq!(exports[1].0, "screen.txt");
+    assert!(exports[1].1.contains("ENDORSEMENTS"));
+    assert!(!exports[1].1.contains("Export (e to close)"));
+}
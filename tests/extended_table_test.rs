@@ -0,0 +1,182 @@
+use pretty_assertions::assert_eq;
+use tezedge_tui::{
+    configuration::TableConfig,
+    endorsements::{EndorsementState, EndorsementStatusSortable, EndrosementsState},
+    extensions::{ColumnEdit, ColumnLayout, SortOrder, SortSpec, TableRowKey},
+};
+use tui::layout::Constraint;
+
+fn endorsers(bakers: &[&str]) -> Vec<EndorsementStatusSortable> {
+    bakers
+        .iter()
+        .enumerate()
+        .map(|(slot_count, baker)| EndorsementStatusSortable::new(baker.to_string(), slot_count))
+        .collect()
+}
+
+fn selected_baker(state: &EndrosementsState) -> Option<String> {
+    let table = &state.endorsement_table;
+    table
+        .table_state
+        .selected()
+        .map(|index| table.content[index].row_key())
+}
+
+#[test]
+pub fn selection_persists_across_content_refresh() {
+    let mut state = EndrosementsState::default();
+    let table = &mut state.endorsement_table;
+
+    table.set_content(endorsers(&["tz1a", "tz1b", "tz1c"]), true);
+    table.select(Some(1));
+    assert_eq!(selected_baker(&state), Some(String::from("tz1b")));
+
+    // the slot counts changed, so the sorting by slots moves tz1b to the end
+    let mut refreshed = endorsers(&["tz1a", "tz1b", "tz1c"]);
+    refreshed[1].slot_count = 10;
+    state.endorsement_table.set_content(refreshed, true);
+    assert_eq!(selected_baker(&state), Some(String::from("tz1b")));
+    assert_eq!(state.endorsement_table.table_state.selected(), Some(2));
+}
+
+#[test]
+pub fn selection_stays_in_bounds_when_row_disappears() {
+    let mut state = EndrosementsState::default();
+    let table = &mut state.endorsement_table;
+
+    table.set_content(endorsers(&["tz1a", "tz1b", "tz1c"]), true);
+    table.select(Some(2));
+
+    table.set_content(endorsers(&["tz1a"]), true);
+    assert_eq!(table.table_state.selected(), Some(0));
+
+    table.set_content(Vec::new(), true);
+    assert_eq!(table.table_state.selected(), None);
+}
+
+#[test]
+pub fn followed_row_is_restored_when_it_reappears() {
+    let mut state = EndrosementsState::default();
+    let table = &mut state.endorsement_table;
+
+    table.set_content(endorsers(&["tz1a", "tz1b", "tz1c"]), true);
+    table.select(Some(1));
+    table.toggle_follow();
+    assert_eq!(table.followed(), Some(&String::from("tz1b")));
+
+    table.set_content(endorsers(&["tz1a", "tz1c"]), true);
+    table.set_content(endorsers(&["tz1c", "tz1a", "tz1b"]), true);
+    assert_eq!(selected_baker(&state), Some(String::from("tz1b")));
+
+    // moving the selection manually stops following
+    state.endorsement_table.select(Some(0));
+    assert_eq!(state.endorsement_table.followed(), None);
+}
+
+fn sorted_bakers(state: &EndrosementsState) -> Vec<String> {
+    state
+        .endorsement_table
+        .content
+        .iter()
+        .map(|row| row.row_key())
+        .collect()
+}
+
+#[test]
+pub fn sorts_by_secondary_key_with_missing_values_last() {
+    let mut state = EndrosementsState::default();
+    let mut rows = endorsers(&["tz1a", "tz1b", "tz1c", "tz1d"]);
+    rows[0].delta = Some(30);
+    rows[1].delta = None;
+    rows[2].delta = Some(10);
+    rows[3].delta = Some(20);
+    rows[3].state = EndorsementState::Applied;
+
+    let table = &mut state.endorsement_table;
+    table.set_rendered(10);
+    // status column first, then delta as the secondary key
+    table.next();
+    table.next();
+    table.sort_by_selected(false);
+    table.next();
+    table.sort_by_selected(true);
+    table.set_content(rows.clone(), false);
+    assert_eq!(sorted_bakers(&state), vec!["tz1c", "tz1a", "tz1b", "tz1d"]);
+
+    // descending delta still keeps the missing one last
+    let table = &mut state.endorsement_table;
+    table.sort_by_selected(true);
+    table.set_content(rows, false);
+    assert_eq!(sorted_bakers(&state), vec!["tz1a", "tz1c", "tz1b", "tz1d"]);
+    assert_eq!(
+        state.endorsement_table.sort_specs(),
+        &[
+            SortSpec::new(2, SortOrder::Ascending),
+            SortSpec::new(3, SortOrder::Descending)
+        ]
+    );
+}
+
+#[test]
+pub fn sort_config_is_applied_to_table() {
+    let mut state = EndrosementsState::default();
+    let config = TableConfig {
+        sort: vec![
+            SortSpec::new(3, SortOrder::Descending),
+            SortSpec::new(3, SortOrder::Ascending),
+            SortSpec::new(42, SortOrder::Ascending),
+            SortSpec::new(1, SortOrder::Ascending),
+        ],
+        ..Default::default()
+    };
+
+    state.endorsement_table.apply_config(&config);
+    assert_eq!(
+        state.endorsement_table.table_config().sort,
+        vec![
+            SortSpec::new(3, SortOrder::Descending),
+            SortSpec::new(1, SortOrder::Ascending)
+        ]
+    );
+}
+
+#[test]
+pub fn column_layout_edits_are_rendered_and_restored_from_config() {
+    let mut state = EndrosementsState::default();
+    let table = &mut state.endorsement_table;
+
+    // hide "Slots", move "Delta" before "Status" and pin "Apply"
+    table.edit_column(0, &ColumnEdit::ToggleVisible);
+    let delta = table.edit_column(3, &ColumnEdit::MoveUp);
+    assert_eq!(delta, 2);
+    let apply = table.edit_column(8, &ColumnEdit::TogglePin);
+    assert_eq!(apply, 4);
+    table.edit_column(1, &ColumnEdit::Widen);
+
+    assert_eq!(&table.visible_columns()[..5], &[1, 3, 2, 8, 4]);
+    assert_eq!(table.fixed(), 4);
+    assert_eq!(table.column_constraint(1), Constraint::Length(37));
+
+    // a fresh table gets the same layout from the persisted config
+    let config = table.table_config();
+    let mut restored = EndrosementsState::default();
+    restored.endorsement_table.apply_config(&config);
+    assert_eq!(
+        restored.endorsement_table.columns(),
+        state.endorsement_table.columns()
+    );
+    assert_eq!(restored.endorsement_table.fixed(), 4);
+}
+
+#[test]
+pub fn last_visible_column_cannot_be_hidden() {
+    let mut state = EndrosementsState::default();
+    let table = &mut state.endorsement_table;
+
+    for index in 0..table.columns().len() {
+        table.edit_column(index, &ColumnEdit::ToggleVisible);
+    }
+
+    assert_eq!(table.visible_columns().len(), 1);
+    assert_eq!(table.selected(), 0);
+}
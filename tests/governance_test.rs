@@ -0,0 +1,79 @@
+use pretty_assertions::assert_eq;
+use std::time::SystemTime;
+
+use services_mocked::ServiceMocked;
+use tezedge_tui::{
+    automaton::{effects, reducer, State, Store},
+    governance::{Proposal, ProposalsReceivedAction, ProtocolActivationDismissAction},
+    services::rpc_service_async::CurrentHeadHeader,
+    terminal_ui::CurrentHeadHeaderChangedAction,
+};
+
+mod services_mocked;
+
+fn store() -> Store<ServiceMocked> {
+    let service = ServiceMocked::new();
+    let log = slog::Logger::root(slog::Discard, slog::o!());
+    Store::new(
+        reducer,
+        effects,
+        service,
+        SystemTime::now(),
+        State::new(None, log),
+    )
+}
+
+fn header(level: i32, protocol: &str) -> CurrentHeadHeader {
+    CurrentHeadHeader {
+        level,
+        protocol: protocol.to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+pub fn protocol_activation_is_detected_and_dismissed() {
+    let mut store = store();
+
+    for (level, protocol) in [(10, "PtA"), (11, "PtA"), (12, "PtB"), (13, "PtB")] {
+        store.dispatch(CurrentHeadHeaderChangedAction {
+            current_head_header: header(level, protocol),
+        });
+    }
+
+    let governance = &store.state().governance;
+    assert_eq!(governance.activations.len(), 1);
+    let activation = governance.activation_banner.clone().unwrap();
+    assert_eq!(
+        (
+            activation.level,
+            activation.previous_protocol.as_str(),
+            activation.protocol.as_str()
+        ),
+        (12, "PtA", "PtB")
+    );
+
+    assert!(store.dispatch(ProtocolActivationDismissAction {}));
+    assert_eq!(store.state().governance.activation_banner, None);
+    // nothing left to dismiss
+    assert!(!store.dispatch(ProtocolActivationDismissAction {}));
+}
+
+#[test]
+pub fn proposals_are_ordered_by_voting_power() {
+    let mut store = store();
+
+    // the voting power is a number in older protocols and a string in newer ones
+    let proposals: Vec<Proposal> =
+        serde_json::from_str(r#"[["PtA", 100], ["PtB", "300"], ["PtC", 100]]"#).unwrap();
+    store.dispatch(ProposalsReceivedAction { proposals });
+
+    let ordered: Vec<(&str, i64)> = store
+        .state()
+        .governance
+        .proposals
+        .iter()
+        .map(|proposal| (proposal.protocol(), proposal.voting_power()))
+        .collect();
+    assert_eq!(ordered, vec![("PtB", 300), ("PtA", 100), ("PtC", 100)]);
+}
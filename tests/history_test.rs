@@ -0,0 +1,120 @@
+use pretty_assertions::assert_eq;
+use std::time::SystemTime;
+
+use services_mocked::ServiceMocked;
+use tezedge_tui::{
+    automaton::{effects, reducer, State, Store},
+    baking::{BlockApplicationStatistics, PerPeerBlockStatistics},
+    history::{
+        HistoryApplicationStatisticsReceivedAction, HistoryCloseAction, HistoryLevelGetAction,
+        HistoryLevelSubmitAction, HistoryPerPeerBlockStatisticsReceivedAction,
+        HistoryPromptEditAction, HistoryPromptToggleAction, PromptEdit,
+    },
+    inspector::InspectorRpcReportsReceivedAction,
+    services::rpc_service_async::{CurrentHeadHeader, RpcCallReport},
+    terminal_ui::CurrentHeadHeaderChangedAction,
+};
+
+mod services_mocked;
+
+fn statistics(hash: &str) -> BlockApplicationStatistics {
+    BlockApplicationStatistics {
+        block_hash: hash.to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+pub fn past_level_statistics_are_fetched_once() {
+    let service = ServiceMocked::new();
+    let log = slog::Logger::root(slog::Discard, slog::o!());
+    let state = State::new(None, log);
+    let mut store = Store::new(reducer, effects, service, SystemTime::now(), state);
+
+    store.dispatch(CurrentHeadHeaderChangedAction {
+        current_head_header: CurrentHeadHeader {
+            level: 200,
+            ..Default::default()
+        },
+    });
+
+    store.dispatch(HistoryPromptToggleAction {});
+    for key in ['1', '2', 'x', '4', '5'] {
+        store.dispatch(HistoryPromptEditAction {
+            edit: PromptEdit::Push(key),
+        });
+    }
+    store.dispatch(HistoryPromptEditAction {
+        edit: PromptEdit::Pop,
+    });
+    assert_eq!(store.state().history.prompt.as_deref(), Some("124"));
+
+    assert!(store.dispatch(HistoryLevelSubmitAction {}));
+    assert_eq!(store.state().history.prompt, None);
+    assert_eq!(store.state().history.level, Some(124));
+    // the submit started the fetch
+    assert!(store.state().history.cache.contains_key(&124));
+    assert!(!store.dispatch(HistoryApplicationStatisticsReceivedAction {
+        level: 123,
+        application_statistics: vec![statistics("other")],
+    }));
+
+    store.dispatch(HistoryApplicationStatisticsReceivedAction {
+        level: 124,
+        application_statistics: vec![statistics("fork"), statistics("head")],
+    });
+    store.dispatch(HistoryPerPeerBlockStatisticsReceivedAction {
+        level: 124,
+        per_peer_block_statistics: vec![PerPeerBlockStatistics {
+            block_hash: String::from("head"),
+            ..Default::default()
+        }],
+    });
+
+    let cached = &store.state().history.cache[&124];
+    assert!(cached.is_complete());
+    assert_eq!(
+        cached
+            .block_statistics()
+            .map(|stats| stats.block_hash.as_str()),
+        Some("head")
+    );
+
+    // the level is final, the cached statistics are reused
+    assert!(!store.dispatch(HistoryLevelGetAction { level: 124 }));
+    // the statistics of the head are still growing
+    assert!(store.dispatch(HistoryLevelGetAction { level: 200 }));
+
+    assert!(store.dispatch(HistoryCloseAction {}));
+    assert_eq!(store.state().history.level, None);
+}
+
+#[test]
+pub fn failed_fetch_is_recorded() {
+    let service = ServiceMocked::new();
+    let log = slog::Logger::root(slog::Discard, slog::o!());
+    let state = State::new(None, log);
+    let mut store = Store::new(reducer, effects, service, SystemTime::now(), state);
+
+    store.dispatch(HistoryLevelGetAction { level: 124 });
+    let call = store.state().inspector.calls[0].clone();
+    store.dispatch(InspectorRpcReportsReceivedAction {
+        reports: vec![RpcCallReport {
+            id: call.id,
+            call: call.call,
+            latency: 1_000_000,
+            status: Some(500),
+            size: 0,
+            preview: String::new(),
+            error: Some(String::from("Internal server error")),
+        }],
+    });
+    assert_eq!(
+        store.state().history.cache[&124].error.as_deref(),
+        Some("Internal server error")
+    );
+
+    // fetching again clears the error
+    assert!(store.dispatch(HistoryLevelGetAction { level: 124 }));
+    assert_eq!(store.state().history.cache[&124].error, None);
+}
@@ -0,0 +1,190 @@
+use pretty_assertions::assert_eq;
+use std::time::SystemTime;
+
+use services_mocked::ServiceMocked;
+use tezedge_tui::{
+    automaton::{effects, reducer, State, Store},
+    inspector::{
+        InspectedRpcCall, InspectorRpcReportsReceivedAction, InspectorState, RpcCallStatus,
+        INSPECTED_CALLS_CAPACITY,
+    },
+    rpc::{RpcRequestAction, RpcRequestNotSentAction},
+    services::{
+        rpc_service_async::{RpcCall, RpcCallReport, RpcTarget},
+        ws_service::WebsocketMessage,
+    },
+    synchronization::BlockApplicationStatus,
+    terminal_ui::{ActivePage, ChangeScreenAction, TuiDownKeyPushedAction, TuiUpKeyPushedAction},
+    websocket::WebsocketMessageReceivedAction,
+};
+
+mod services_mocked;
+
+fn store() -> Store<ServiceMocked> {
+    let service = ServiceMocked::new();
+    let log = slog::Logger::root(slog::Discard, slog::o!());
+    let state = State::new(None, log);
+    Store::new(reducer, effects, service, SystemTime::now(), state)
+}
+
+fn report(inspected: &InspectedRpcCall, status: u16, body: &str) -> RpcCallReport {
+    RpcCallReport {
+        id: inspected.id,
+        call: inspected.call.clone(),
+        latency: 1_000_000,
+        status: Some(status),
+        size: body.len(),
+        preview: body.to_string(),
+        error: None,
+    }
+}
+
+fn statuses(state: &State) -> Vec<&RpcCallStatus> {
+    state
+        .inspector
+        .calls
+        .iter()
+        .map(|inspected| &inspected.status)
+        .collect()
+}
+
+#[test]
+pub fn reports_finish_the_calls_with_their_id() {
+    let mut store = store();
+    let header = RpcCall::new(RpcTarget::CurrentHeadHeader, None);
+    let statistics = RpcCall::new(
+        RpcTarget::LevelApplicationStatistics(200),
+        Some(String::from("?level=200")),
+    );
+    for call in [&header, &statistics, &header] {
+        store.dispatch(RpcRequestAction { call: call.clone() });
+    }
+    assert_eq!(statuses(store.state()), vec![&RpcCallStatus::Pending; 3]);
+    let calls: Vec<InspectedRpcCall> = store.state().inspector.calls.iter().cloned().collect();
+
+    // the newer call of the same target is answered first
+    let second_header = report(&calls[2], 200, "{\"level\":201}");
+    let empty = report(&calls[1], 200, "[]");
+    store.dispatch(InspectorRpcReportsReceivedAction {
+        reports: vec![second_header.clone(), empty.clone()],
+    });
+    assert_eq!(
+        statuses(store.state()),
+        vec![
+            &RpcCallStatus::Pending,
+            &RpcCallStatus::Finished(empty),
+            &RpcCallStatus::Finished(second_header.clone()),
+        ]
+    );
+
+    // the requests the rpc service did not accept are not waiting for a report
+    store.dispatch(RpcRequestNotSentAction {
+        id: calls[0].id,
+        call: header.clone(),
+        reason: String::from("no available capacity"),
+    });
+    assert_eq!(
+        store.state().inspector.calls[0].status,
+        RpcCallStatus::NotSent(String::from("no available capacity"))
+    );
+    store.dispatch(InspectorRpcReportsReceivedAction {
+        reports: vec![report(&calls[0], 500, ""), report(&calls[2], 500, "")],
+    });
+    let inspector = &store.state().inspector;
+    assert_eq!(
+        inspector.calls[0].status,
+        RpcCallStatus::NotSent(String::from("no available capacity"))
+    );
+    assert_eq!(
+        inspector.calls[2].status,
+        RpcCallStatus::Finished(second_header)
+    );
+}
+
+#[test]
+pub fn actions_and_websocket_messages_are_counted() {
+    let mut store = store();
+    store.dispatch(RpcRequestAction {
+        call: RpcCall::new(RpcTarget::BestRemoteLevel, None),
+    });
+    store.dispatch(WebsocketMessageReceivedAction {
+        websocket_message: vec![
+            WebsocketMessage::PeersMetrics(Vec::new()),
+            WebsocketMessage::BlockApplicationStatus(BlockApplicationStatus::default()),
+            WebsocketMessage::PeersMetrics(Vec::new()),
+        ],
+    });
+    store.dispatch(RpcRequestAction {
+        call: RpcCall::new(RpcTarget::BestRemoteLevel, None),
+    });
+
+    let inspector = &store.state().inspector;
+    let requests = &inspector.actions["RpcRequest"];
+    assert_eq!(requests.count, 2);
+    assert_eq!((requests.min_depth, requests.max_depth), (0, 0));
+    assert_eq!(requests.minute_count, 2);
+    assert_eq!(inspector.actions["WebsocketMessageReceived"].count, 1);
+    assert_eq!(inspector.websocket_messages["PeersMetrics"], 2);
+    assert_eq!(inspector.websocket_messages["BlockApplicationStatus"], 1);
+}
+
+#[test]
+pub fn action_frequency_is_the_count_of_the_last_minute() {
+    const MINUTE: u64 = 60_000_000_000;
+    let mut inspector = InspectorState::default();
+    inspector.count_action("DrawScreen", 0, 0);
+    inspector.count_action("DrawScreen", 0, MINUTE / 2);
+    let statistics = &inspector.actions["DrawScreen"];
+    assert_eq!(statistics.per_minute(MINUTE / 2), None);
+    assert_eq!(statistics.per_minute(MINUTE), Some(2));
+
+    // the next minute starts with the next action
+    inspector.count_action("DrawScreen", 0, MINUTE + 1);
+    let statistics = &inspector.actions["DrawScreen"];
+    assert_eq!(statistics.count, 3);
+    assert_eq!(statistics.per_minute(MINUTE + 1), Some(2));
+    assert_eq!(statistics.per_minute(2 * MINUTE + 1), Some(1));
+    // a whole minute without the action
+    assert_eq!(statistics.per_minute(3 * MINUTE + 1), Some(0));
+}
+
+#[test]
+pub fn selection_stays_on_the_call_when_the_oldest_are_dropped() {
+    let mut store = store();
+    store.dispatch(ChangeScreenAction {
+        screen: ActivePage::Inspector,
+    });
+    for level in 0..INSPECTED_CALLS_CAPACITY as i32 {
+        store.dispatch(RpcRequestAction {
+            call: RpcCall::new(RpcTarget::LevelApplicationStatistics(level), None),
+        });
+    }
+    assert_eq!(store.state().inspector.selected, None);
+
+    store.dispatch(TuiUpKeyPushedAction {});
+    store.dispatch(TuiUpKeyPushedAction {});
+    assert_eq!(
+        store.state().inspector.selected,
+        Some(INSPECTED_CALLS_CAPACITY - 2)
+    );
+
+    store.dispatch(RpcRequestAction {
+        call: RpcCall::new(RpcTarget::CurrentHeadHeader, None),
+    });
+    let inspector = &store.state().inspector;
+    assert_eq!(inspector.calls.len(), INSPECTED_CALLS_CAPACITY);
+    assert_eq!(
+        inspector
+            .selected_call()
+            .map(|inspected| inspected.call.target),
+        Some(RpcTarget::LevelApplicationStatistics(
+            INSPECTED_CALLS_CAPACITY as i32 - 2
+        ))
+    );
+
+    // moving past the newest call follows the calls again
+    for _ in 0..3 {
+        store.dispatch(TuiDownKeyPushedAction {});
+    }
+    assert_eq!(store.state().inspector.selected, None);
+}
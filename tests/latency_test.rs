@@ -0,0 +1,93 @@
+use pretty_assertions::assert_eq;
+use std::time::SystemTime;
+
+use services_mocked::ServiceMocked;
+use tezedge_tui::{
+    automaton::{effects, reducer, State, Store},
+    baking::{
+        ApplicationStatisticsReceivedAction, BlockApplicationStatistics, PerPeerBlockStatistics,
+        PerPeerBlockStatisticsReceivedAction,
+    },
+    latency::{LatencyDistribution, LatencyWindowToggleAction},
+    services::rpc_service_async::CurrentHeadHeader,
+    terminal_ui::CurrentHeadHeaderChangedAction,
+};
+
+mod services_mocked;
+
+fn difference(a: u64, b: u64) -> u64 {
+    a.max(b) - a.min(b)
+}
+
+#[test]
+pub fn percentiles_and_buckets() {
+    let millisecond = 1_000_000;
+    let distribution = LatencyDistribution::new((1..=100).map(|ms| ms * millisecond)).unwrap();
+
+    assert_eq!(distribution.count, 100);
+    // the histogram keeps 3 significant digits
+    assert!(difference(distribution.p50, 50 * millisecond) < 100_000);
+    assert!(difference(distribution.p90, 90 * millisecond) < 100_000);
+    assert!(difference(distribution.p99, 99 * millisecond) < 100_000);
+    assert!(distribution.buckets.len() as u64 <= LatencyDistribution::BUCKETS + 1);
+    assert_eq!(
+        distribution
+            .buckets
+            .iter()
+            .map(|(_, count)| count)
+            .sum::<u64>(),
+        100
+    );
+
+    assert_eq!(LatencyDistribution::new(Vec::new()), None);
+}
+
+#[test]
+pub fn finished_blocks_enter_the_window() {
+    let service = ServiceMocked::new();
+    let log = slog::Logger::root(slog::Discard, slog::o!());
+    let state = State::new(None, log);
+    let mut store = Store::new(reducer, effects, service, SystemTime::now(), state);
+
+    let head = |level: i32| CurrentHeadHeaderChangedAction {
+        current_head_header: CurrentHeadHeader {
+            level,
+            hash: format!("hash{}", level),
+            ..Default::default()
+        },
+    };
+
+    store.dispatch(head(10));
+    store.dispatch(ApplicationStatisticsReceivedAction {
+        level: Some(10),
+        application_statistics: vec![BlockApplicationStatistics {
+            block_hash: String::from("hash10"),
+            load_data_start: Some(1_000),
+            store_result_end: Some(3_000),
+            ..Default::default()
+        }],
+    });
+    store.dispatch(PerPeerBlockStatisticsReceivedAction {
+        level: Some(10),
+        per_peer_block_statistics: [Some(100), None, Some(300)]
+            .into_iter()
+            .map(|sent_end_time| PerPeerBlockStatistics {
+                block_hash: String::from("hash10"),
+                sent_end_time,
+                ..Default::default()
+            })
+            .collect(),
+    });
+    assert!(store.state().latency.window.is_empty());
+
+    store.dispatch(head(11));
+    let window = &store.state().latency.window;
+    assert_eq!(window.len(), 1);
+    assert_eq!(window[0].level, 10);
+    assert_eq!(window[0].propagation, vec![100, 300]);
+    assert_eq!(window[0].application, vec![2_000]);
+
+    assert!(!store.state().latency.rolling);
+    store.dispatch(LatencyWindowToggleAction {});
+    assert!(store.state().latency.rolling);
+}
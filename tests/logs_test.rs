@@ -0,0 +1,185 @@
+use pretty_assertions::assert_eq;
+use slog::{info, o, warn, Drain};
+use std::time::SystemTime;
+
+use services_mocked::{log_service::LogServiceMocked, ServiceMocked};
+use tezedge_tui::{
+    automaton::{effects, reducer, State, Store},
+    history::PromptEdit,
+    logs::{
+        LogsGetAction, LogsLevelFilterAction, LogsModuleFilterAction, LogsSearchEditAction,
+        LogsSearchSubmitAction, LogsSearchToggleAction,
+    },
+    services::log_service::{LogBuffer, LogLevel, LogRecord, LOG_RECORDS_CAPACITY},
+    terminal_ui::{ActivePage, ChangeScreenAction, TuiDownKeyPushedAction, TuiUpKeyPushedAction},
+};
+use time::OffsetDateTime;
+
+mod services_mocked;
+
+fn record(level: LogLevel, module: &str, message: &str) -> LogRecord {
+    LogRecord {
+        time: OffsetDateTime::UNIX_EPOCH,
+        level,
+        module: module.to_string(),
+        message: message.to_string(),
+    }
+}
+
+fn store_with_records(records: Vec<LogRecord>) -> Store<ServiceMocked> {
+    let service = ServiceMocked {
+        logs: LogServiceMocked { records },
+        ..ServiceMocked::new()
+    };
+    let log = slog::Logger::root(slog::Discard, slog::o!());
+    let state = State::new(None, log);
+    Store::new(reducer, effects, service, SystemTime::now(), state)
+}
+
+fn shown_messages(state: &State) -> Vec<&str> {
+    state
+        .logs
+        .filtered()
+        .iter()
+        .map(|record| record.message.as_str())
+        .collect()
+}
+
+#[test]
+pub fn buffer_keeps_the_logged_records() {
+    let buffer = LogBuffer::default();
+    let log = slog::Logger::root(buffer.clone().fuse(), o!("service" => "rpc"));
+
+    info!(log, "Connected"; "url" => "http://127.0.0.1:18732");
+    warn!(log, "Request timed out");
+
+    let records = buffer.take();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].level, LogLevel::Info);
+    assert_eq!(
+        records[0].message,
+        "Connected, url: http://127.0.0.1:18732, service: rpc"
+    );
+    assert_eq!(records[0].module, module_path!());
+    assert_eq!(records[1].level, LogLevel::Warning);
+    assert_eq!(records[1].message, "Request timed out, service: rpc");
+
+    // taken once
+    assert!(buffer.take().is_empty());
+}
+
+#[test]
+pub fn records_are_filtered_by_level_module_and_search() {
+    let mut store = store_with_records(vec![
+        record(
+            LogLevel::Debug,
+            "tezedge_tui::services::ws_service",
+            "Websocket message",
+        ),
+        record(
+            LogLevel::Info,
+            "tezedge_tui::services::ws_service",
+            "Connected",
+        ),
+        record(
+            LogLevel::Warning,
+            "tezedge_tui::services::rpc_service_async",
+            "RPC timed out",
+        ),
+        record(
+            LogLevel::Error,
+            "tezedge_tui::services::rpc_service_async",
+            "RPC failed",
+        ),
+    ]);
+    store.dispatch(ChangeScreenAction {
+        screen: ActivePage::Logs,
+    });
+    store.dispatch(LogsGetAction {});
+    assert_eq!(store.state().logs.records.len(), 4);
+    assert_eq!(shown_messages(store.state()).len(), 4);
+
+    // trace -> debug -> info
+    store.dispatch(LogsLevelFilterAction {});
+    store.dispatch(LogsLevelFilterAction {});
+    assert_eq!(store.state().logs.level, LogLevel::Info);
+    assert_eq!(
+        shown_messages(store.state()),
+        vec!["Connected", "RPC timed out", "RPC failed"]
+    );
+
+    // the modules are cycled in order, then all of them are shown again
+    store.dispatch(LogsModuleFilterAction {});
+    assert_eq!(
+        shown_messages(store.state()),
+        vec!["RPC timed out", "RPC failed"]
+    );
+    store.dispatch(LogsModuleFilterAction {});
+    assert_eq!(shown_messages(store.state()), vec!["Connected"]);
+    store.dispatch(LogsModuleFilterAction {});
+    assert_eq!(store.state().logs.module, None);
+
+    store.dispatch(LogsSearchToggleAction {});
+    for character in "rpc TX".chars() {
+        store.dispatch(LogsSearchEditAction {
+            edit: PromptEdit::Push(character),
+        });
+    }
+    store.dispatch(LogsSearchEditAction {
+        edit: PromptEdit::Pop,
+    });
+    store.dispatch(LogsSearchSubmitAction {});
+    assert_eq!(store.state().logs.prompt, None);
+    assert_eq!(store.state().logs.search.as_deref(), Some("rpc T"));
+    assert_eq!(shown_messages(store.state()), vec!["RPC timed out"]);
+
+    // an empty search shows all the records again
+    store.dispatch(LogsSearchToggleAction {});
+    for _ in 0..5 {
+        store.dispatch(LogsSearchEditAction {
+            edit: PromptEdit::Pop,
+        });
+    }
+    store.dispatch(LogsSearchSubmitAction {});
+    assert_eq!(store.state().logs.search, None);
+    assert_eq!(shown_messages(store.state()).len(), 3);
+}
+
+#[test]
+pub fn selection_follows_the_newest_records_until_moved() {
+    let mut store = store_with_records(
+        (0..LOG_RECORDS_CAPACITY)
+            .map(|index| record(LogLevel::Info, "tezedge_tui", &index.to_string()))
+            .collect(),
+    );
+    store.dispatch(ChangeScreenAction {
+        screen: ActivePage::Logs,
+    });
+    store.dispatch(LogsGetAction {});
+    assert_eq!(store.state().logs.selected, None);
+
+    // the newest record is selected first
+    store.dispatch(TuiUpKeyPushedAction {});
+    store.dispatch(TuiUpKeyPushedAction {});
+    assert_eq!(store.state().logs.selected, Some(LOG_RECORDS_CAPACITY - 2));
+
+    // the oldest records are dropped, the selection stays on the same record
+    store.service().logs.records = vec![
+        record(LogLevel::Info, "tezedge_tui", "new"),
+        record(LogLevel::Info, "tezedge_tui", "newer"),
+    ];
+    store.dispatch(LogsGetAction {});
+    let logs = &store.state().logs;
+    assert_eq!(logs.records.len(), LOG_RECORDS_CAPACITY);
+    assert_eq!(logs.selected, Some(LOG_RECORDS_CAPACITY - 4));
+    assert_eq!(
+        logs.filtered()[LOG_RECORDS_CAPACITY - 4].message,
+        (LOG_RECORDS_CAPACITY - 2).to_string()
+    );
+
+    // moving past the newest record follows the records again
+    for _ in 0..4 {
+        store.dispatch(TuiDownKeyPushedAction {});
+    }
+    assert_eq!(store.state().logs.selected, None);
+}
@@ -0,0 +1,229 @@
+use pretty_assertions::assert_eq;
+use std::{path::Path, time::Duration};
+
+use tezedge_tui::{
+    mock::{block_hash, MockNode, MockScenario},
+    services::{
+        rpc_service_async::{
+            RpcCall, RpcRequest, RpcResponse, RpcService, RpcServiceDefault, RpcTarget,
+        },
+        ws_service::{WebsocketMessage, WebsocketService, WebsocketServiceDefault},
+    },
+};
+
+fn log() -> slog::Logger {
+    slog::Logger::root(slog::Discard, slog::o!())
+}
+
+async fn call(rpc: &mut RpcServiceDefault, target: RpcTarget, query: Option<&str>) -> RpcResponse {
+    rpc.request_send(RpcRequest {
+        id: 1,
+        call: RpcCall::new(target, query.map(String::from)),
+    })
+    .unwrap();
+    // a failed call is only logged, the timeout catches it
+    tokio::time::timeout(Duration::from_secs(10), rpc.response_recv())
+        .await
+        .expect("the mock node response did not parse")
+        .unwrap()
+}
+
+/// Messages of the first block the websocket service receives
+async fn first_messages(node: &MockNode) -> Vec<WebsocketMessage> {
+    let mut websocket = WebsocketServiceDefault::new(16, node.websocket_url(), &log());
+
+    // the messages sent before the service connects are lost, bake until one arrives
+    tokio::time::timeout(Duration::from_secs(10), async {
+        loop {
+            node.bake_block();
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            if let Ok(messages) = websocket.message_try_recv() {
+                return messages;
+            }
+        }
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+pub async fn rpc_service_parses_the_simulated_chain() {
+    let scenario = MockScenario::default();
+    let baker = scenario.bakers[0].clone();
+    let node = MockNode::start(scenario);
+    node.bake_block();
+    node.endorse(10);
+    let level = node.chain().level;
+
+    let mut rpc = RpcServiceDefault::new(16, node.rpc_url(), &log());
+
+    match call(&mut rpc, RpcTarget::CurrentHeadHeader, None).await {
+        RpcResponse::CurrentHeadHeader(header) => {
+            assert_eq!(header.level, level);
+            assert_eq!(header.hash, block_hash(level));
+        }
+        response => panic!("unexpected response {:?}", response),
+    }
+    match call(&mut rpc, RpcTarget::EndersementsStatus, None).await {
+        RpcResponse::EndorsementsStatus(statuses) => assert_eq!(statuses.len(), 10),
+        response => panic!("unexpected response {:?}", response),
+    }
+    match call(&mut rpc, RpcTarget::MempoolEndorsementStats, None).await {
+        RpcResponse::MempoolEndorsementStats(stats) => assert_eq!(stats.len(), 10),
+        response => panic!("unexpected response {:?}", response),
+    }
+    match call(
+        &mut rpc,
+        RpcTarget::LevelApplicationStatistics(level - 5),
+        Some(&format!("?level={}", level - 5)),
+    )
+    .await
+    {
+        RpcResponse::LevelApplicationStatistics(_, statistics) => {
+            assert_eq!(statistics.len(), 1);
+            assert_eq!(statistics[0].block_hash, block_hash(level - 5));
+        }
+        response => panic!("unexpected response {:?}", response),
+    }
+    match call(
+        &mut rpc,
+        RpcTarget::BakingRights,
+        Some(&format!("?delegate={}&max_priority=0&cycle=7", baker)),
+    )
+    .await
+    {
+        RpcResponse::BakingRights(cycle, rights) => {
+            assert_eq!(cycle, 7);
+            assert!(!rights.is_empty());
+            assert!(rights.iter().all(|right| right.delegate == baker));
+        }
+        response => panic!("unexpected response {:?}", response),
+    }
+
+    // the remaining paths parse into their responses
+    let rest = [
+        (RpcTarget::EndorsementRights, Some("?level=2001&block=head")),
+        (RpcTarget::OperationsStats, None),
+        (RpcTarget::ApplicationStatistics, Some("?level=2001")),
+        (RpcTarget::PerPeerBlockStatistics, Some("?level=2001")),
+        (
+            RpcTarget::LevelPerPeerBlockStatistics(2000),
+            Some("?level=2000"),
+        ),
+        (
+            RpcTarget::EndorsementRightsWithTime,
+            Some("?delegate=tz1Mkb2MQyHnVybEru6iTgTGQaZikyg4fBhr&cycle=7"),
+        ),
+        (RpcTarget::NetworkConstants, None),
+        (RpcTarget::CurrentHeadMetadata, None),
+        (RpcTarget::BestRemoteLevel, None),
+        (RpcTarget::Proposals, None),
+        (RpcTarget::PendingOperations, None),
+    ];
+    for (target, query) in rest {
+        call(&mut rpc, target, query).await;
+    }
+}
+
+#[tokio::test]
+pub async fn rpc_service_reports_the_calls() {
+    let node = MockNode::start(MockScenario::default());
+    let mut rpc = RpcServiceDefault::new(16, node.rpc_url(), &log());
+
+    call(&mut rpc, RpcTarget::CurrentHeadHeader, None).await;
+    // the report is sent after the response
+    let reports = tokio::time::timeout(Duration::from_secs(10), async {
+        loop {
+            let reports = rpc.take_reports();
+            if !reports.is_empty() {
+                return reports;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .unwrap();
+
+    match &reports[..] {
+        [report] => {
+            assert_eq!(report.id, 1);
+            assert_eq!(report.call.target, RpcTarget::CurrentHeadHeader);
+            assert_eq!(report.status, Some(200));
+            assert_eq!(report.error, None);
+            assert!(report.size > 0);
+            assert!(report.preview.starts_with('{'));
+        }
+        reports => panic!("unexpected reports {:?}", reports),
+    }
+}
+
+#[tokio::test]
+pub async fn scenario_fixture_overrides_responses() {
+    let scenario = MockScenario::load(Path::new("tests/fixtures/mock_scenario.json")).unwrap();
+    let node = MockNode::start(scenario);
+    let mut rpc = RpcServiceDefault::new(16, node.rpc_url(), &log());
+
+    match call(&mut rpc, RpcTarget::BestRemoteLevel, None).await {
+        RpcResponse::BestRemoteLevel(level) => assert_eq!(level, Some(4100)),
+        response => panic!("unexpected response {:?}", response),
+    }
+    match call(&mut rpc, RpcTarget::Proposals, None).await {
+        RpcResponse::Proposals(proposals) => assert_eq!(proposals[0].1, 8000),
+        response => panic!("unexpected response {:?}", response),
+    }
+    match call(&mut rpc, RpcTarget::CurrentHeadMetadata, None).await {
+        RpcResponse::CurrentHeadMetadata(metadata) => {
+            assert_eq!(metadata.level_info.level, 4095);
+            assert_eq!(metadata.level_info.cycle_position, 4094);
+        }
+        response => panic!("unexpected response {:?}", response),
+    }
+}
+
+#[tokio::test]
+pub async fn websocket_service_receives_the_applied_blocks() {
+    let node = MockNode::start(MockScenario::default());
+    let messages = first_messages(&node).await;
+
+    let level = node.chain().level;
+    match &messages[..] {
+        [WebsocketMessage::IncomingTransfer(transfer), WebsocketMessage::BlockStatus(blocks), WebsocketMessage::BlockApplicationStatus(status), WebsocketMessage::ChainStatus(chain), WebsocketMessage::PeersMetrics(peers)] =>
+        {
+            let applied = status.last_applied_block.as_ref().unwrap();
+            assert_eq!(applied.hash, block_hash(applied.level));
+            // the node is synchronized up to the head
+            assert_eq!(transfer.eta, None);
+            assert!(transfer.downloaded_blocks <= level as usize);
+            assert_eq!(blocks.len(), chain.chain.len());
+            assert!(chain.chain[..chain.chain.len() - 1]
+                .iter()
+                .all(|cycle| cycle.all_applied()));
+            assert!(blocks[..blocks.len() - 1]
+                .iter()
+                .all(|block| block.all_downloaded()));
+            assert_eq!(peers.len(), MockScenario::default().peers);
+        }
+        messages => panic!("unexpected messages {:?}", messages),
+    }
+}
+
+#[tokio::test]
+pub async fn scenario_fixture_overrides_websocket_messages() {
+    let scenario = MockScenario::load(Path::new("tests/fixtures/mock_scenario.json")).unwrap();
+    let node = MockNode::start(scenario);
+    let messages = first_messages(&node).await;
+
+    assert_eq!(messages.len(), 5);
+    match &messages[0] {
+        WebsocketMessage::IncomingTransfer(transfer) => {
+            assert_eq!(transfer.eta, Some(120.0));
+            assert_eq!(transfer.downloaded_blocks, 4000);
+        }
+        message => panic!("unexpected message {:?}", message),
+    }
+    // the other messages are still simulated
+    assert!(matches!(
+        messages[2],
+        WebsocketMessage::BlockApplicationStatus(_)
+    ));
+}
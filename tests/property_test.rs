@@ -0,0 +1,372 @@
+use crossterm::event::KeyModifiers;
+use proptest::{collection::vec, option, prelude::*};
+use std::time::SystemTime;
+
+use services_mocked::ServiceMocked;
+use tezedge_tui::{
+    automaton::{effects, reducer, State, Store},
+    baking::PerPeerBlockStatisticsExtended,
+    endorsements::{EndorsementStatusSortable, EndrosementsState},
+    extensions::{
+        ColumnEdit, ColumnLayout, ExtendedTable, SortKeyByFocus, SortOrder, SortSpec,
+        SortableByFocus,
+    },
+    terminal_ui::{
+        next_item, previous_item, ActivePage, ChangeScreenAction, DrawScreenSuccessAction,
+        TuiColumnChooserKeyPushedAction, TuiColumnEditKeyPushedAction,
+        TuiDeltaToggleKeyPushedAction, TuiDownKeyPushedAction, TuiLeftKeyPushedAction,
+        TuiRightKeyPushedAction, TuiSortKeyPushedAction, TuiUpKeyPushedAction,
+        TuiWidgetSelectionKeyPushedAction,
+    },
+};
+use tui::layout::Constraint;
+
+mod services_mocked;
+
+fn endorser() -> impl Strategy<Value = EndorsementStatusSortable> {
+    (
+        0..8u8,
+        0..5usize,
+        option::of(0..1000u64),
+        option::of(0..1000u64),
+        option::of(0..1000u64),
+        option::of(0..1000u64),
+    )
+        .prop_map(
+            |(baker, slot_count, delta, received_hash_time, applied_time, applied_time_delta)| {
+                EndorsementStatusSortable {
+                    delta,
+                    received_hash_time,
+                    applied_time,
+                    applied_time_delta,
+                    ..EndorsementStatusSortable::new(format!("tz1{}", baker), slot_count)
+                }
+            },
+        )
+}
+
+fn endorsers() -> impl Strategy<Value = Vec<EndorsementStatusSortable>> {
+    vec(endorser(), 0..12)
+}
+
+/// Sort keys of the columns past the last one are missing
+fn sort_specs() -> impl Strategy<Value = Vec<SortSpec>> {
+    vec(
+        (0..12usize, any::<bool>()).prop_map(|(column, ascending)| {
+            let order = if ascending {
+                SortOrder::Ascending
+            } else {
+                SortOrder::Descending
+            };
+            SortSpec::new(column, order)
+        }),
+        1..=3,
+    )
+}
+
+fn column_edit() -> impl Strategy<Value = ColumnEdit> {
+    prop_oneof![
+        Just(ColumnEdit::ToggleVisible),
+        Just(ColumnEdit::MoveUp),
+        Just(ColumnEdit::MoveDown),
+        Just(ColumnEdit::TogglePin),
+        Just(ColumnEdit::Widen),
+        Just(ColumnEdit::Narrow),
+    ]
+}
+
+fn screen() -> impl Strategy<Value = ActivePage> {
+    prop_oneof![
+        Just(ActivePage::Endorsements),
+        Just(ActivePage::Baking),
+        Just(ActivePage::Rights),
+        Just(ActivePage::Blocks),
+        Just(ActivePage::Logs),
+        Just(ActivePage::Inspector),
+        Just(ActivePage::Synchronization),
+        Just(ActivePage::Statistics),
+    ]
+}
+
+/// Operations on a table, like the reducers apply them
+#[derive(Debug, Clone)]
+enum TableOp {
+    Next,
+    Previous,
+    Down,
+    Up,
+    Draw(u16),
+    Edit(usize, ColumnEdit),
+    Sort(bool),
+    Refresh(Vec<EndorsementStatusSortable>),
+}
+
+fn table_op() -> impl Strategy<Value = TableOp> {
+    prop_oneof![
+        3 => Just(TableOp::Next),
+        3 => Just(TableOp::Previous),
+        2 => Just(TableOp::Down),
+        2 => Just(TableOp::Up),
+        2 => (0..300u16).prop_map(TableOp::Draw),
+        2 => (0..12usize, column_edit()).prop_map(|(index, edit)| TableOp::Edit(index, edit)),
+        1 => any::<bool>().prop_map(TableOp::Sort),
+        1 => endorsers().prop_map(TableOp::Refresh),
+    ]
+}
+
+/// Keys pushed in the tui, and the redraws reporting the width of the terminal
+#[derive(Debug, Clone)]
+enum KeyOp {
+    Up,
+    Down,
+    Left,
+    Right,
+    Sort(bool),
+    Widget,
+    Screen(ActivePage),
+    Draw(u16),
+    ColumnChooser,
+    ColumnEdit(ColumnEdit),
+    DeltaToggle,
+}
+
+fn key_op() -> impl Strategy<Value = KeyOp> {
+    prop_oneof![
+        3 => Just(KeyOp::Up),
+        3 => Just(KeyOp::Down),
+        3 => Just(KeyOp::Left),
+        3 => Just(KeyOp::Right),
+        1 => any::<bool>().prop_map(KeyOp::Sort),
+        2 => Just(KeyOp::Widget),
+        2 => screen().prop_map(KeyOp::Screen),
+        2 => (0..300u16).prop_map(KeyOp::Draw),
+        1 => Just(KeyOp::ColumnChooser),
+        1 => column_edit().prop_map(KeyOp::ColumnEdit),
+        1 => Just(KeyOp::DeltaToggle),
+    ]
+}
+
+fn constraint_width(constraint: &Constraint) -> u16 {
+    match constraint {
+        Constraint::Min(width) | Constraint::Length(width) => *width,
+        _ => 0,
+    }
+}
+
+fn assert_columns_in_bounds<S: SortableByFocus + Default>(table: &ExtendedTable<S>) {
+    let visible = table.visible_columns().len();
+    assert!(visible > 0, "all the columns are hidden");
+    assert!(
+        table.selected() < visible,
+        "selected column {} of {}",
+        table.selected(),
+        visible
+    );
+    assert!(table.fixed() <= table.first_rendered_index());
+    assert!(
+        table.first_rendered_index() <= visible,
+        "first rendered column {} of {}",
+        table.first_rendered_index(),
+        visible
+    );
+}
+
+fn assert_rows_in_bounds<T>(table: &ExtendedTable<Vec<T>>)
+where
+    Vec<T>: SortableByFocus + Default,
+{
+    if let Some(selected) = table.table_state.selected() {
+        assert!(
+            selected < table.content.len(),
+            "selected row {} of {}",
+            selected,
+            table.content.len()
+        );
+    }
+}
+
+fn assert_selection_in_bounds(selected: Option<usize>, total: usize) {
+    if let Some(selected) = selected {
+        assert!(selected < total, "selected {} of {}", selected, total);
+    }
+}
+
+fn assert_state_in_bounds(state: &State) {
+    let endorsement_table = &state.endorsmenents.endorsement_table;
+    assert_columns_in_bounds(endorsement_table);
+    assert_rows_in_bounds(endorsement_table);
+
+    let baking_table = &state.baking.baking_table;
+    assert_columns_in_bounds(baking_table);
+    assert_rows_in_bounds(baking_table);
+
+    let main_table = &state.operations_statistics.main_operation_statistics_table;
+    assert_columns_in_bounds(main_table);
+    assert_rows_in_bounds(main_table);
+
+    let details_table = &state
+        .operations_statistics
+        .details_operation_statistics_table;
+    assert_columns_in_bounds(details_table);
+    assert_rows_in_bounds(details_table);
+
+    assert_selection_in_bounds(
+        state.synchronization.peer_table_state.selected(),
+        state.synchronization.peer_metrics.len(),
+    );
+    assert_selection_in_bounds(state.blocks.selected, state.blocks.recent.len());
+    assert_selection_in_bounds(state.logs.selected, state.logs.filtered().len());
+    assert_selection_in_bounds(state.inspector.selected, state.inspector.calls.len());
+    assert_selection_in_bounds(state.rights.selected, state.rights.entries().len());
+
+    if let (Some(chooser), Some(table)) = (&state.ui.column_chooser, state.active_table()) {
+        assert!(chooser.cursor < table.columns().len());
+    }
+}
+
+fn is_sorted_by(rows: &[EndorsementStatusSortable], spec: &SortSpec, delta_toggle: bool) -> bool {
+    rows.windows(2).all(|pair| {
+        let a = pair[0].sort_key(spec.column, delta_toggle);
+        let b = pair[1].sort_key(spec.column, delta_toggle);
+        match (a, b) {
+            // missing values are always last
+            (None, Some(_)) => false,
+            (Some(a), Some(b)) => match spec.order {
+                SortOrder::Ascending => a <= b,
+                SortOrder::Descending => a >= b,
+            },
+            _ => true,
+        }
+    })
+}
+
+fn serialized(rows: &[EndorsementStatusSortable]) -> Vec<String> {
+    let mut rows: Vec<String> = rows
+        .iter()
+        .map(|row| serde_json::to_string(row).unwrap())
+        .collect();
+    rows.sort();
+    rows
+}
+
+proptest! {
+    #[test]
+    fn next_and_previous_item_stay_in_bounds(total in 0..20usize, selected in option::of(0..40usize)) {
+        for item in [next_item(total, selected), previous_item(total, selected)] {
+            match item {
+                Some(item) => prop_assert!(item < total),
+                None => prop_assert_eq!(total, 0),
+            }
+        }
+    }
+
+    #[test]
+    fn sort_is_a_permutation_ordered_by_the_primary_key(
+        rows in endorsers(),
+        specs in sort_specs(),
+        delta_toggle in any::<bool>(),
+    ) {
+        let mut sorted = rows.clone();
+        sorted.sort_by_focus(&specs, delta_toggle);
+
+        prop_assert_eq!(serialized(&sorted), serialized(&rows));
+        prop_assert!(is_sorted_by(&sorted, &specs[0], delta_toggle));
+
+        // sorting is stable, sorting again does not move the rows
+        let mut resorted = sorted.clone();
+        resorted.sort_by_focus(&specs, delta_toggle);
+        prop_assert_eq!(resorted, sorted);
+    }
+
+    #[test]
+    fn table_stays_in_bounds(
+        content in endorsers(),
+        ops in vec(table_op(), 0..60),
+        delta_toggle in any::<bool>(),
+    ) {
+        let mut table = EndrosementsState::default().endorsement_table;
+        table.set_content(content, delta_toggle);
+
+        for op in ops {
+            match op {
+                TableOp::Next => table.next(),
+                TableOp::Previous => table.previous(),
+                TableOp::Down => {
+                    table.select(next_item(table.content.len(), table.table_state.selected()))
+                }
+                TableOp::Up => {
+                    table.select(previous_item(table.content.len(), table.table_state.selected()))
+                }
+                TableOp::Draw(width) => {
+                    let visible = table.visible_columns().len();
+                    let constraints = table.renderable_constraints(width);
+                    prop_assert!(constraints.len() <= visible);
+                    // the fixed columns are always rendered, the scrolled ones only when they fit
+                    prop_assert!(constraints.len() >= table.fixed().min(visible));
+                    if constraints.len() > table.fixed() {
+                        let total: u32 = constraints.iter().map(|c| constraint_width(c) as u32).sum();
+                        prop_assert!(total <= width as u32);
+                    }
+                    table.set_rendered(constraints.len());
+                }
+                TableOp::Edit(index, edit) => {
+                    table.edit_column(index, &edit);
+                }
+                TableOp::Sort(append) => {
+                    table.sort_by_selected(append);
+                    table.sort_content(delta_toggle);
+                }
+                TableOp::Refresh(content) => table.set_content(content, delta_toggle),
+            }
+
+            assert_columns_in_bounds(&table);
+            assert_rows_in_bounds(&table);
+        }
+    }
+
+    #[test]
+    fn reducers_keep_the_selections_in_bounds(
+        endorsers in endorsers(),
+        peers in vec(0..8u8, 0..6),
+        ops in vec(key_op(), 0..40),
+    ) {
+        let mut state = State::new(None, slog::Logger::root(slog::Discard, slog::o!()));
+        state.endorsmenents.endorsement_table.set_content(endorsers, false);
+        let peers = peers
+            .into_iter()
+            .map(|peer| PerPeerBlockStatisticsExtended {
+                address: format!("10.0.0.{}:9732", peer),
+                ..Default::default()
+            })
+            .collect();
+        state.baking.baking_table.set_content(peers, false);
+
+        let service = ServiceMocked::new();
+        let mut store = Store::new(reducer, effects, service, SystemTime::now(), state);
+
+        for op in ops {
+            match op {
+                KeyOp::Up => store.dispatch(TuiUpKeyPushedAction {}),
+                KeyOp::Down => store.dispatch(TuiDownKeyPushedAction {}),
+                KeyOp::Left => store.dispatch(TuiLeftKeyPushedAction {}),
+                KeyOp::Right => store.dispatch(TuiRightKeyPushedAction {}),
+                KeyOp::Sort(shift) => {
+                    let modifier = if shift {
+                        KeyModifiers::SHIFT
+                    } else {
+                        KeyModifiers::NONE
+                    };
+                    store.dispatch(TuiSortKeyPushedAction { modifier })
+                }
+                KeyOp::Widget => store.dispatch(TuiWidgetSelectionKeyPushedAction {}),
+                KeyOp::Screen(screen) => store.dispatch(ChangeScreenAction { screen }),
+                KeyOp::Draw(screen_width) => store.dispatch(DrawScreenSuccessAction { screen_width }),
+                KeyOp::ColumnChooser => store.dispatch(TuiColumnChooserKeyPushedAction {}),
+                KeyOp::ColumnEdit(edit) => store.dispatch(TuiColumnEditKeyPushedAction { edit }),
+                KeyOp::DeltaToggle => store.dispatch(TuiDeltaToggleKeyPushedAction {}),
+            };
+
+            assert_state_in_bounds(store.state());
+        }
+    }
+}
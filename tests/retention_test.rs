@@ -0,0 +1,206 @@
+use pretty_assertions::assert_eq;
+use std::time::SystemTime;
+
+use services_mocked::ServiceMocked;
+use tezedge_tui::{
+    automaton::{effects, reducer, State, Store},
+    baking::{ApplicationStatisticsReceivedAction, BlockApplicationStatistics},
+    configuration::RetentionConfig,
+    logs::LogsReceivedAction,
+    retention::{memory_usage, RetentionState, SliceUsage},
+    rights::LevelRights,
+    services::{
+        log_service::{LogLevel, LogRecord},
+        rpc_service_async::CurrentHeadHeader,
+    },
+    terminal_ui::{CurrentHeadHeaderChangedAction, TuiMemoryPanelKeyPushedAction},
+};
+use time::OffsetDateTime;
+
+mod services_mocked;
+
+fn slice<'a>(usage: &'a [SliceUsage], name: &str) -> &'a SliceUsage {
+    usage
+        .iter()
+        .find(|slice| slice.name == name)
+        .unwrap_or_else(|| panic!("no usage of {}", name))
+}
+
+#[test]
+pub fn old_levels_are_pruned() {
+    let service = ServiceMocked::new();
+    let log = slog::Logger::root(slog::Discard, slog::o!());
+    let mut state = State::new(None, log);
+    state.config.retention = RetentionConfig {
+        max_levels: 3,
+        max_age_seconds: 0,
+    };
+    for level in 1..=5 {
+        state
+            .endorsmenents
+            .injected_endorsement_stats
+            .insert(level, Default::default());
+    }
+    state.rights.calendar.insert(
+        10,
+        LevelRights {
+            endorsing_slots: Some(1),
+            ..Default::default()
+        },
+    );
+    let mut store = Store::new(reducer, effects, service, SystemTime::now(), state);
+
+    for level in 1..=5 {
+        store.dispatch(CurrentHeadHeaderChangedAction {
+            current_head_header: CurrentHeadHeader {
+                level,
+                hash: format!("hash{}", level),
+                ..Default::default()
+            },
+        });
+        store.dispatch(ApplicationStatisticsReceivedAction {
+            level: Some(level),
+            application_statistics: vec![BlockApplicationStatistics {
+                block_hash: format!("hash{}", level),
+                ..Default::default()
+            }],
+        });
+    }
+
+    let state = store.state();
+    assert_eq!(state.retention.cutoff_level, Some(3));
+    assert_eq!(
+        state
+            .baking
+            .application_statistics
+            .keys()
+            .collect::<Vec<_>>(),
+        vec!["hash3", "hash4", "hash5"]
+    );
+    assert_eq!(
+        state
+            .endorsmenents
+            .injected_endorsement_stats
+            .keys()
+            .collect::<Vec<_>>(),
+        vec![&3, &4, &5]
+    );
+    assert_eq!(
+        state
+            .blocks
+            .recent
+            .iter()
+            .map(|block| block.level)
+            .collect::<Vec<_>>(),
+        vec![5, 4, 3]
+    );
+    // the finished levels
+    assert_eq!(
+        state
+            .latency
+            .window
+            .iter()
+            .map(|latencies| latencies.level)
+            .collect::<Vec<_>>(),
+        vec![3, 4]
+    );
+    assert_eq!(
+        state
+            .trends
+            .samples
+            .iter()
+            .map(|sample| sample.level)
+            .collect::<Vec<_>>(),
+        vec![3, 4]
+    );
+
+    // the usage is measured when the panel opens
+    assert!(state.retention.usage.is_empty());
+    store.dispatch(LogsReceivedAction {
+        records: vec![LogRecord {
+            time: OffsetDateTime::UNIX_EPOCH,
+            level: LogLevel::Info,
+            module: String::from("tezedge_tui"),
+            message: String::from("Started"),
+        }],
+    });
+    store.dispatch(TuiMemoryPanelKeyPushedAction {});
+    let usage = &store.state().retention.usage;
+    assert_eq!(usage, &memory_usage(store.state()));
+    assert_eq!(usage[0].name, "Application statistics");
+    assert_eq!(usage[0].entries, 3);
+    assert!(usage[0].bytes > 0);
+    let logs = slice(usage, "Log records");
+    assert_eq!(logs.entries, 1);
+    assert!(logs.bytes > 0);
+    // the calls of the new heads and the dispatched actions are inspected
+    let calls = slice(usage, "Inspected rpc calls");
+    assert_eq!(calls.entries, store.state().inspector.calls.len());
+    assert!(calls.entries > 0);
+    let actions = slice(usage, "Inspected actions and messages");
+    assert_eq!(actions.entries, store.state().inspector.actions.len());
+    assert!(actions.bytes > 0);
+    assert_eq!(slice(usage, "Rights calendar").entries, 1);
+}
+
+#[test]
+pub fn statistics_are_kept_at_the_requested_level() {
+    let service = ServiceMocked::new();
+    let log = slog::Logger::root(slog::Discard, slog::o!());
+    let state = State::new(None, log);
+    let mut store = Store::new(reducer, effects, service, SystemTime::now(), state);
+
+    store.dispatch(CurrentHeadHeaderChangedAction {
+        current_head_header: CurrentHeadHeader {
+            level: 5,
+            hash: String::from("hash5"),
+            ..Default::default()
+        },
+    });
+    // the response to the request made at the previous head arrives late
+    store.dispatch(ApplicationStatisticsReceivedAction {
+        level: Some(4),
+        application_statistics: vec![BlockApplicationStatistics {
+            block_hash: String::from("hash4"),
+            ..Default::default()
+        }],
+    });
+    assert_eq!(store.state().retention.block_levels["hash4"], 4);
+}
+
+#[test]
+pub fn stricter_limit_applies() {
+    let second = 1_000_000_000;
+    let retention = RetentionState {
+        level_times: [(10, 0), (11, 100 * second), (12, 200 * second)].into(),
+        ..Default::default()
+    };
+
+    let by_age = RetentionConfig {
+        max_levels: 0,
+        max_age_seconds: 150,
+    };
+    assert_eq!(retention.cutoff_level(&by_age, 12, 200 * second), 11);
+
+    let by_levels = RetentionConfig {
+        max_levels: 3,
+        max_age_seconds: 300,
+    };
+    assert_eq!(retention.cutoff_level(&by_levels, 12, 200 * second), 10);
+
+    // the previous head is kept even with a single level
+    let single = RetentionConfig {
+        max_levels: 1,
+        max_age_seconds: 0,
+    };
+    assert_eq!(retention.cutoff_level(&single, 12, 200 * second), 11);
+
+    let unlimited = RetentionConfig {
+        max_levels: 0,
+        max_age_seconds: 0,
+    };
+    assert_eq!(
+        retention.cutoff_level(&unlimited, 12, 200 * second),
+        i32::MIN
+    );
+}
@@ -0,0 +1,237 @@
+use pretty_assertions::assert_eq;
+use std::time::{Duration as StdDuration, SystemTime};
+use time::Duration;
+
+use services_mocked::ServiceMocked;
+use tezedge_tui::{
+    automaton::{effects, reducer, State, Store},
+    baking::{BakingRightsPerLevel, BakingRightsReceivedAction},
+    endorsements::{EndorsementRightsWithTimePerLevel, EndorsementsRightsWithTimeReceivedAction},
+    rights::{format_countdown, RightKind, RightsState, RIGHTS_REQUEST_TIMEOUT},
+    services::rpc_service_async::{CurrentHeadMetadata, LevelInfo, NetworkConstants},
+    terminal_ui::{
+        CurrentHeadMetadataChangedAction, CycleChangedAction, NetworkConstantsReceivedAction,
+    },
+};
+
+mod services_mocked;
+
+fn baking(level: i32, priority: u64) -> BakingRightsPerLevel {
+    BakingRightsPerLevel {
+        level,
+        priority,
+        round: None,
+        delegate: String::from("tz1a"),
+        estimated_time: None,
+    }
+}
+
+fn endorsing(level: i32, slots: usize) -> EndorsementRightsWithTimePerLevel {
+    EndorsementRightsWithTimePerLevel {
+        level,
+        slots: (0..slots as u16).collect(),
+        delegate: String::from("tz1a"),
+        estimated_time: None,
+    }
+}
+
+#[test]
+pub fn rights_are_merged_per_level_and_pruned() {
+    let mut rights = RightsState::default();
+    rights.add_baking(&[baking(10, 2), baking(10, 0), baking(30, 1)]);
+    rights.add_endorsing(&[endorsing(10, 3), endorsing(20, 1)]);
+
+    let kinds: Vec<(i32, RightKind)> = rights
+        .entries()
+        .into_iter()
+        .map(|entry| (entry.level, entry.kind))
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            (
+                10,
+                RightKind::Baking {
+                    priority: 0,
+                    round: None
+                }
+            ),
+            (10, RightKind::Endorsing { slots: 3 }),
+            (20, RightKind::Endorsing { slots: 1 }),
+            (
+                30,
+                RightKind::Baking {
+                    priority: 1,
+                    round: None
+                }
+            ),
+        ]
+    );
+
+    // the selection stays on the baking right at level 30
+    rights.selected = Some(3);
+    rights.cleanup(11);
+    assert_eq!(rights.entries().len(), 2);
+    assert_eq!(rights.selected, Some(1));
+}
+
+#[test]
+pub fn baking_rounds_are_kept_since_tenderbake() {
+    let mut rights = RightsState::default();
+    let rights_json = r#"[
+        {"level": 40, "delegate": "tz1a", "round": 2},
+        {"level": 40, "delegate": "tz1a", "round": 1}
+    ]"#;
+    let raw: Vec<BakingRightsPerLevel> = serde_json::from_str(rights_json).unwrap();
+    rights.add_baking(&raw);
+
+    assert_eq!(
+        rights.entries()[0].kind,
+        RightKind::Baking {
+            priority: 0,
+            round: Some(1)
+        }
+    );
+}
+
+fn level_info(cycle: i32, cycle_position: i32, level: i32) -> LevelInfo {
+    serde_json::from_str(&format!(
+        r#"{{"cycle": {}, "cycle_position": {}, "expected_commitment": false, "level": {}, "level_position": {}}}"#,
+        cycle,
+        cycle_position,
+        level,
+        level - 1
+    ))
+    .unwrap()
+}
+
+#[test]
+pub fn cycle_is_computed_from_level_info() {
+    let level_info = level_info(5, 4094, 24574);
+
+    assert_eq!(level_info.cycle_of(24574, 0), None);
+    assert_eq!(level_info.cycle_of(24575, 4096), Some(5));
+    assert_eq!(level_info.cycle_of(24576, 4096), Some(6));
+    assert_eq!(level_info.cycle_of(24576 + 4096, 4096), Some(7));
+    assert_eq!(level_info.cycle_of(20479, 4096), Some(4));
+}
+
+#[test]
+pub fn countdown_is_formatted() {
+    assert_eq!(format_countdown(Duration::seconds(-5)), "now");
+    assert_eq!(format_countdown(Duration::seconds(65)), "00:01:05");
+    assert_eq!(
+        format_countdown(Duration::seconds(2 * 86_400 + 3 * 3_600 + 4 * 60 + 5)),
+        "2d 03:04:05"
+    );
+}
+
+#[test]
+pub fn preserved_cycles_are_requested_once() {
+    let service = ServiceMocked::new();
+    let log = slog::Logger::root(slog::Discard, slog::o!());
+    let state = State::new(Some(String::from("tz1a")), log);
+    let mut store = Store::new(reducer, effects, service, SystemTime::now(), state);
+
+    let requested = |store: &Store<ServiceMocked>| -> Vec<i32> {
+        store
+            .state()
+            .rights
+            .requested_cycles
+            .keys()
+            .copied()
+            .collect()
+    };
+
+    // the preserved cycles are not known until the constants arrive
+    store.dispatch(CurrentHeadMetadataChangedAction {
+        new_metadata: CurrentHeadMetadata {
+            level_info: level_info(5, 4094, 24574),
+            ..Default::default()
+        },
+    });
+    assert_eq!(requested(&store), vec![5]);
+
+    store.dispatch(NetworkConstantsReceivedAction {
+        constants: NetworkConstants {
+            minimal_block_delay: 30,
+            preserved_cycles: 2,
+            blocks_per_cycle: 4096,
+            ..Default::default()
+        },
+    });
+    assert_eq!(requested(&store), vec![5, 6, 7]);
+
+    store.dispatch(CycleChangedAction {
+        new_cycle: 6,
+        at_level: 24577,
+    });
+    store.dispatch(CurrentHeadMetadataChangedAction {
+        new_metadata: CurrentHeadMetadata {
+            level_info: level_info(6, 1, 24577),
+            ..Default::default()
+        },
+    });
+    assert_eq!(requested(&store), vec![6, 7, 8]);
+}
+
+#[test]
+pub fn rights_are_requested_again_when_they_do_not_arrive() {
+    let start = SystemTime::now();
+    let log = slog::Logger::root(slog::Discard, slog::o!());
+    let state = State::new(Some(String::from("tz1a")), log);
+    let mut store = Store::new(reducer, effects, ServiceMocked::new(), start, state);
+
+    let metadata = |cycle_position: i32| CurrentHeadMetadataChangedAction {
+        new_metadata: CurrentHeadMetadata {
+            level_info: level_info(5, cycle_position, 20480 + cycle_position + 1),
+            ..Default::default()
+        },
+    };
+    store.dispatch(NetworkConstantsReceivedAction {
+        constants: NetworkConstants {
+            preserved_cycles: 1,
+            blocks_per_cycle: 4096,
+            ..Default::default()
+        },
+    });
+    store.dispatch(metadata(10));
+    let requested_at = |store: &Store<ServiceMocked>, cycle: i32| {
+        store.state().rights.requested_cycles[&cycle].requested
+    };
+    let first_request = requested_at(&store, 6);
+
+    // the rights of the cycle 6 only partially arrived, e.g. the other rpc failed
+    store.dispatch(BakingRightsReceivedAction {
+        cycle: 5,
+        rights: vec![baking(20500, 0)],
+    });
+    store.dispatch(EndorsementsRightsWithTimeReceivedAction {
+        cycle: 5,
+        rights: vec![endorsing(20501, 2)],
+    });
+    store.dispatch(BakingRightsReceivedAction {
+        cycle: 6,
+        rights: vec![baking(24600, 0)],
+    });
+    assert!(store.state().rights.requested_cycles[&5].is_received());
+    assert!(!store.state().rights.requested_cycles[&6].is_received());
+
+    // still on the way
+    store.dispatch(metadata(11));
+    assert_eq!(requested_at(&store, 6), first_request);
+
+    let later = start + StdDuration::from_nanos(RIGHTS_REQUEST_TIMEOUT + 1_000_000_000);
+    let mut store = Store::new(
+        reducer,
+        effects,
+        ServiceMocked::new(),
+        later,
+        store.state().clone(),
+    );
+    store.dispatch(metadata(12));
+    assert!(requested_at(&store, 6) > first_request);
+    assert!(!store.state().rights.requested_cycles[&6].baking_received);
+    // the received rights are not requested again
+    assert!(requested_at(&store, 5) < first_request + RIGHTS_REQUEST_TIMEOUT);
+}
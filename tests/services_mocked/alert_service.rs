@@ -0,0 +1,13 @@
+use tezedge_tui::{alerts::Alert, services::alert_service::AlertService};
+
+/// Keeps the delivered alerts in memory instead of handing them to the sinks
+#[derive(Default)]
+pub struct AlertServiceMocked {
+    pub delivered: Vec<Alert>,
+}
+
+impl AlertService for AlertServiceMocked {
+    fn deliver(&mut self, alert: &Alert) {
+        self.delivered.push(alert.clone());
+    }
+}
@@ -0,0 +1,13 @@
+use tezedge_tui::{configuration::TuiConfig, services::config_service::ConfigService};
+
+/// Keeps the last saved config in memory instead of writing it to disk
+#[derive(Default)]
+pub struct ConfigServiceMocked {
+    pub saved: Option<TuiConfig>,
+}
+
+impl ConfigService for ConfigServiceMocked {
+    fn save(&mut self, config: &TuiConfig) {
+        self.saved = Some(config.clone());
+    }
+}
@@ -0,0 +1,17 @@
+use std::{io, path::PathBuf};
+
+use tezedge_tui::services::export_service::ExportService;
+
+/// Keeps the exports in memory instead of writing them to disk
+#[derive(Default)]
+pub struct ExportServiceMocked {
+    pub exports: Vec<(String, String)>,
+}
+
+impl ExportService for ExportServiceMocked {
+    fn export(&mut self, name: &str, extension: &str, content: &str) -> io::Result<PathBuf> {
+        let file_name = format!("{}.{}", name, extension);
+        self.exports.push((file_name.clone(), content.to_string()));
+        Ok(PathBuf::from(file_name))
+    }
+}
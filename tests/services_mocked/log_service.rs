@@ -0,0 +1,13 @@
+use tezedge_tui::services::log_service::{LogRecord, LogService};
+
+/// Hands out the records pushed by the test instead of the logged ones
+#[derive(Default)]
+pub struct LogServiceMocked {
+    pub records: Vec<LogRecord>,
+}
+
+impl LogService for LogServiceMocked {
+    fn take_records(&mut self) -> Vec<LogRecord> {
+        std::mem::take(&mut self.records)
+    }
+}
@@ -1,8 +1,10 @@
 pub use redux_rs::TimeService;
-use tezedge_tui::services::Service;
+use tezedge_tui::{automaton::ActionRecorder, services::Service};
 use tui::backend::TestBackend;
 
 use self::{
+    alert_service::AlertServiceMocked, config_service::ConfigServiceMocked,
+    export_service::ExportServiceMocked, log_service::LogServiceMocked,
     rpc_service_async::RpcServiceMocked, tui_service::TuiServiceMocked,
     ws_service::WebsocketServiceMocked,
 };
@@ -13,10 +15,49 @@ pub mod ws_service;
 
 pub mod tui_service;
 
+pub mod config_service;
+
+pub mod export_service;
+
+pub mod alert_service;
+
+pub mod log_service;
+
 pub struct ServiceMocked {
     pub rpc: RpcServiceMocked,
     pub tui: TuiServiceMocked,
     pub ws: WebsocketServiceMocked,
+    pub config: ConfigServiceMocked,
+    pub export: ExportServiceMocked,
+    pub alert: AlertServiceMocked,
+    pub logs: LogServiceMocked,
+    pub action_recorder: Option<ActionRecorder>,
+}
+
+impl ServiceMocked {
+    pub fn new() -> Self {
+        Self::with_size(400, 400)
+    }
+
+    /// Services drawing on a terminal of the given size
+    pub fn with_size(width: u16, height: u16) -> Self {
+        ServiceMocked {
+            rpc: RpcServiceMocked {},
+            tui: TuiServiceMocked::with_size(width, height),
+            ws: WebsocketServiceMocked {},
+            config: Default::default(),
+            export: Default::default(),
+            alert: Default::default(),
+            logs: Default::default(),
+            action_recorder: None,
+        }
+    }
+}
+
+impl Default for ServiceMocked {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl TimeService for ServiceMocked {}
@@ -26,6 +67,10 @@ impl Service for ServiceMocked {
     type Rpc = RpcServiceMocked;
     type Tui = TuiServiceMocked;
     type Ws = WebsocketServiceMocked;
+    type Config = ConfigServiceMocked;
+    type Export = ExportServiceMocked;
+    type Alert = AlertServiceMocked;
+    type Logs = LogServiceMocked;
 
     fn rpc(&mut self) -> &mut Self::Rpc {
         &mut self.rpc
@@ -36,4 +81,19 @@ impl Service for ServiceMocked {
     fn ws(&mut self) -> &mut Self::Ws {
         &mut self.ws
     }
+    fn config(&mut self) -> &mut Self::Config {
+        &mut self.config
+    }
+    fn export(&mut self) -> &mut Self::Export {
+        &mut self.export
+    }
+    fn alert(&mut self) -> &mut Self::Alert {
+        &mut self.alert
+    }
+    fn logs(&mut self) -> &mut Self::Logs {
+        &mut self.logs
+    }
+    fn action_recorder(&mut self) -> Option<&ActionRecorder> {
+        self.action_recorder.as_ref()
+    }
 }
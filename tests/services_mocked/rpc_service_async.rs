@@ -1,6 +1,6 @@
 use async_trait::async_trait;
 use tezedge_tui::services::{
-    rpc_service_async::{RpcCall, RpcResponse, RpcService},
+    rpc_service_async::{RpcCallReport, RpcRequest, RpcResponse, RpcService},
     RequestTrySendError,
 };
 
@@ -15,11 +15,15 @@ impl RpcServiceMocked {
 
 #[async_trait]
 impl RpcService for RpcServiceMocked {
-    fn request_send(&mut self, _: RpcCall) -> Result<(), RequestTrySendError<RpcCall>> {
+    fn request_send(&mut self, _: RpcRequest) -> Result<(), RequestTrySendError<RpcRequest>> {
         Ok(())
     }
 
     async fn response_recv(&mut self) -> Option<RpcResponse> {
         None
     }
+
+    fn take_reports(&mut self) -> Vec<RpcCallReport> {
+        Vec::new()
+    }
 }
@@ -7,7 +7,11 @@ pub struct TuiServiceMocked {
 
 impl TuiServiceMocked {
     pub fn new() -> Self {
-        let backend = TestBackend::new(400, 400);
+        Self::with_size(400, 400)
+    }
+
+    pub fn with_size(width: u16, height: u16) -> Self {
+        let backend = TestBackend::new(width, height);
 
         let terminal = Terminal::new(backend).expect("Error initializing terminal");
 
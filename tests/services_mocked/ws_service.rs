@@ -2,7 +2,8 @@ use tezedge_tui::services::ws_service::{WebsocketMessage, WebsocketService, WsRe
 
 impl WebsocketService for WebsocketServiceMocked {
     fn message_try_recv(&mut self) -> Result<Vec<WebsocketMessage>, WsRecvError> {
-        Ok(vec![])
+        // no messages, like the channel of the websocket service once it is drained
+        Err(WsRecvError::Empty)
     }
 }
 
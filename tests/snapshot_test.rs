@@ -0,0 +1,161 @@
+use pretty_assertions::assert_eq;
+use std::{
+    env, fs,
+    path::Path,
+    time::{Duration, UNIX_EPOCH},
+};
+
+use services_mocked::ServiceMocked;
+use tezedge_tui::{
+    alerts::AlertRule,
+    automaton::{effects, reducer, State, Store},
+    terminal_ui::{ActivePage, ChangeScreenAction, DrawScreenAction},
+};
+use tui::buffer::Buffer;
+
+mod services_mocked;
+
+/// Terminal sizes the screens are rendered at, from a small terminal to a large one
+const SIZES: [(u16, u16); 3] = [(80, 24), (120, 36), (200, 50)];
+
+/// Time the screens are drawn at, a few seconds after the fixture head `2026-10-18T20:09:03Z`
+const DRAW_TIME: u64 = 1_792_354_148;
+
+/// Directory of the golden frames, rewritten when the tests run with `UPDATE_SNAPSHOTS=1`
+const SNAPSHOT_DIR: &str = "tests/snapshots";
+
+/// The fixture has no alert rules, the banner would cover the header values of every frame
+fn fixture_state() -> State {
+    let fixture = fs::read_to_string("tests/fixtures/snapshot_state.json").unwrap();
+    serde_json::from_str(&fixture).unwrap()
+}
+
+/// Draw the screen like the tui does, the first frame reports the width the tables are laid out for
+fn render(state: State, screen: ActivePage, width: u16, height: u16) -> String {
+    let service = ServiceMocked::with_size(width, height);
+    let mut store = Store::new(
+        reducer,
+        effects,
+        service,
+        UNIX_EPOCH + Duration::from_secs(DRAW_TIME),
+        state,
+    );
+
+    store.dispatch(ChangeScreenAction { screen });
+    store.dispatch(DrawScreenAction {});
+    store.dispatch(DrawScreenAction {});
+
+    frame_text(store.service().tui.terminal.backend().buffer())
+}
+
+/// The symbols of the frame, a line per row without the trailing spaces
+fn frame_text(buffer: &Buffer) -> String {
+    let width = buffer.area.width as usize;
+    buffer
+        .content
+        .chunks(width)
+        .map(|row| {
+            let line: String = row.iter().map(|cell| cell.symbol.as_str()).collect();
+            format!("{}\n", line.trim_end())
+        })
+        .collect()
+}
+
+fn assert_snapshot(name: &str, frame: &str) {
+    let path = Path::new(SNAPSHOT_DIR).join(format!("{}.txt", name));
+    if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::create_dir_all(SNAPSHOT_DIR).unwrap();
+        fs::write(&path, frame).unwrap();
+        return;
+    }
+
+    let golden = fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "Missing snapshot {}, run the tests with UPDATE_SNAPSHOTS=1 to create it",
+            path.display()
+        )
+    });
+    assert_eq!(
+        golden, frame,
+        "The {} frame changed, run the tests with UPDATE_SNAPSHOTS=1 if the change is intended",
+        name
+    );
+}
+
+fn assert_screen(screen: ActivePage, name: &str) {
+    for (width, height) in SIZES {
+        let frame = render(fixture_state(), screen.clone(), width, height);
+        assert_snapshot(&format!("{}_{}x{}", name, width, height), &frame);
+    }
+}
+
+#[test]
+pub fn endorsements_screen() {
+    assert_screen(ActivePage::Endorsements, "endorsements");
+}
+
+#[test]
+pub fn baking_screen() {
+    assert_screen(ActivePage::Baking, "baking");
+}
+
+#[test]
+pub fn statistics_screen() {
+    assert_screen(ActivePage::Statistics, "statistics");
+}
+
+#[test]
+pub fn synchronization_screen() {
+    assert_screen(ActivePage::Synchronization, "synchronization");
+}
+
+#[test]
+pub fn blocks_screen() {
+    assert_screen(ActivePage::Blocks, "blocks");
+}
+
+#[test]
+pub fn logs_screen() {
+    assert_screen(ActivePage::Logs, "logs");
+}
+
+#[test]
+pub fn inspector_screen() {
+    assert_screen(ActivePage::Inspector, "inspector");
+}
+
+#[test]
+pub fn rights_screen() {
+    assert_screen(ActivePage::Rights, "rights");
+}
+
+#[test]
+pub fn alert_banner() {
+    let mut state = fixture_state();
+    // the endorsement of the baker in the fixture was not injected
+    state.config.alerts.rules = vec![AlertRule::EndorsementNotInjected];
+    let frame = render(state, ActivePage::Endorsements, 120, 36);
+    assert!(frame.starts_with(" ALERT"), "{}", frame);
+    assert_snapshot("alert_banner_120x36", &frame);
+}
+
+#[test]
+pub fn screens_render_on_tiny_terminals() {
+    let mut state = fixture_state();
+    state.current_head_header.protocol = String::from("Pt");
+    let screens = [
+        ActivePage::Synchronization,
+        ActivePage::Endorsements,
+        ActivePage::Statistics,
+        ActivePage::Baking,
+        ActivePage::Rights,
+        ActivePage::Blocks,
+        ActivePage::Logs,
+        ActivePage::Inspector,
+    ];
+    for screen in screens {
+        for (width, height) in [(1, 1), (20, 5), (40, 10)] {
+            render(state.clone(), screen.clone(), width, height);
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use pretty_assertions::assert_eq;
+use std::time::Duration;
+
+use tezedge_tui::{
+    mock::{MockNode, MockScenario},
+    services::{
+        rpc_service_async::{
+            RpcCall, RpcRequest, RpcResponse, RpcService, RpcServiceDefault, RpcTarget,
+        },
+        traffic_service::{
+            load_traffic, start_replay, ReplayPace, TrafficEvent, TrafficRecord, TrafficRecorder,
+        },
+        ws_service::{WebsocketMessage, WebsocketService},
+    },
+    synchronization::{BlockApplicationStatus, BlockInfo},
+};
+
+fn log() -> slog::Logger {
+    slog::Logger::root(slog::Discard, slog::o!())
+}
+
+async fn recv(rpc: &mut impl RpcService) -> RpcResponse {
+    tokio::time::timeout(Duration::from_secs(10), rpc.response_recv())
+        .await
+        .expect("no response received")
+        .unwrap()
+}
+
+fn request(id: u64, target: RpcTarget) -> RpcRequest {
+    RpcRequest {
+        id,
+        call: RpcCall::new(target, None),
+    }
+}
+
+fn header_level(response: &RpcResponse) -> i32 {
+    match response {
+        RpcResponse::CurrentHeadHeader(header) => header.level,
+        other => panic!("unexpected response {:?}", other),
+    }
+}
+
+#[tokio::test]
+pub async fn recorded_traffic_replays_in_order() {
+    let path =
+        std::env::temp_dir().join(format!("tezedge_tui_traffic_{}.ndjson", std::process::id()));
+    let node = MockNode::start(MockScenario::default());
+    let level = node.chain().level;
+
+    let recorder = TrafficRecorder::create(&path, &log()).unwrap();
+    let mut rpc = RpcServiceDefault::new(16, node.rpc_url(), &log()).with_recorder(recorder);
+    for (id, target) in [RpcTarget::NetworkConstants, RpcTarget::CurrentHeadHeader]
+        .into_iter()
+        .enumerate()
+    {
+        rpc.request_send(request(id as u64, target)).unwrap();
+        recv(&mut rpc).await;
+    }
+    node.bake_block();
+    rpc.request_send(request(2, RpcTarget::CurrentHeadHeader))
+        .unwrap();
+    recv(&mut rpc).await;
+
+    let records = load_traffic(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(records.len(), 3);
+    assert!(records.windows(2).all(|pair| pair[0].time <= pair[1].time));
+
+    let (mut rpc, _, _) = start_replay(records, ReplayPace::Speed(1000.0), 16, &log());
+    match recv(&mut rpc).await {
+        RpcResponse::NetworkConstants(constants) => {
+            assert_eq!(
+                constants.minimal_block_delay as u32,
+                MockScenario::default().block_delay_seconds
+            );
+        }
+        other => panic!("unexpected response {:?}", other),
+    }
+    assert_eq!(header_level(&recv(&mut rpc).await), level);
+
+    // the replayed responses answer the calls of the same target
+    rpc.request_send(request(10, RpcTarget::CurrentHeadHeader))
+        .unwrap();
+    rpc.request_send(request(11, RpcTarget::BestRemoteLevel))
+        .unwrap();
+    assert_eq!(header_level(&recv(&mut rpc).await), level + 1);
+    match &rpc.take_reports()[..] {
+        [report] => {
+            assert_eq!(report.id, 10);
+            assert_eq!(report.call.target, RpcTarget::CurrentHeadHeader);
+            assert_eq!(report.status, Some(200));
+            assert!(report.preview.contains(&format!("\"level\":{}", level + 1)));
+        }
+        reports => panic!("unexpected reports {:?}", reports),
+    }
+    assert!(rpc.take_reports().is_empty());
+}
+
+#[tokio::test]
+pub async fn stepped_replay_holds_the_next_block() {
+    let node = MockNode::start(MockScenario::default());
+    let first = node.bake_block();
+    let second = node.bake_block();
+
+    let applied = |level| {
+        TrafficEvent::Websocket(vec![WebsocketMessage::BlockApplicationStatus(
+            BlockApplicationStatus {
+                current_application_speed: 1.0,
+                average_application_speed: 1.0,
+                last_applied_block: Some(BlockInfo {
+                    hash: String::new(),
+                    level,
+                }),
+            },
+        )])
+    };
+    let records = vec![
+        TrafficRecord {
+            time: 0,
+            event: TrafficEvent::Rpc(RpcResponse::CurrentHeadHeader(first.clone())),
+        },
+        TrafficRecord {
+            time: 1,
+            event: applied(first.level),
+        },
+        TrafficRecord {
+            time: 2,
+            event: TrafficEvent::Rpc(RpcResponse::CurrentHeadHeader(second.clone())),
+        },
+    ];
+
+    let (mut rpc, mut ws, control) = start_replay(records, ReplayPace::Stepped, 16, &log());
+
+    // the first block is released without a step
+    assert_eq!(header_level(&recv(&mut rpc).await), first.level);
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert_eq!(ws.message_try_recv().unwrap().len(), 1);
+
+    assert!(
+        tokio::time::timeout(Duration::from_millis(200), rpc.response_recv())
+            .await
+            .is_err()
+    );
+
+    control.step();
+    assert_eq!(header_level(&recv(&mut rpc).await), second.level);
+}
@@ -0,0 +1,88 @@
+use pretty_assertions::assert_eq;
+use std::time::SystemTime;
+
+use services_mocked::ServiceMocked;
+use tezedge_tui::{
+    automaton::{effects, reducer, State, Store},
+    baking::{ApplicationStatisticsReceivedAction, BlockApplicationStatistics},
+    endorsements::{EndorsementState, MempoolEndorsementStatsReceivedAction},
+    operations::OperationStats,
+    services::rpc_service_async::CurrentHeadHeader,
+    terminal_ui::CurrentHeadHeaderChangedAction,
+    trends::{mempool_size, MempoolSizeReceivedAction, PendingOperations, TrendSample},
+};
+use time::OffsetDateTime;
+
+mod services_mocked;
+
+#[test]
+pub fn finished_blocks_are_sampled() {
+    let service = ServiceMocked::new();
+    let log = slog::Logger::root(slog::Discard, slog::o!());
+    let mut state = State::new(None, log);
+    state.endorsmenents.endoresement_status_summary = [
+        (EndorsementState::Applied, 200),
+        (EndorsementState::Broadcast, 50),
+        (EndorsementState::Missing, 6),
+    ]
+    .into_iter()
+    .collect();
+    let mut store = Store::new(reducer, effects, service, SystemTime::now(), state);
+
+    let head = |level: i32, seconds: i64| CurrentHeadHeaderChangedAction {
+        current_head_header: CurrentHeadHeader {
+            level,
+            hash: format!("hash{}", level),
+            timestamp: OffsetDateTime::from_unix_timestamp(seconds).unwrap(),
+            ..Default::default()
+        },
+    };
+
+    store.dispatch(head(10, 1_000));
+    store.dispatch(ApplicationStatisticsReceivedAction {
+        level: Some(10),
+        application_statistics: vec![BlockApplicationStatistics {
+            block_hash: String::from("hash10"),
+            apply_block_start: Some(1_000),
+            apply_block_end: Some(5_000),
+            ..Default::default()
+        }],
+    });
+    store.dispatch(MempoolEndorsementStatsReceivedAction {
+        stats: ["op1", "op2", "op3"]
+            .into_iter()
+            .map(|hash| (hash.to_string(), OperationStats::default()))
+            .collect(),
+    });
+    store.dispatch(MempoolSizeReceivedAction { size: 41 });
+    assert!(store.state().trends.samples.is_empty());
+
+    store.dispatch(head(11, 1_032));
+    assert_eq!(
+        store.state().trends.samples.back(),
+        Some(&TrendSample {
+            level: 10,
+            block_time: Some(32),
+            application_time: Some(4_000),
+            endorsements: 250,
+            mempool_size: 41,
+            mempool_endorsements: 3,
+        })
+    );
+}
+
+#[test]
+pub fn rejected_operations_are_not_in_the_mempool_size() {
+    let pending: PendingOperations = serde_json::from_str(
+        r#"{
+            "applied": [{"hash": "op1"}, {"hash": "op2"}],
+            "refused": [["op3", {"error": []}]],
+            "outdated": [["op4", {}]],
+            "branch_refused": [],
+            "branch_delayed": [["op5", {}]],
+            "unprocessed": [["op6", {}], ["op7", {}]]
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(mempool_size(&pending), 5);
+}